@@ -10,4 +10,10 @@ pub enum ErrorCode {
 
     #[msg("Role id is invalid")]
     InvalidRoleId,
+
+    #[msg("Expiry must be in the future")]
+    InvalidExpiry,
+
+    #[msg("Role has not expired")]
+    RoleNotExpired,
 }
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RoleSetEvent {
+    pub user: Pubkey,
+    pub role_id: u64,
+    pub expires_at: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RoleExpiredEvent {
+    pub user: Pubkey,
+    pub role_id: u64,
+    pub expired_at: i64,
+    pub timestamp: i64,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{ROLE_EXPIRY_SEED, USER_ROLE_SEED};
+use crate::errors::ErrorCode;
+use crate::events::RoleExpiredEvent;
+use crate::state::{RoleExpiry, UserRole};
+
+#[derive(Accounts)]
+#[instruction(role_id: u64, user: Pubkey)]
+pub struct ExpireRole<'info> {
+    #[account(
+        mut, 
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            user.as_ref(), 
+            role_id.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(
+        seeds = [
+            ROLE_EXPIRY_SEED.as_bytes(), 
+            user.as_ref(), 
+            role_id.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub role_expiry: Account<'info, RoleExpiry>,
+}
+
+// permissionless crank, admin roles stay usable until someone runs it
+pub fn handle_expire_role(ctx: Context<ExpireRole>, role_id: u64, user: Pubkey) -> Result<()> {
+    let timestamp = Clock::get()?.unix_timestamp;
+    let expires_at = ctx.accounts.role_expiry.expires_at;
+
+    if !ctx.accounts.roles.has_role || !ctx.accounts.role_expiry.is_expired(timestamp) {
+        return Err(ErrorCode::RoleNotExpired.into());
+    }
+
+    ctx.accounts.roles.has_role = false;
+
+    emit!(RoleExpiredEvent {
+        user,
+        role_id,
+        expired_at: expires_at,
+        timestamp,
+    });
+
+    Ok(())
+}
@@ -1,9 +1,11 @@
 
+pub mod expire_role;
 pub mod initialize;
 pub mod revoke_role;
 pub mod set_role;
 pub mod set_role_manager;
 
+pub use expire_role::*;
 pub use initialize::*;
 pub use revoke_role::*;
 pub use set_role::*;
@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{ROLE_MANAGER_SEED, USER_ROLE_SEED};
+use crate::constants::{ROLE_EXPIRY_SEED, ROLE_MANAGER_SEED, USER_ROLE_SEED};
 use crate::state::{RoleManager, UserRole};
+use crate::utils::close_account;
 
 #[derive(Accounts)]
 #[instruction(role_id: u64, user: Pubkey)]
@@ -17,6 +18,18 @@ pub struct RevokeRole<'info> {
         close = recipient
     )]
     pub roles: Account<'info, UserRole>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        mut,
+        seeds = [
+            ROLE_EXPIRY_SEED.as_bytes(),
+            user.as_ref(),
+            role_id.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub role_expiry: UncheckedAccount<'info>,
     
     #[account(seeds = [ROLE_MANAGER_SEED.as_bytes(), role_id.to_le_bytes().as_ref()], bump)]
     pub role_manager: Account<'info, RoleManager>,
@@ -42,6 +55,6 @@ pub struct RevokeRole<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handle_revoke_role(_ctx: Context<RevokeRole>, _role_id: u64, _user: Pubkey) -> Result<()> {
-    Ok(())
+pub fn handle_revoke_role(ctx: Context<RevokeRole>, _role_id: u64, _user: Pubkey) -> Result<()> {
+    close_account(&ctx.accounts.role_expiry, &ctx.accounts.recipient)
 }
\ No newline at end of file
@@ -1,9 +1,11 @@
 use anchor_lang::prelude::*;
 use num_traits::FromPrimitive;
 
-use crate::constants::{DISCRIMINATOR_LEN, ROLE_MANAGER_SEED, USER_ROLE_SEED};
-use crate::state::{Role, RoleManager, UserRole};
+use crate::constants::{DISCRIMINATOR_LEN, ROLE_EXPIRY_SEED, ROLE_MANAGER_SEED, USER_ROLE_SEED};
+use crate::state::{Role, RoleExpiry, RoleManager, UserRole};
 use crate::errors::ErrorCode;
+use crate::events::RoleSetEvent;
+use crate::utils::close_account;
 
 #[derive(Accounts)]
 #[instruction(role_id: u64, user: Pubkey)]
@@ -21,6 +23,67 @@ pub struct SetRole<'info> {
     )]
     pub roles: Account<'info, UserRole>,
 
+    /// CHECK: this account may not exist, a permanent grant closes it
+    #[account(
+        mut,
+        seeds = [
+            ROLE_EXPIRY_SEED.as_bytes(),
+            user.as_ref(),
+            role_id.to_le_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub role_expiry: UncheckedAccount<'info>,
+
+    #[account(seeds = [ROLE_MANAGER_SEED.as_bytes(), role_id.to_le_bytes().as_ref()], bump)]
+    pub role_manager: Account<'info, RoleManager>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(), 
+            role_manager.manager_role_id.to_le_bytes().as_ref()
+        ], 
+        bump
+    )]
+    pub signer_roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = signer_roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(role_id: u64, user: Pubkey)]
+pub struct SetRoleWithExpiry<'info> {
+    #[account(
+        init_if_needed, 
+        seeds = [
+            USER_ROLE_SEED.as_bytes(),
+            user.as_ref(),
+            role_id.to_le_bytes().as_ref()
+        ], 
+        bump,  
+        payer = signer, 
+        space = DISCRIMINATOR_LEN + UserRole::INIT_SPACE,
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(
+        init_if_needed, 
+        seeds = [
+            ROLE_EXPIRY_SEED.as_bytes(),
+            user.as_ref(),
+            role_id.to_le_bytes().as_ref()
+        ], 
+        bump,  
+        payer = signer, 
+        space = DISCRIMINATOR_LEN + RoleExpiry::INIT_SPACE,
+    )]
+    pub role_expiry: Account<'info, RoleExpiry>,
+
     #[account(seeds = [ROLE_MANAGER_SEED.as_bytes(), role_id.to_le_bytes().as_ref()], bump)]
     pub role_manager: Account<'info, RoleManager>,
 
@@ -41,7 +104,29 @@ pub struct SetRole<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handle_set_role(ctx: Context<SetRole>, role_id: u64, _user: Pubkey) -> Result<()> {
+pub fn handle_set_role(ctx: Context<SetRole>, role_id: u64, user: Pubkey) -> Result<()> {
+    validate_role(role_id, &ctx.accounts.role_manager)?;
+
+    ctx.accounts.roles.has_role = true;
+    close_account(&ctx.accounts.role_expiry, &ctx.accounts.signer)?;
+
+    emit_role_set(user, role_id, 0)
+}
+
+// granting again before expiry renews the role
+pub fn handle_set_role_with_expiry(ctx: Context<SetRoleWithExpiry>, role_id: u64, user: Pubkey, expires_at: i64) -> Result<()> {
+    if expires_at <= Clock::get()?.unix_timestamp {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    validate_role(role_id, &ctx.accounts.role_manager)?;
+
+    ctx.accounts.roles.has_role = true;
+    ctx.accounts.role_expiry.expires_at = expires_at;
+
+    emit_role_set(user, role_id, expires_at)
+}
+
+fn validate_role(role_id: u64, role_manager: &RoleManager) -> Result<()> {
     let role: Option<Role> = FromPrimitive::from_u64(role_id);
     if  role == None {
         return Err(ErrorCode::InvalidRoleId.into());
@@ -50,9 +135,19 @@ pub fn handle_set_role(ctx: Context<SetRole>, role_id: u64, _user: Pubkey) -> Re
         return Err(ErrorCode::CannotSetRoleAdmin.into());
     }
 
-    let role_manager: Role = FromPrimitive::from_u64(ctx.accounts.role_manager.manager_role_id).unwrap();
+    let role_manager: Role = FromPrimitive::from_u64(role_manager.manager_role_id).unwrap();
 
     msg!("role_manager: {:?}", role_manager);
-    ctx.accounts.roles.has_role = true;
     Ok(())
-}  
+}
+
+fn emit_role_set(user: Pubkey, role_id: u64, expires_at: i64) -> Result<()> {
+    emit!(RoleSetEvent {
+        user,
+        role_id,
+        expires_at,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
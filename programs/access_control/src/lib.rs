@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 pub mod constants;
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
@@ -22,6 +23,10 @@ pub mod access_control {
         handle_set_role(ctx, role_id, user)
     }
 
+    pub fn set_role_with_expiry(ctx: Context<SetRoleWithExpiry>, role_id: u64, user: Pubkey, expires_at: i64) -> Result<()> {
+        handle_set_role_with_expiry(ctx, role_id, user, expires_at)
+    }
+
     pub fn set_role_manager(ctx: Context<SetRoleManager>, role_id: u64, manager_role_id: u64) -> Result<()> {
         handle_set_role_manager(ctx, role_id, manager_role_id)
     }
@@ -29,4 +34,8 @@ pub mod access_control {
     pub fn revoke_role(ctx: Context<RevokeRole>, role_id: u64, user: Pubkey) -> Result<()> {
         handle_revoke_role(ctx, role_id, user)
     }
+
+    pub fn expire_role(ctx: Context<ExpireRole>, role_id: u64, user: Pubkey) -> Result<()> {
+        handle_expire_role(ctx, role_id, user)
+    }
 }
\ No newline at end of file
@@ -1,8 +1,10 @@
 
 pub mod config;
+pub mod role_expiry;
 pub mod role_manager;
 pub mod user_role;
 
 pub use config::*;
 pub use user_role::*;
+pub use role_expiry::*;
 pub use role_manager::*;
\ No newline at end of file
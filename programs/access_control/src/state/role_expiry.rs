@@ -0,0 +1,15 @@
+use anchor_lang::prelude::*;
+
+// kept apart from `UserRole` so existing role accounts keep their size.
+// only roles granted with an expiry have one, `expire_role` revokes them once it passes
+#[account]
+#[derive(Debug, InitSpace)]
+pub struct RoleExpiry {
+    pub expires_at: i64,
+}
+
+impl RoleExpiry {
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        timestamp >= self.expires_at
+    }
+}
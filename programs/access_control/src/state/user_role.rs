@@ -28,6 +28,11 @@ pub enum Role {
     AccountantAdmin,
     KYCProvider,
     KYCVerified,
+    ComplianceOfficer,
+    // blocklist entry, managed by the compliance officer
+    Sanctioned,
+    // hot key that can only pause
+    Guardian,
 }
 
 impl Role {
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 
 use crate::state::UserRole;
 
@@ -24,4 +25,17 @@ impl<'a> UserRoleAccInfo for AccountInfo<'a> {
         }
         roles.unwrap().has_role
     }
+}
+
+// same as anchor's `close` constraint, for accounts that may not exist
+pub fn close_account<'info>(info: &AccountInfo<'info>, destination: &AccountInfo<'info>) -> Result<()> {
+    if info.data_is_empty() {
+        return Ok(());
+    }
+
+    **destination.try_borrow_mut_lamports()? += info.lamports();
+    **info.try_borrow_mut_lamports()? = 0;
+
+    info.assign(&system_program::ID);
+    info.realloc(0, false).map_err(Into::into)
 }
\ No newline at end of file
@@ -1,3 +1,14 @@
+use anchor_lang::prelude::*;
+
 pub const FEE_BPS: u64 = 10_000;
 pub const CONFIG_SEED: &str = "config";
+pub const PAUSE_SEED: &str = "pause";
+pub const FEE_OVERRIDE_SEED: &str = "fee_override";
 pub const DISCRIMINATOR_LEN: usize = 8;
+pub const ACCOUNTANT_VERSION: u8 = 1;
+// shortest notice depositors get before a fee increase applies
+pub const MIN_FEE_CHANGE_DELAY: u64 = 86_400;
+
+pub const VAULT_USER_DATA_SEED: &str = "user_data";
+pub const VAULT_PRICE_HISTORY_SEED: &str = "price_history";
+pub const TOKENIZED_VAULT_PROGRAM_ID: Pubkey = pubkey!("8Y5ZEEnhiNdvGHbfiZVj2eSawrNrQTKd9jPEFqnnKizC");
@@ -13,4 +13,28 @@ pub enum ErrorCode {
 
     #[msg("Fee is invalid")]
     InvalidFee,
+
+    #[msg("Fee exceeds the configured maximum")]
+    FeeExceedsCap,
+
+    #[msg("Fee caps can only be lowered")]
+    FeeCapIncrease,
+
+    #[msg("Fee increase must go through the fee change delay")]
+    UndelayedFeeIncrease,
+
+    #[msg("Accountants are paused")]
+    Paused,
+
+    #[msg("Accountant uses an old layout, run migrate_accountant first")]
+    AccountantNotMigrated,
+
+    #[msg("Accountant is already on the current layout")]
+    AccountantAlreadyMigrated,
+
+    #[msg("Fee change delay is below the minimum")]
+    FeeChangeDelayTooShort,
+
+    #[msg("Fee change delay can only be raised")]
+    FeeChangeDelayDecrease,
 }
@@ -4,16 +4,77 @@ use anchor_lang::prelude::*;
 pub struct PerformanceFeeUpdatedEvent {
     pub accountant_key: Pubkey, 
     pub performance_fee: u64,
+    pub effective_at: u64,
 }
 
 #[event]
 pub struct EntryFeeUpdatedEvent {
     pub accountant_key: Pubkey, 
     pub entry_fee: u64,
+    pub effective_at: u64,
 }
 
 #[event]
 pub struct RedemptionFeeUpdatedEvent {
     pub accountant_key: Pubkey, 
     pub redemption_fee: u64,
-}
\ No newline at end of file
+    pub effective_at: u64,
+}
+#[event]
+pub struct FeeSharesRedeemedEvent {
+    pub accountant_key: Pubkey, 
+    pub vault_key: Pubkey,
+    pub shares: u64,
+    pub assets: u64,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct FeeOverrideUpdatedEvent {
+    pub accountant_key: Pubkey,
+    pub target: Pubkey,
+    pub entry_fee: Option<u64>,
+    pub redemption_fee: Option<u64>,
+    pub performance_fee: Option<u64>,
+}
+
+#[event]
+pub struct FeeOverrideRemovedEvent {
+    pub accountant_key: Pubkey,
+    pub target: Pubkey,
+}
+
+#[event]
+pub struct FeeCapsUpdatedEvent {
+    pub accountant_key: Pubkey,
+    pub max_entry_fee: u64,
+    pub max_redemption_fee: u64,
+    pub max_performance_fee: u64,
+}
+
+#[event]
+pub struct FeeChangeDelayUpdatedEvent {
+    pub accountant_key: Pubkey,
+    pub fee_change_delay: u64,
+}
+
+#[event]
+pub struct ReferralFeeUpdatedEvent {
+    pub accountant_key: Pubkey,
+    pub referral_fee: u64,
+}
+
+#[event]
+pub struct AccountantsPauseUpdatedEvent {
+    pub paused: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountantMigratedEvent {
+    pub account_key: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+    pub timestamp: i64,
+}
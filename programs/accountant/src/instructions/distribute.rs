@@ -10,6 +10,9 @@ use access_control::{
     state::{Role, UserRole}
 };
 
+use crate::constants::PAUSE_SEED;
+use crate::error::ErrorCode;
+use crate::utils::is_paused;
 use crate::utils::unchecked_accountant::UncheckedAccountant;
 
 #[derive(Accounts)]
@@ -35,6 +38,10 @@ pub struct Distribute<'info> {
     )]
     pub roles: Account<'info, UserRole>,
 
+    /// CHECK: this account may not exist
+    #[account(seeds = [PAUSE_SEED.as_bytes()], bump, constraint = !is_paused(&pause_state)? @ErrorCode::Paused)]
+    pub pause_state: UncheckedAccount<'info>,
+
     #[account(mut, constraint = roles.check_role()?)]
     pub signer: Signer<'info>,
 
@@ -56,5 +63,5 @@ pub struct Distribute<'info> {
 pub fn handle_distribute(ctx: Context<Distribute>) -> Result<()> {
     let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
     accountant.distribute(&ctx.accounts)?;
-    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])
+    ctx.accounts.accountant.save_changes(accountant.as_ref())
 }
\ No newline at end of file
@@ -48,10 +48,14 @@ pub struct InitAccountant<'info> {
     pub rent: Sysvar<'info, Rent>,
 }
 
-pub fn handle_init_accountant(ctx: Context<InitAccountant>, accountant_type: AccountantType) -> Result<()> {
+pub fn handle_init_accountant(
+    ctx: Context<InitAccountant>,
+    accountant_type: AccountantType,
+    config: AccountantConfig,
+) -> Result<()> {
     match accountant_type {
         AccountantType::Generic => {
-            return init_accountant_internal::<GenericAccountant>(ctx)
+            return init_accountant_internal::<GenericAccountant>(ctx, config)
         }
         // _ => {
         //     return Err(ErrorCode::InvalidData.into())
@@ -59,7 +63,7 @@ pub fn handle_init_accountant(ctx: Context<InitAccountant>, accountant_type: Acc
     }
 }
 
-fn init_accountant_internal<T>(ctx: Context<InitAccountant>) -> Result<()> 
+fn init_accountant_internal<T>(ctx: Context<InitAccountant>, config: AccountantConfig) -> Result<()> 
 where 
     T: Accountant + AnchorDeserialize + AnchorSerialize + Discriminator + Default
 {
@@ -71,7 +75,7 @@ where
     // we need to set the discriminator to the first 8 bytes of the account data
     data[..8].copy_from_slice(&T::discriminator());
 
-    accountant.init(ctx.accounts.config.next_accountant_index, ctx.bumps.accountant)?;
+    accountant.init(ctx.accounts.config.next_accountant_index, ctx.bumps.accountant, &config)?;
     ctx.accounts.config.next_accountant_index += 1;
 
     // Serialize the accountant data into the account
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::{ACCOUNTANT_VERSION, DISCRIMINATOR_LEN};
+use crate::error::ErrorCode;
+use crate::events::AccountantMigratedEvent;
+use crate::state::*;
+use crate::utils::unchecked_accountant::{read_layout, UncheckedAccountant};
+
+#[derive(Accounts)]
+pub struct MigrateAccountant<'info> {
+    /// CHECK: can be any accountant on any supported layout
+    #[account(mut, owner = crate::ID)]
+    pub accountant: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::AccountantAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_accountant(ctx: Context<MigrateAccountant>) -> Result<()> {
+    match ctx.accounts.accountant.get_discriminator()? {
+        GenericAccountant::DISCRIMINATOR => migrate::<GenericAccountant, GenericAccountantV0>(ctx),
+        _ => Err(ErrorCode::InvalidDiscriminator.into()),
+    }
+}
+
+fn migrate<T, V0>(ctx: Context<MigrateAccountant>) -> Result<()>
+where
+    T: Accountant + AnchorDeserialize + Space,
+    V0: AnchorDeserialize + Space + Into<T>,
+{
+    let accountant_info = ctx.accounts.accountant.to_account_info();
+    let new_len = DISCRIMINATOR_LEN + T::INIT_SPACE;
+
+    if accountant_info.data_len() == new_len {
+        return Err(ErrorCode::AccountantAlreadyMigrated.into());
+    }
+
+    let accountant = read_layout::<T, V0>(&accountant_info.try_borrow_data()?)?;
+
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(accountant_info.lamports());
+
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: accountant_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+
+    accountant_info.realloc(new_len, false)?;
+    ctx.accounts.accountant.save_changes(&accountant)?;
+
+    emit!(AccountantMigratedEvent {
+        account_key: accountant_info.key(),
+        old_version: 0,
+        new_version: ACCOUNTANT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -2,10 +2,18 @@ pub mod distribute;
 pub mod init_accountant;
 pub mod init_token_account;
 pub mod initialize;
+pub mod migrate_accountant;
+pub mod pause;
+pub mod redeem_and_distribute;
 pub mod set_fee;
+pub mod set_fee_override;
 
 pub use distribute::*;
 pub use init_accountant::*;
 pub use init_token_account::*;
 pub use initialize::*;
-pub use set_fee::*;
\ No newline at end of file
+pub use migrate_accountant::*;
+pub use pause::*;
+pub use redeem_and_distribute::*;
+pub use set_fee::*;
+pub use set_fee_override::*;
\ No newline at end of file
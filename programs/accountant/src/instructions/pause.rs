@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::{DISCRIMINATOR_LEN, PAUSE_SEED};
+use crate::events::AccountantsPauseUpdatedEvent;
+use crate::state::PauseState;
+
+#[derive(Accounts)]
+pub struct PauseAccountants<'info> {
+    #[account(
+        init_if_needed,
+        seeds = [PAUSE_SEED.as_bytes()],
+        bump,
+        payer = signer,
+        space = DISCRIMINATOR_LEN + PauseState::INIT_SPACE,
+    )]
+    pub pause_state: Account<'info, PauseState>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::Guardian.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnpauseAccountants<'info> {
+    #[account(mut, seeds = [PAUSE_SEED.as_bytes()], bump)]
+    pub pause_state: Account<'info, PauseState>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::AccountantAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+}
+
+pub fn handle_pause_accountants(ctx: Context<PauseAccountants>) -> Result<()> {
+    ctx.accounts.pause_state.paused = true;
+
+    emit!(AccountantsPauseUpdatedEvent {
+        paused: true,
+        authority: ctx.accounts.signer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn handle_unpause_accountants(ctx: Context<UnpauseAccountants>) -> Result<()> {
+    ctx.accounts.pause_state.paused = false;
+
+    emit!(AccountantsPauseUpdatedEvent {
+        paused: false,
+        authority: ctx.accounts.signer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked},
+};
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{Role, UserRole}
+};
+
+use crate::constants::{
+    FEE_OVERRIDE_SEED,
+    PAUSE_SEED,
+    TOKENIZED_VAULT_PROGRAM_ID,
+    VAULT_PRICE_HISTORY_SEED,
+    VAULT_USER_DATA_SEED,
+};
+use crate::error::ErrorCode;
+use crate::events::FeeSharesRedeemedEvent;
+use crate::utils::is_paused;
+use crate::utils::unchecked_accountant::UncheckedAccountant;
+use crate::utils::vault::{self, RedeemAccounts};
+
+#[derive(Accounts)]
+pub struct RedeemAndDistribute<'info> {
+    /// CHECK: can be any accountant
+    #[account(mut)]
+    pub accountant: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the vault program
+    #[account(mut)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the vault program
+    #[account(mut)]
+    pub vault_token_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = shares_mint, 
+        associated_token::authority = accountant,
+        associated_token::token_program = shares_token_program,
+    )]
+    pub shares_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            FEE_OVERRIDE_SEED.as_bytes(),
+            accountant.key().as_ref(),
+            vault.key().as_ref()
+        ],
+        bump,
+    )]
+    pub vault_fee_override: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = underlying_mint, 
+        associated_token::authority = accountant,
+        associated_token::token_program = token_program,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = underlying_mint,
+        token::token_program = token_program,
+    )]
+    pub recipient: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        mut,
+        seeds = [
+            VAULT_USER_DATA_SEED.as_bytes(), 
+            vault.key().as_ref(), 
+            accountant.key().as_ref()
+        ], 
+        bump,
+        seeds::program = vault_program.key()
+    )]
+    pub user_data: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            accountant.key().as_ref(),
+            Role::Sanctioned.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub sanctioned: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        mut,
+        seeds = [VAULT_PRICE_HISTORY_SEED.as_bytes(), vault.key().as_ref()],
+        bump,
+        seeds::program = vault_program.key()
+    )]
+    pub price_history: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::AccountantAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    /// CHECK: this account may not exist
+    #[account(seeds = [PAUSE_SEED.as_bytes()], bump, constraint = !is_paused(&pause_state)? @ErrorCode::Paused)]
+    pub pause_state: UncheckedAccount<'info>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    /// CHECK: validated by the vault program
+    pub strategy_program: UncheckedAccount<'info>,
+
+    /// CHECK: checked against the known vault program id
+    #[account(address = TOKENIZED_VAULT_PROGRAM_ID)]
+    pub vault_program: UncheckedAccount<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub shares_token_program: Interface<'info, TokenInterface>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+// redeems from the vault's idle only, see `vault::redeem_instruction`
+pub fn handle_redeem_and_distribute(ctx: Context<RedeemAndDistribute>) -> Result<()> {
+    let accountant = ctx.accounts.accountant.from_unchecked()?;
+    let shares = ctx.accounts.shares_token_account.amount;
+
+    if shares > 0 {
+        vault::redeem(
+            RedeemAccounts {
+                vault: ctx.accounts.vault.to_account_info(),
+                user_token_account: ctx.accounts.token_account.to_account_info(),
+                vault_token_account: ctx.accounts.vault_token_account.to_account_info(),
+                accountant: ctx.accounts.accountant.to_account_info(),
+                accountant_recipient: ctx.accounts.shares_token_account.to_account_info(),
+                vault_fee_override: ctx.accounts.vault_fee_override.to_account_info(),
+                accountant_token_account: ctx.accounts.token_account.to_account_info(),
+                shares_mint: ctx.accounts.shares_mint.to_account_info(),
+                underlying_mint: ctx.accounts.underlying_mint.to_account_info(),
+                user_shares_account: ctx.accounts.shares_token_account.to_account_info(),
+                user_data: ctx.accounts.user_data.to_account_info(),
+                sanctioned: ctx.accounts.sanctioned.to_account_info(),
+                price_history: ctx.accounts.price_history.to_account_info(),
+                user: ctx.accounts.accountant.to_account_info(),
+                owner: ctx.accounts.accountant.to_account_info(),
+                shares_token_program: ctx.accounts.shares_token_program.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+                strategy_program: ctx.accounts.strategy_program.to_account_info(),
+                access_control: ctx.accounts.access_control.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                vault_program: ctx.accounts.vault_program.to_account_info(),
+            },
+            shares,
+            &[&accountant.seeds()],
+        )?;
+    }
+
+    ctx.accounts.token_account.reload()?;
+    let assets = ctx.accounts.token_account.amount;
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.token_account.to_account_info(),
+                mint: ctx.accounts.underlying_mint.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+                authority: ctx.accounts.accountant.to_account_info(),
+            },
+            &[&accountant.seeds()],
+        ),
+        assets,
+        ctx.accounts.underlying_mint.decimals,
+    )?;
+
+    emit!(FeeSharesRedeemedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        vault_key: ctx.accounts.vault.key(),
+        shares,
+        assets,
+        recipient: ctx.accounts.recipient.key(),
+    });
+
+    Ok(())
+}
@@ -5,7 +5,8 @@ use access_control::{
     state::{UserRole, Role}
 };
 
-use crate::events::{EntryFeeUpdatedEvent, PerformanceFeeUpdatedEvent, RedemptionFeeUpdatedEvent};
+use crate::events::{EntryFeeUpdatedEvent, FeeCapsUpdatedEvent, FeeChangeDelayUpdatedEvent, PerformanceFeeUpdatedEvent, RedemptionFeeUpdatedEvent, ReferralFeeUpdatedEvent};
+use crate::state::FeeCaps;
 use crate::utils::unchecked_accountant::UncheckedAccountant;
 
 #[derive(Accounts)]
@@ -37,12 +38,13 @@ pub fn handle_set_performance_fee(
 ) -> Result<()> {
     let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
 
-    accountant.set_performance_fee(fee)?;
-    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])?;
+    let effective_at = accountant.set_performance_fee(fee)?;
+    ctx.accounts.accountant.save_changes(accountant.as_ref())?;
 
     emit!(PerformanceFeeUpdatedEvent {
         accountant_key: ctx.accounts.accountant.key(),
         performance_fee: fee,
+        effective_at,
     });
 
     Ok(())
@@ -55,12 +57,13 @@ pub fn handle_set_entry_fee(
 ) -> Result<()> {
     let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
 
-    accountant.set_entry_fee(fee)?;
-    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])?;
+    let effective_at = accountant.set_entry_fee(fee)?;
+    ctx.accounts.accountant.save_changes(accountant.as_ref())?;
 
     emit!(EntryFeeUpdatedEvent {
         accountant_key: ctx.accounts.accountant.key(),
         entry_fee: fee,
+        effective_at,
     });
 
     Ok(())
@@ -72,14 +75,67 @@ pub fn handle_set_redemption_fee(
 ) -> Result<()> {
     let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
 
-    accountant.set_redemption_fee(fee)?;
-    accountant.save_changes(&mut &mut ctx.accounts.accountant.try_borrow_mut_data()?[8..])?;
+    let effective_at = accountant.set_redemption_fee(fee)?;
+    ctx.accounts.accountant.save_changes(accountant.as_ref())?;
 
     emit!(RedemptionFeeUpdatedEvent {
         accountant_key: ctx.accounts.accountant.key(),
         redemption_fee: fee,
+        effective_at,
     });
 
     Ok(())
 }
 
+pub fn handle_set_fee_caps(
+    ctx: Context<SetFee>, 
+    caps: FeeCaps,
+) -> Result<()> {
+    let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
+
+    accountant.set_fee_caps(&caps)?;
+    ctx.accounts.accountant.save_changes(accountant.as_ref())?;
+
+    emit!(FeeCapsUpdatedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        max_entry_fee: caps.max_entry_fee,
+        max_redemption_fee: caps.max_redemption_fee,
+        max_performance_fee: caps.max_performance_fee,
+    });
+
+    Ok(())
+}
+
+pub fn handle_set_fee_change_delay(
+    ctx: Context<SetFee>, 
+    delay: u64,
+) -> Result<()> {
+    let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
+
+    accountant.set_fee_change_delay(delay)?;
+    ctx.accounts.accountant.save_changes(accountant.as_ref())?;
+
+    emit!(FeeChangeDelayUpdatedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        fee_change_delay: delay,
+    });
+
+    Ok(())
+}
+
+pub fn handle_set_referral_fee(
+    ctx: Context<SetFee>, 
+    fee: u64,
+) -> Result<()> {
+    let accountant = &mut ctx.accounts.accountant.from_unchecked()?;
+
+    accountant.set_referral_fee(fee)?;
+    ctx.accounts.accountant.save_changes(accountant.as_ref())?;
+
+    emit!(ReferralFeeUpdatedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        referral_fee: fee,
+    });
+
+    Ok(())
+}
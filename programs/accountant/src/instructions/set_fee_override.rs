@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::FEE_OVERRIDE_SEED;
+use crate::events::{FeeOverrideRemovedEvent, FeeOverrideUpdatedEvent};
+use crate::state::{FeeOverride, FeeOverrideConfig};
+use crate::utils::unchecked_accountant::UncheckedAccountant;
+
+#[derive(Accounts)]
+#[instruction(target: Pubkey)]
+pub struct SetFeeOverride<'info> {
+    /// CHECK: can be any accountant
+    #[account(owner = crate::ID)]
+    pub accountant: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = FeeOverride::LEN,
+        seeds = [
+            FEE_OVERRIDE_SEED.as_bytes(),
+            accountant.key().as_ref(),
+            target.as_ref()
+        ],
+        bump,
+    )]
+    pub fee_override: Account<'info, FeeOverride>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::AccountantAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(target: Pubkey)]
+pub struct RemoveFeeOverride<'info> {
+    /// CHECK: can be any accountant
+    #[account(owner = crate::ID)]
+    pub accountant: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = signer,
+        seeds = [
+            FEE_OVERRIDE_SEED.as_bytes(),
+            accountant.key().as_ref(),
+            target.as_ref()
+        ],
+        bump,
+    )]
+    pub fee_override: Account<'info, FeeOverride>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::AccountantAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+}
+
+pub fn handle_set_fee_override(
+    ctx: Context<SetFeeOverride>, 
+    target: Pubkey,
+    config: FeeOverrideConfig,
+) -> Result<()> {
+    let accountant = ctx.accounts.accountant.from_unchecked()?;
+
+    ctx.accounts.fee_override.update(
+        ctx.accounts.accountant.key(),
+        target,
+        &config,
+        accountant.as_ref(),
+        Clock::get()?.unix_timestamp as u64,
+    )?;
+
+    emit!(FeeOverrideUpdatedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        target,
+        entry_fee: config.entry_fee,
+        redemption_fee: config.redemption_fee,
+        performance_fee: config.performance_fee,
+    });
+
+    Ok(())
+}
+
+pub fn handle_remove_fee_override(ctx: Context<RemoveFeeOverride>, target: Pubkey) -> Result<()> {
+    let accountant = ctx.accounts.accountant.from_unchecked()?;
+    ctx.accounts.fee_override.validate_removal(accountant.as_ref(), Clock::get()?.unix_timestamp as u64)?;
+
+    emit!(FeeOverrideRemovedEvent {
+        accountant_key: ctx.accounts.accountant.key(),
+        target,
+    });
+
+    Ok(())
+}
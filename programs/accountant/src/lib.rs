@@ -34,18 +34,27 @@ pub mod accountant {
     pub fn init_accountant(
         ctx: Context<InitAccountant>,
         accountant_type: AccountantType,
+        config: AccountantConfig,
     ) -> Result<()> {
-        handle_init_accountant(ctx, accountant_type)
+        handle_init_accountant(ctx, accountant_type, config)
     }
 
     pub fn init_token_account(ctx: Context<InitTokenAccount>) -> Result<()> {
         handle_init_token_account(ctx)
     }
 
+    pub fn migrate_accountant(ctx: Context<MigrateAccountant>) -> Result<()> {
+        handle_migrate_accountant(ctx)
+    }
+
     pub fn distribute(ctx: Context<Distribute>) -> Result<()> {
         handle_distribute(ctx)
     }
 
+    pub fn redeem_and_distribute(ctx: Context<RedeemAndDistribute>) -> Result<()> {
+        handle_redeem_and_distribute(ctx)
+    }
+
     pub fn set_performance_fee(ctx: Context<SetFee>, fee: u64) -> Result<()> {
         handle_set_performance_fee(ctx, fee)
     }
@@ -57,4 +66,36 @@ pub mod accountant {
     pub fn set_entry_fee(ctx: Context<SetFee>, fee: u64) -> Result<()> {
         handle_set_entry_fee(ctx, fee)
     }
+
+    pub fn set_fee_caps(ctx: Context<SetFee>, caps: FeeCaps) -> Result<()> {
+        handle_set_fee_caps(ctx, caps)
+    }
+
+    pub fn set_fee_change_delay(ctx: Context<SetFee>, delay: u64) -> Result<()> {
+        handle_set_fee_change_delay(ctx, delay)
+    }
+
+    pub fn set_referral_fee(ctx: Context<SetFee>, fee: u64) -> Result<()> {
+        handle_set_referral_fee(ctx, fee)
+    }
+
+    pub fn set_fee_override(
+        ctx: Context<SetFeeOverride>,
+        target: Pubkey,
+        config: FeeOverrideConfig,
+    ) -> Result<()> {
+        handle_set_fee_override(ctx, target, config)
+    }
+
+    pub fn remove_fee_override(ctx: Context<RemoveFeeOverride>, target: Pubkey) -> Result<()> {
+        handle_remove_fee_override(ctx, target)
+    }
+
+    pub fn pause_accountants(ctx: Context<PauseAccountants>) -> Result<()> {
+        handle_pause_accountants(ctx)
+    }
+
+    pub fn unpause_accountants(ctx: Context<UnpauseAccountants>) -> Result<()> {
+        handle_unpause_accountants(ctx)
+    }
 }
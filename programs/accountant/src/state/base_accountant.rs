@@ -1,26 +1,35 @@
 use anchor_lang::prelude::*;
 
 use crate::instructions::Distribute;
-use crate::state::GenericAccountant;
+use crate::state::{AccountantConfig, FeeCaps, FeeOverride, GenericAccountant};
 
 const DISCRIMINATOR_LEN: usize = 8;
 
 pub trait Accountant {
-    fn init(&mut self, index: u64, bump: u8) -> Result<()>;
+    fn init(&mut self, index: u64, bump: u8, config: &AccountantConfig) -> Result<()>;
 
-    fn report(&self, profit: u64, loss: u64) -> Result<(u64,u64)>;
-    fn enter(&self, amount: u64) -> Result<u64>;
-    fn redeem(&self, amount: u64) -> Result<u64>;
+    fn report(&self, vault: &Pubkey, strategy: &Pubkey, overrides: &[FeeOverride], profit: u64, loss: u64) -> Result<(u64,u64)>;
+    fn enter(&self, vault: &Pubkey, overrides: &[FeeOverride], amount: u64) -> Result<u64>;
+    fn redeem(&self, vault: &Pubkey, overrides: &[FeeOverride], amount: u64) -> Result<u64>;
     
     fn distribute(&mut self, accounts: &Distribute) -> Result<()>;
 
-    fn set_performance_fee(&mut self, fee: u64) -> Result<()>;
-    fn set_redemption_fee(&mut self, fee: u64) -> Result<()>;
-    fn set_entry_fee(&mut self, fee: u64) -> Result<()>;
-
-    fn entry_fee(&self) -> u64;
-    fn redemption_fee(&self) -> u64;
-    fn performance_fee(&self) -> u64;
+    // setters return the timestamp the new fee is effective at
+    fn set_performance_fee(&mut self, fee: u64) -> Result<u64>;
+    fn set_redemption_fee(&mut self, fee: u64) -> Result<u64>;
+    fn set_entry_fee(&mut self, fee: u64) -> Result<u64>;
+    // caps and the delay can only be tightened
+    fn set_fee_caps(&mut self, caps: &FeeCaps) -> Result<()>;
+    fn set_fee_change_delay(&mut self, delay: u64) -> Result<()>;
+    // share of the entry fee (in bps) routed to a depositor's referrer
+    fn set_referral_fee(&mut self, fee: u64) -> Result<()>;
+
+    fn entry_fee(&self) -> Result<u64>;
+    fn redemption_fee(&self) -> Result<u64>;
+    fn performance_fee(&self) -> Result<u64>;
+    fn referral_fee(&self) -> u64;
+    fn fee_caps(&self) -> FeeCaps;
+    fn fee_change_delay(&self) -> u64;
 
     fn seeds(&self) -> [&[u8]; 2];
     fn save_changes(&self, writer: &mut dyn std::io::Write) -> Result<()>;
@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::DISCRIMINATOR_LEN;
+use crate::error::ErrorCode;
+use crate::state::base_accountant::Accountant;
+use crate::state::fee_schedule::{current_fee, schedule_fee, PendingFee};
+
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct FeeOverride {
+    pub accountant: Pubkey,
+    pub target: Pubkey,
+
+    pub entry_fee: Option<u64>,
+    pub redemption_fee: Option<u64>,
+    pub performance_fee: Option<u64>,
+
+    pub pending_entry_fee: PendingFee,
+    pub pending_redemption_fee: PendingFee,
+    pub pending_performance_fee: PendingFee,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct FeeOverrideConfig {
+    pub entry_fee: Option<u64>,
+    pub redemption_fee: Option<u64>,
+    pub performance_fee: Option<u64>,
+}
+
+impl FeeOverride {
+    pub const LEN: usize = DISCRIMINATOR_LEN + Self::INIT_SPACE;
+
+    pub fn update(
+        &mut self,
+        accountant_key: Pubkey,
+        target: Pubkey,
+        config: &FeeOverrideConfig,
+        accountant: &dyn Accountant,
+        now: u64,
+    ) -> Result<()> {
+        let caps = accountant.fee_caps();
+        let delay = accountant.fee_change_delay();
+
+        self.accountant = accountant_key;
+        self.target = target;
+
+        update_fee(
+            &mut self.entry_fee,
+            &mut self.pending_entry_fee,
+            config.entry_fee,
+            accountant.entry_fee()?,
+            caps.max_entry_fee,
+            delay,
+            now,
+        )?;
+        update_fee(
+            &mut self.redemption_fee,
+            &mut self.pending_redemption_fee,
+            config.redemption_fee,
+            accountant.redemption_fee()?,
+            caps.max_redemption_fee,
+            delay,
+            now,
+        )?;
+        update_fee(
+            &mut self.performance_fee,
+            &mut self.pending_performance_fee,
+            config.performance_fee,
+            accountant.performance_fee()?,
+            caps.max_performance_fee,
+            delay,
+            now,
+        )
+    }
+
+    // dropping an override must not raise the fee charged to the target
+    pub fn validate_removal(&self, accountant: &dyn Accountant, now: u64) -> Result<()> {
+        let caps = accountant.fee_caps();
+
+        validate_fee_removal(self.entry_fee, &self.pending_entry_fee, accountant.entry_fee()?, caps.max_entry_fee, now)?;
+        validate_fee_removal(self.redemption_fee, &self.pending_redemption_fee, accountant.redemption_fee()?, caps.max_redemption_fee, now)?;
+        validate_fee_removal(self.performance_fee, &self.pending_performance_fee, accountant.performance_fee()?, caps.max_performance_fee, now)
+    }
+}
+
+fn update_fee(
+    fee: &mut Option<u64>,
+    pending: &mut PendingFee,
+    new_fee: Option<u64>,
+    base_fee: u64,
+    cap: u64,
+    delay: u64,
+    now: u64,
+) -> Result<()> {
+    match new_fee {
+        Some(new_fee) => {
+            // a new override starts from the accountant's fee
+            let mut current = fee.unwrap_or(base_fee);
+            schedule_fee(&mut current, pending, new_fee, cap, delay, now)?;
+            *fee = Some(current);
+        }
+        None => {
+            validate_fee_removal(*fee, pending, base_fee, cap, now)?;
+            *fee = None;
+            *pending = PendingFee::default();
+        }
+    }
+    Ok(())
+}
+
+fn validate_fee_removal(fee: Option<u64>, pending: &PendingFee, base_fee: u64, cap: u64, now: u64) -> Result<()> {
+    if let Some(fee) = fee {
+        if base_fee > current_fee(fee, pending, cap, now) {
+            return Err(ErrorCode::UndelayedFeeIncrease.into());
+        }
+    }
+    Ok(())
+}
+
+/// Returns the fee of the first override matching one of `targets`, in the order of `targets`.
+pub fn resolve_fee<F>(overrides: &[FeeOverride], targets: &[Pubkey], fee: F) -> Option<u64>
+    where F: Fn(&FeeOverride) -> Option<u64>
+{
+    targets.iter().find_map(|target| {
+        overrides
+            .iter()
+            .filter(|o| o.target == *target)
+            .find_map(|o| fee(o))
+    })
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{FEE_BPS, MIN_FEE_CHANGE_DELAY};
+use crate::error::ErrorCode;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct FeeCaps {
+    pub max_entry_fee: u64,
+    pub max_redemption_fee: u64,
+    pub max_performance_fee: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct AccountantConfig {
+    pub fee_caps: FeeCaps,
+    pub fee_change_delay: u64,
+    // fees set at init apply right away, every later increase waits for the delay
+    pub entry_fee: u64,
+    pub redemption_fee: u64,
+    pub performance_fee: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, InitSpace)]
+pub struct PendingFee {
+    pub fee: u64,
+    pub effective_at: u64,
+}
+
+impl FeeCaps {
+    pub fn validate(&self) -> Result<()> {
+        if self.max_entry_fee > FEE_BPS || self.max_redemption_fee > FEE_BPS || self.max_performance_fee > FEE_BPS {
+            return Err(ErrorCode::InvalidFee.into());
+        }
+        Ok(())
+    }
+
+    pub fn lower(&mut self, caps: &FeeCaps) -> Result<()> {
+        if caps.max_entry_fee > self.max_entry_fee
+            || caps.max_redemption_fee > self.max_redemption_fee
+            || caps.max_performance_fee > self.max_performance_fee
+        {
+            return Err(ErrorCode::FeeCapIncrease.into());
+        }
+
+        *self = *caps;
+        Ok(())
+    }
+}
+
+impl AccountantConfig {
+    pub fn validate(&self) -> Result<()> {
+        self.fee_caps.validate()?;
+
+        if self.fee_change_delay < MIN_FEE_CHANGE_DELAY {
+            return Err(ErrorCode::FeeChangeDelayTooShort.into());
+        }
+
+        if self.entry_fee > self.fee_caps.max_entry_fee
+            || self.redemption_fee > self.fee_caps.max_redemption_fee
+            || self.performance_fee > self.fee_caps.max_performance_fee
+        {
+            return Err(ErrorCode::FeeExceedsCap.into());
+        }
+        Ok(())
+    }
+}
+
+impl PendingFee {
+    pub fn is_set(&self) -> bool {
+        self.effective_at != 0
+    }
+
+    pub fn is_due(&self, now: u64) -> bool {
+        self.is_set() && now >= self.effective_at
+    }
+}
+
+/// Fee in effect at `now`, taking a due pending change and the cap into account.
+pub fn current_fee(fee: u64, pending: &PendingFee, cap: u64, now: u64) -> u64 {
+    let fee = if pending.is_due(now) { pending.fee } else { fee };
+    std::cmp::min(fee, cap)
+}
+
+/// Decreases apply right away, increases only after `delay` seconds.
+/// Returns the timestamp the new fee becomes effective at.
+pub fn schedule_fee(
+    fee: &mut u64,
+    pending: &mut PendingFee,
+    new_fee: u64,
+    cap: u64,
+    delay: u64,
+    now: u64,
+) -> Result<u64> {
+    if new_fee > cap {
+        return Err(ErrorCode::FeeExceedsCap.into());
+    }
+
+    *fee = current_fee(*fee, pending, cap, now);
+    *pending = PendingFee::default();
+
+    if new_fee <= *fee || delay == 0 {
+        *fee = new_fee;
+        return Ok(now);
+    }
+
+    *pending = PendingFee {
+        fee: new_fee,
+        effective_at: now + delay,
+    };
+    Ok(pending.effective_at)
+}
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Transfer};
 
 use crate::state::base_accountant::Accountant;
+use crate::state::fee_override::{resolve_fee, FeeOverride};
+use crate::state::fee_schedule::{current_fee, schedule_fee, AccountantConfig, FeeCaps, PendingFee};
 use crate::instructions::Distribute;
 use crate::error::ErrorCode;
 use crate::constants::FEE_BPS;
@@ -15,12 +17,28 @@ pub struct GenericAccountant {
     pub entry_fee: u64,
     pub redemption_fee: u64,
     pub performance_fee: u64,
+
+    pub fee_caps: FeeCaps,
+    pub fee_change_delay: u64,
+
+    pub pending_entry_fee: PendingFee,
+    pub pending_redemption_fee: PendingFee,
+    pub pending_performance_fee: PendingFee,
+
+    pub referral_fee: u64,
 }
 
 impl Accountant for GenericAccountant {
-    fn init(&mut self, index: u64, bump: u8) -> Result<()> {
+    fn init(&mut self, index: u64, bump: u8, config: &AccountantConfig) -> Result<()> {
+        config.validate()?;
+
         self.index_buffer = index.to_le_bytes();
         self.bump[0] = bump;
+        self.fee_caps = config.fee_caps;
+        self.fee_change_delay = config.fee_change_delay;
+        self.entry_fee = config.entry_fee;
+        self.redemption_fee = config.redemption_fee;
+        self.performance_fee = config.performance_fee;
         Ok(())
     }
 
@@ -31,19 +49,37 @@ impl Accountant for GenericAccountant {
         ]
     }
 
-    fn report(&self, profit: u64, _loss: u64) -> Result<(u64, u64)> {
-        let total_fees = self.performance_fee * profit / FEE_BPS;
+    fn report(&self, vault: &Pubkey, strategy: &Pubkey, overrides: &[FeeOverride], profit: u64, _loss: u64) -> Result<(u64, u64)> {
+        let now = get_timestamp()?;
+        let cap = self.fee_caps.max_performance_fee;
+        let performance_fee = resolve_fee(overrides, &[*strategy, *vault], |o| {
+            o.performance_fee.map(|fee| current_fee(fee, &o.pending_performance_fee, cap, now))
+        }).unwrap_or(current_fee(self.performance_fee, &self.pending_performance_fee, cap, now));
+
+        let total_fees = performance_fee * profit / FEE_BPS;
         let total_refunds = 0;
         Ok((total_fees, total_refunds))
     }
 
-    fn enter(&self, amount: u64) -> Result<u64> {
-        let fee = self.entry_fee * amount / FEE_BPS;
+    fn enter(&self, vault: &Pubkey, overrides: &[FeeOverride], amount: u64) -> Result<u64> {
+        let now = get_timestamp()?;
+        let cap = self.fee_caps.max_entry_fee;
+        let entry_fee = resolve_fee(overrides, &[*vault], |o| {
+            o.entry_fee.map(|fee| current_fee(fee, &o.pending_entry_fee, cap, now))
+        }).unwrap_or(current_fee(self.entry_fee, &self.pending_entry_fee, cap, now));
+
+        let fee = entry_fee * amount / FEE_BPS;
         Ok(fee)
     }
 
-    fn redeem(&self, amount: u64) -> Result<u64> {
-        let fee = self.redemption_fee * amount / FEE_BPS;
+    fn redeem(&self, vault: &Pubkey, overrides: &[FeeOverride], amount: u64) -> Result<u64> {
+        let now = get_timestamp()?;
+        let cap = self.fee_caps.max_redemption_fee;
+        let redemption_fee = resolve_fee(overrides, &[*vault], |o| {
+            o.redemption_fee.map(|fee| current_fee(fee, &o.pending_redemption_fee, cap, now))
+        }).unwrap_or(current_fee(self.redemption_fee, &self.pending_redemption_fee, cap, now));
+
+        let fee = redemption_fee * amount / FEE_BPS;
         Ok(fee)
     }
 
@@ -64,43 +100,81 @@ impl Accountant for GenericAccountant {
         )
     }
 
-    fn set_performance_fee(&mut self, fee: u64) -> Result<()> {
-        if fee > FEE_BPS {
-            return Err(ErrorCode::InvalidFee.into());
-        }
+    fn set_performance_fee(&mut self, fee: u64) -> Result<u64> {
+        schedule_fee(
+            &mut self.performance_fee,
+            &mut self.pending_performance_fee,
+            fee,
+            self.fee_caps.max_performance_fee,
+            self.fee_change_delay,
+            get_timestamp()?,
+        )
+    }
 
-        self.performance_fee = fee;
-        Ok(())
+    fn set_redemption_fee(&mut self, fee: u64) -> Result<u64> {
+        schedule_fee(
+            &mut self.redemption_fee,
+            &mut self.pending_redemption_fee,
+            fee,
+            self.fee_caps.max_redemption_fee,
+            self.fee_change_delay,
+            get_timestamp()?,
+        )
     }
 
-    fn set_redemption_fee(&mut self, fee: u64) -> Result<()> {
-        if fee > FEE_BPS {
-            return Err(ErrorCode::InvalidFee.into());
-        }
+    fn set_entry_fee(&mut self, fee: u64) -> Result<u64> {
+        schedule_fee(
+            &mut self.entry_fee,
+            &mut self.pending_entry_fee,
+            fee,
+            self.fee_caps.max_entry_fee,
+            self.fee_change_delay,
+            get_timestamp()?,
+        )
+    }
 
-        self.redemption_fee = fee;
+    fn set_fee_caps(&mut self, caps: &FeeCaps) -> Result<()> {
+        self.fee_caps.lower(caps)
+    }
+
+    fn set_fee_change_delay(&mut self, delay: u64) -> Result<()> {
+        if delay < self.fee_change_delay {
+            return Err(ErrorCode::FeeChangeDelayDecrease.into());
+        }
+        self.fee_change_delay = delay;
         Ok(())
     }
 
-    fn set_entry_fee(&mut self, fee: u64) -> Result<()> {
+    fn set_referral_fee(&mut self, fee: u64) -> Result<()> {
         if fee > FEE_BPS {
             return Err(ErrorCode::InvalidFee.into());
         }
-        
-        self.entry_fee = fee;
+        self.referral_fee = fee;
         Ok(())
     }
 
-    fn performance_fee(&self) -> u64 {
-        self.performance_fee
+    fn performance_fee(&self) -> Result<u64> {
+        Ok(current_fee(self.performance_fee, &self.pending_performance_fee, self.fee_caps.max_performance_fee, get_timestamp()?))
     }
 
-    fn entry_fee(&self) -> u64 {
-        self.entry_fee
+    fn entry_fee(&self) -> Result<u64> {
+        Ok(current_fee(self.entry_fee, &self.pending_entry_fee, self.fee_caps.max_entry_fee, get_timestamp()?))
     }
 
-    fn redemption_fee(&self) -> u64 {
-        self.redemption_fee
+    fn redemption_fee(&self) -> Result<u64> {
+        Ok(current_fee(self.redemption_fee, &self.pending_redemption_fee, self.fee_caps.max_redemption_fee, get_timestamp()?))
+    }
+
+    fn referral_fee(&self) -> u64 {
+        self.referral_fee
+    }
+
+    fn fee_caps(&self) -> FeeCaps {
+        self.fee_caps
+    }
+
+    fn fee_change_delay(&self) -> u64 {
+        self.fee_change_delay
     }
 
     fn save_changes(&self, writer: &mut dyn std::io::Write) -> Result<()> {
@@ -110,3 +184,7 @@ impl Accountant for GenericAccountant {
     }
     
 }
+
+fn get_timestamp() -> Result<u64> {
+    Ok(Clock::get()?.unix_timestamp as u64)
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use super::fee_schedule::{FeeCaps, PendingFee};
+use super::GenericAccountant;
+use crate::constants::{FEE_BPS, MIN_FEE_CHANGE_DELAY};
+
+// snapshot of the unversioned accountant layout, never edit it.
+// `UncheckedAccountant::from_unchecked` still reads it and `migrate_accountant` upgrades it in place.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct GenericAccountantV0 {
+    pub index_buffer: [u8; 8],
+    pub bump: [u8; 1],
+
+    pub entry_fee: u64,
+    pub redemption_fee: u64,
+    pub performance_fee: u64,
+}
+
+impl From<GenericAccountantV0> for GenericAccountant {
+    fn from(old: GenericAccountantV0) -> Self {
+        GenericAccountant {
+            index_buffer: old.index_buffer,
+            bump: old.bump,
+            entry_fee: old.entry_fee,
+            redemption_fee: old.redemption_fee,
+            performance_fee: old.performance_fee,
+            // old accountants were only bounded by FEE_BPS, keep them that way until an admin lowers the caps.
+            // increases still wait for the minimum delay, so depositors get notice before any raise.
+            fee_caps: FeeCaps {
+                max_entry_fee: FEE_BPS,
+                max_redemption_fee: FEE_BPS,
+                max_performance_fee: FEE_BPS,
+            },
+            fee_change_delay: MIN_FEE_CHANGE_DELAY,
+            pending_entry_fee: PendingFee::default(),
+            pending_redemption_fee: PendingFee::default(),
+            pending_performance_fee: PendingFee::default(),
+            referral_fee: 0,
+        }
+    }
+}
@@ -1,7 +1,15 @@
 pub mod base_accountant;
 pub mod config;
-pub mod generic_accountant;  
+pub mod pause_state;
+pub mod fee_override;
+pub mod fee_schedule;
+pub mod generic_accountant;
+pub mod generic_accountant_v0;
 
 pub use base_accountant::*;
 pub use config::*;
-pub use generic_accountant::*;
\ No newline at end of file
+pub use pause_state::*;
+pub use fee_override::*;
+pub use fee_schedule::*;
+pub use generic_accountant::*;
+pub use generic_accountant_v0::*;
\ No newline at end of file
@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+// kept out of `Config` so the deployed singleton keeps its size, a missing account means not paused
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct PauseState {
+    pub paused: bool,
+}
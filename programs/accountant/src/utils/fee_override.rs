@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::error::ErrorCode;
+use crate::state::FeeOverride;
+
+// override accounts are optional, missing ones are skipped
+pub fn load_fee_overrides(accountant: &Pubkey, accounts: &[AccountInfo]) -> Result<Vec<FeeOverride>> {
+    let mut overrides = Vec::new();
+
+    for acc in accounts {
+        if acc.data_is_empty() {
+            continue;
+        }
+
+        if *acc.owner != crate::ID {
+            return Err(ErrorCode::InvalidData.into());
+        }
+
+        let fee_override = FeeOverride::try_deserialize(&mut &acc.try_borrow_data()?[..])?;
+        if fee_override.accountant != *accountant {
+            return Err(ErrorCode::InvalidData.into());
+        }
+
+        overrides.push(fee_override);
+    }
+
+    Ok(overrides)
+}
@@ -1,3 +1,8 @@
+pub mod fee_override;
+pub mod pause;
 pub mod unchecked_accountant;
+pub mod vault;
 
-pub use unchecked_accountant::*;
\ No newline at end of file
+pub use fee_override::*;
+pub use pause::*;
+pub use unchecked_accountant::*;
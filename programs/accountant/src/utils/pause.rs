@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PauseState;
+
+pub fn is_paused(pause_state: &AccountInfo) -> Result<bool> {
+    if pause_state.data_is_empty() {
+        return Ok(false);
+    }
+    let data = pause_state.try_borrow_data()?;
+    Ok(PauseState::try_deserialize(&mut &data[..])?.paused)
+}
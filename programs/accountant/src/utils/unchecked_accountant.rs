@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_lang::Discriminator;
 
+use crate::constants::DISCRIMINATOR_LEN;
 use crate::state::*;
 use crate::error::ErrorCode;
 use crate::state::GenericAccountant;
@@ -8,8 +9,7 @@ use crate::state::GenericAccountant;
 pub trait UncheckedAccountant {
     fn get_discriminator(&self) -> Result<[u8; 8]>;
     fn from_unchecked(&self) -> Result<Box<dyn Accountant>>;
-    fn save_changes<T>(&self, accountant: Box<T>) -> Result<()>
-        where T: Accountant + AnchorSerialize;
+    fn save_changes(&self, accountant: &dyn Accountant) -> Result<()>;
     
 }
 
@@ -26,9 +26,8 @@ impl<'a> UncheckedAccountant for UncheckedAccount<'a> {
 
         match discriminator {
             GenericAccountant::DISCRIMINATOR => {
-                let strategy = GenericAccountant::try_from_slice(&data[8..])
-                    .map_err(|_| ErrorCode::InvalidData)?;
-                Ok(Box::new(strategy))
+                let accountant = read_layout::<GenericAccountant, GenericAccountantV0>(&data)?;
+                Ok(Box::new(accountant))
             }
             _ => {
                 msg!("Invalid discriminator");
@@ -37,12 +36,37 @@ impl<'a> UncheckedAccountant for UncheckedAccount<'a> {
         }
     }
 
-    fn save_changes<T>(&self, accountant: Box<T>) -> Result<()>
-        where T: Accountant + AnchorSerialize
-    {
+    // old layouts can be read but are too small for the current one, they have to be migrated before any write
+    fn save_changes(&self, accountant: &dyn Accountant) -> Result<()> {
+        let mut buf = Vec::new();
+        accountant.save_changes(&mut buf)?;
+
         let mut data = self.try_borrow_mut_data()?;
-        accountant.serialize(&mut &mut data[8..])?;
+        if data.len() != DISCRIMINATOR_LEN + buf.len() {
+            return Err(ErrorCode::AccountantNotMigrated.into());
+        }
+
+        data[DISCRIMINATOR_LEN..].copy_from_slice(&buf);
         Ok(())
     }
     
-}
\ No newline at end of file
+}
+
+// reads the current layout or any older one that can still be upgraded to it
+pub fn read_layout<T, V0>(data: &[u8]) -> Result<T>
+where
+    T: AnchorDeserialize + Space,
+    V0: AnchorDeserialize + Space + Into<T>,
+{
+    let body = &data[DISCRIMINATOR_LEN..];
+
+    let accountant = if body.len() == T::INIT_SPACE {
+        T::try_from_slice(body)
+    } else if body.len() == V0::INIT_SPACE {
+        V0::try_from_slice(body).map(Into::into)
+    } else {
+        return Err(ErrorCode::InvalidData.into());
+    };
+
+    accountant.map_err(|_| ErrorCode::InvalidData.into())
+}
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    hash::hash,
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+use crate::constants::TOKENIZED_VAULT_PROGRAM_ID;
+
+// mirrors tokenized_vault::AccountsIndexes, the vault crate can't be imported here since it depends on this one
+#[derive(Default, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AccountsIndexes {
+    pub strategy_acc: u64,
+    pub strategy_token_account: u64,
+    pub strategy_data: u64,
+    pub remaining_accounts: Vec<u64>,
+}
+
+// mirrors tokenized_vault::AccountsMap
+#[derive(Default, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct AccountsMap {
+    pub accounts_map: Vec<AccountsIndexes>,
+}
+
+pub struct RedeemAccounts<'a> {
+    pub vault: AccountInfo<'a>,
+    pub user_token_account: AccountInfo<'a>,
+    pub vault_token_account: AccountInfo<'a>,
+    pub accountant: AccountInfo<'a>,
+    pub accountant_recipient: AccountInfo<'a>,
+    pub vault_fee_override: AccountInfo<'a>,
+    pub accountant_token_account: AccountInfo<'a>,
+    pub shares_mint: AccountInfo<'a>,
+    pub underlying_mint: AccountInfo<'a>,
+    pub user_shares_account: AccountInfo<'a>,
+    pub user_data: AccountInfo<'a>,
+    pub sanctioned: AccountInfo<'a>,
+    pub price_history: AccountInfo<'a>,
+    pub user: AccountInfo<'a>,
+    pub owner: AccountInfo<'a>,
+    pub shares_token_program: AccountInfo<'a>,
+    pub token_program: AccountInfo<'a>,
+    pub strategy_program: AccountInfo<'a>,
+    pub access_control: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub vault_program: AccountInfo<'a>,
+}
+
+// must list the accounts in the same order as tokenized_vault::Withdraw
+impl<'a> RedeemAccounts<'a> {
+    pub fn to_account_metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.vault.key(), false),
+            AccountMeta::new(self.user_token_account.key(), false),
+            AccountMeta::new(self.vault_token_account.key(), false),
+            AccountMeta::new(self.accountant.key(), false),
+            AccountMeta::new(self.accountant_recipient.key(), false),
+            AccountMeta::new_readonly(self.vault_fee_override.key(), false),
+            AccountMeta::new(self.accountant_token_account.key(), false),
+            AccountMeta::new(self.shares_mint.key(), false),
+            AccountMeta::new(self.underlying_mint.key(), false),
+            AccountMeta::new(self.user_shares_account.key(), false),
+            AccountMeta::new(self.user_data.key(), false),
+            AccountMeta::new_readonly(self.sanctioned.key(), false),
+            AccountMeta::new(self.price_history.key(), false),
+            AccountMeta::new(self.user.key(), true),
+            AccountMeta::new_readonly(self.owner.key(), false),
+            AccountMeta::new_readonly(self.shares_token_program.key(), false),
+            AccountMeta::new_readonly(self.token_program.key(), false),
+            AccountMeta::new_readonly(self.strategy_program.key(), false),
+            AccountMeta::new_readonly(self.access_control.key(), false),
+            AccountMeta::new_readonly(self.system_program.key(), false),
+        ]
+    }
+
+    fn to_account_infos(&self) -> Vec<AccountInfo<'a>> {
+        vec![
+            self.vault.clone(),
+            self.user_token_account.clone(),
+            self.vault_token_account.clone(),
+            self.accountant.clone(),
+            self.accountant_recipient.clone(),
+            self.vault_fee_override.clone(),
+            self.accountant_token_account.clone(),
+            self.shares_mint.clone(),
+            self.underlying_mint.clone(),
+            self.user_shares_account.clone(),
+            self.user_data.clone(),
+            self.sanctioned.clone(),
+            self.price_history.clone(),
+            self.user.clone(),
+            self.owner.clone(),
+            self.shares_token_program.clone(),
+            self.token_program.clone(),
+            self.strategy_program.clone(),
+            self.access_control.clone(),
+            self.system_program.clone(),
+            self.vault_program.clone(),
+        ]
+    }
+}
+
+// only idle is redeemed: freeing funds from a strategy would nest accountant -> vault -> strategy -> swap program -> token,
+// one CPI deeper than the runtime allows. The vault rejects the redeem when idle doesn't cover it.
+pub fn redeem_instruction(accounts: &RedeemAccounts, shares: u64) -> Result<Instruction> {
+    let mut data = hash(b"global:redeem").to_bytes()[..8].to_vec();
+    (shares, 0u64, AccountsMap::default()).serialize(&mut data)?;
+
+    Ok(Instruction {
+        program_id: TOKENIZED_VAULT_PROGRAM_ID,
+        accounts: accounts.to_account_metas(),
+        data,
+    })
+}
+
+pub fn redeem<'a>(accounts: RedeemAccounts<'a>, shares: u64, seeds: &[&[&[u8]]]) -> Result<()> {
+    let ix = redeem_instruction(&accounts, shares)?;
+    invoke_signed(&ix, &accounts.to_account_infos(), seeds).map_err(Into::into)
+}
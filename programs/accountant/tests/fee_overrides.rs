@@ -0,0 +1,103 @@
+mod common;
+
+use accountant::constants::MIN_FEE_CHANGE_DELAY;
+use accountant::error::ErrorCode;
+use accountant::state::{
+    Accountant, AccountantConfig, FeeCaps, FeeOverride, FeeOverrideConfig, GenericAccountant,
+};
+use anchor_lang::prelude::*;
+use common::set_clock;
+
+fn accountant() -> GenericAccountant {
+    set_clock();
+
+    let mut accountant = GenericAccountant::default();
+    let config = AccountantConfig {
+        fee_caps: FeeCaps {
+            max_entry_fee: 5_000,
+            max_redemption_fee: 5_000,
+            max_performance_fee: 5_000,
+        },
+        fee_change_delay: MIN_FEE_CHANGE_DELAY,
+        entry_fee: 100,
+        performance_fee: 1_000,
+        ..Default::default()
+    };
+    accountant.init(0, 255, &config).unwrap();
+    accountant
+}
+
+fn fee_override(accountant: &GenericAccountant, target: Pubkey, config: FeeOverrideConfig) -> Result<FeeOverride> {
+    let mut fee_override = FeeOverride::default();
+    fee_override.update(Pubkey::new_unique(), target, &config, accountant, common::NOW as u64)?;
+    Ok(fee_override)
+}
+
+#[test]
+fn vault_override_replaces_accountant_fee() {
+    let accountant = accountant();
+    let vault = Pubkey::new_unique();
+    let other_vault = Pubkey::new_unique();
+    let overrides = [fee_override(
+        &accountant,
+        vault,
+        FeeOverrideConfig { entry_fee: Some(50), ..Default::default() },
+    )
+    .unwrap()];
+
+    assert_eq!(accountant.enter(&vault, &overrides, 10_000).unwrap(), 50);
+    assert_eq!(accountant.enter(&other_vault, &overrides, 10_000).unwrap(), 100);
+}
+
+#[test]
+fn strategy_override_wins_over_vault_override() {
+    let accountant = accountant();
+    let vault = Pubkey::new_unique();
+    let strategy = Pubkey::new_unique();
+    let overrides = [
+        fee_override(
+            &accountant,
+            vault,
+            FeeOverrideConfig { performance_fee: Some(500), ..Default::default() },
+        )
+        .unwrap(),
+        fee_override(
+            &accountant,
+            strategy,
+            FeeOverrideConfig { performance_fee: Some(200), ..Default::default() },
+        )
+        .unwrap(),
+    ];
+
+    assert_eq!(accountant.report(&vault, &strategy, &overrides, 10_000, 0).unwrap(), (200, 0));
+    assert_eq!(accountant.report(&vault, &Pubkey::new_unique(), &overrides, 10_000, 0).unwrap(), (500, 0));
+}
+
+#[test]
+fn override_above_cap_is_rejected() {
+    let accountant = accountant();
+
+    let err = fee_override(
+        &accountant,
+        Pubkey::new_unique(),
+        FeeOverrideConfig { entry_fee: Some(5_001), ..Default::default() },
+    )
+    .unwrap_err();
+
+    assert_eq!(err, ErrorCode::FeeExceedsCap.into());
+}
+
+#[test]
+fn removing_discount_override_is_rejected() {
+    let accountant = accountant();
+    let vault = Pubkey::new_unique();
+    let fee_override = fee_override(
+        &accountant,
+        vault,
+        FeeOverrideConfig { entry_fee: Some(50), ..Default::default() },
+    )
+    .unwrap();
+
+    let err = fee_override.validate_removal(&accountant, common::NOW as u64).unwrap_err();
+    assert_eq!(err, ErrorCode::UndelayedFeeIncrease.into());
+}
@@ -0,0 +1,135 @@
+mod common;
+
+use accountant::constants::MIN_FEE_CHANGE_DELAY;
+use accountant::error::ErrorCode;
+use accountant::state::{current_fee, Accountant, AccountantConfig, FeeCaps, GenericAccountant};
+use common::{set_clock, NOW};
+
+const DELAY: u64 = 86_400;
+
+fn config() -> AccountantConfig {
+    AccountantConfig {
+        fee_caps: FeeCaps {
+            max_entry_fee: 1_000,
+            max_redemption_fee: 1_000,
+            max_performance_fee: 2_000,
+        },
+        fee_change_delay: DELAY,
+        ..Default::default()
+    }
+}
+
+fn accountant() -> GenericAccountant {
+    set_clock();
+
+    let mut accountant = GenericAccountant::default();
+    accountant.init(0, 255, &config()).unwrap();
+    accountant
+}
+
+#[test]
+fn fee_increase_waits_for_the_delay() {
+    let mut accountant = accountant();
+    let now = NOW as u64;
+
+    let effective_at = accountant.set_entry_fee(500).unwrap();
+
+    assert_eq!(effective_at, now + DELAY);
+    assert_eq!(accountant.entry_fee().unwrap(), 0);
+    assert_eq!(current_fee(accountant.entry_fee, &accountant.pending_entry_fee, 1_000, now + DELAY), 500);
+}
+
+#[test]
+fn fee_decrease_applies_at_once() {
+    let mut accountant = accountant();
+    accountant.entry_fee = 800;
+
+    let effective_at = accountant.set_entry_fee(300).unwrap();
+
+    assert_eq!(effective_at, NOW as u64);
+    assert_eq!(accountant.entry_fee().unwrap(), 300);
+    assert!(!accountant.pending_entry_fee.is_set());
+}
+
+#[test]
+fn fee_above_cap_is_rejected() {
+    let mut accountant = accountant();
+
+    let err = accountant.set_performance_fee(2_001).unwrap_err();
+
+    assert_eq!(err, ErrorCode::FeeExceedsCap.into());
+}
+
+#[test]
+fn lowered_cap_limits_the_charged_fee() {
+    let mut accountant = accountant();
+    accountant.performance_fee = 2_000;
+
+    accountant
+        .set_fee_caps(&FeeCaps {
+            max_entry_fee: 1_000,
+            max_redemption_fee: 1_000,
+            max_performance_fee: 1_500,
+        })
+        .unwrap();
+
+    assert_eq!(accountant.performance_fee().unwrap(), 1_500);
+}
+
+#[test]
+fn raising_caps_is_rejected() {
+    let mut accountant = accountant();
+
+    let err = accountant
+        .set_fee_caps(&FeeCaps {
+            max_entry_fee: 1_001,
+            max_redemption_fee: 1_000,
+            max_performance_fee: 2_000,
+        })
+        .unwrap_err();
+
+    assert_eq!(err, ErrorCode::FeeCapIncrease.into());
+}
+
+#[test]
+fn init_sets_fees_without_delay() {
+    set_clock();
+    let mut accountant = GenericAccountant::default();
+
+    accountant.init(0, 255, &AccountantConfig { entry_fee: 500, ..config() }).unwrap();
+
+    assert_eq!(accountant.entry_fee().unwrap(), 500);
+    assert!(!accountant.pending_entry_fee.is_set());
+}
+
+#[test]
+fn init_rejects_fee_above_cap() {
+    let mut accountant = GenericAccountant::default();
+
+    let err = accountant.init(0, 255, &AccountantConfig { performance_fee: 2_001, ..config() }).unwrap_err();
+
+    assert_eq!(err, ErrorCode::FeeExceedsCap.into());
+}
+
+#[test]
+fn init_rejects_short_delay() {
+    let mut accountant = GenericAccountant::default();
+
+    let err = accountant
+        .init(0, 255, &AccountantConfig { fee_change_delay: MIN_FEE_CHANGE_DELAY - 1, ..config() })
+        .unwrap_err();
+
+    assert_eq!(err, ErrorCode::FeeChangeDelayTooShort.into());
+}
+
+#[test]
+fn fee_change_delay_can_only_be_raised() {
+    let mut accountant = accountant();
+
+    accountant.set_fee_change_delay(DELAY * 2).unwrap();
+    let err = accountant.set_fee_change_delay(DELAY).unwrap_err();
+
+    assert_eq!(err, ErrorCode::FeeChangeDelayDecrease.into());
+    assert_eq!(accountant.fee_change_delay(), DELAY * 2);
+    assert_eq!(accountant.set_entry_fee(500).unwrap(), NOW as u64 + DELAY * 2);
+}
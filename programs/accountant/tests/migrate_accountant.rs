@@ -0,0 +1,89 @@
+use accountant::constants::{DISCRIMINATOR_LEN, FEE_BPS, MIN_FEE_CHANGE_DELAY};
+use accountant::error::ErrorCode;
+use accountant::state::{GenericAccountant, GenericAccountantV0};
+use accountant::utils::unchecked_accountant::{read_layout, UncheckedAccountant};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+fn with_accountant<R>(data: &mut [u8], f: impl FnOnce(&UncheckedAccount) -> R) -> R {
+    let key = Pubkey::new_unique();
+    let owner = accountant::ID;
+    let mut lamports = 1_000_000;
+    let info = AccountInfo::new(&key, false, true, &mut lamports, data, &owner, false, 0);
+    f(&UncheckedAccount::try_from(&info))
+}
+
+fn v0_account() -> Vec<u8> {
+    let mut data = GenericAccountant::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&3u64.to_le_bytes());
+    data.push(254);
+    data.extend_from_slice(&100u64.to_le_bytes());
+    data.extend_from_slice(&200u64.to_le_bytes());
+    data.extend_from_slice(&300u64.to_le_bytes());
+    assert_eq!(data.len(), DISCRIMINATOR_LEN + GenericAccountantV0::INIT_SPACE);
+    data
+}
+
+#[test]
+fn reads_baseline_layout() {
+    let data = v0_account();
+
+    let accountant = read_layout::<GenericAccountant, GenericAccountantV0>(&data).unwrap();
+
+    assert_eq!(accountant.index_buffer, 3u64.to_le_bytes());
+    assert_eq!(accountant.bump, [254]);
+    assert_eq!(accountant.entry_fee, 100);
+    assert_eq!(accountant.redemption_fee, 200);
+    assert_eq!(accountant.performance_fee, 300);
+
+    // migrated accountants keep charging what they did before, raises wait for the minimum delay
+    assert_eq!(accountant.fee_caps.max_entry_fee, FEE_BPS);
+    assert_eq!(accountant.fee_caps.max_redemption_fee, FEE_BPS);
+    assert_eq!(accountant.fee_caps.max_performance_fee, FEE_BPS);
+    assert_eq!(accountant.fee_change_delay, MIN_FEE_CHANGE_DELAY);
+    assert!(!accountant.pending_entry_fee.is_set());
+    assert!(!accountant.pending_redemption_fee.is_set());
+    assert!(!accountant.pending_performance_fee.is_set());
+    assert_eq!(accountant.referral_fee, 0);
+}
+
+#[test]
+fn rejects_unknown_layout() {
+    let mut data = v0_account();
+    data.push(0);
+
+    let err = read_layout::<GenericAccountant, GenericAccountantV0>(&data).unwrap_err();
+    assert_eq!(err, ErrorCode::InvalidData.into());
+}
+
+#[test]
+fn refuses_to_write_unmigrated_accountant() {
+    let mut data = v0_account();
+
+    let err = with_accountant(&mut data, |accountant| {
+        let mut loaded = accountant.from_unchecked().unwrap();
+        loaded.set_referral_fee(50).unwrap();
+        accountant.save_changes(loaded.as_ref())
+    }).unwrap_err();
+
+    assert_eq!(err, ErrorCode::AccountantNotMigrated.into());
+    assert_eq!(data, v0_account());
+}
+
+#[test]
+fn writes_migrated_accountant() {
+    let mut data = v0_account();
+    let migrated: GenericAccountant = read_layout::<GenericAccountant, GenericAccountantV0>(&data).unwrap();
+    data.resize(DISCRIMINATOR_LEN + GenericAccountant::INIT_SPACE, 0);
+
+    with_accountant(&mut data, |accountant| {
+        accountant.save_changes(&migrated).unwrap();
+        let mut loaded = accountant.from_unchecked().unwrap();
+        loaded.set_referral_fee(50).unwrap();
+        accountant.save_changes(loaded.as_ref()).unwrap();
+    });
+
+    let accountant = GenericAccountant::try_from_slice(&data[DISCRIMINATOR_LEN..]).unwrap();
+    assert_eq!(accountant.entry_fee, 100);
+    assert_eq!(accountant.referral_fee, 50);
+}
@@ -0,0 +1,6 @@
+use anchor_lang::prelude::*;
+
+pub const EXTRA_ACCOUNT_METAS_SEED: &str = "extra-account-metas";
+pub const VAULT_USER_DATA_SEED: &str = "user_data";
+
+pub const TOKENIZED_VAULT_PROGRAM_ID: Pubkey = pubkey!("8Y5ZEEnhiNdvGHbfiZVj2eSawrNrQTKd9jPEFqnnKizC");
@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Receiver is neither KYC verified nor whitelisted")]
+    ReceiverNotVerified,
+
+    #[msg("Signer is not the mint authority")]
+    InvalidMintAuthority,
+
+    #[msg("Sender is sanctioned")]
+    SenderSanctioned,
+}
@@ -0,0 +1,118 @@
+use access_control::{
+    constants::{ROLE_EXPIRY_SEED, USER_ROLE_SEED},
+    state::Role,
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use spl_tlv_account_resolution::{
+    account::ExtraAccountMeta,
+    seeds::Seed,
+    state::ExtraAccountMetaList,
+};
+use spl_transfer_hook_interface::instruction::ExecuteInstruction;
+
+use crate::constants::{EXTRA_ACCOUNT_METAS_SEED, TOKENIZED_VAULT_PROGRAM_ID, VAULT_USER_DATA_SEED};
+use crate::errors::ErrorCode;
+
+// execute accounts: 0 source, 1 mint, 2 destination, 3 owner, 4 extra account meta list
+const SOURCE_INDEX: u8 = 0;
+const DESTINATION_INDEX: u8 = 2;
+const ACCESS_CONTROL_INDEX: u8 = 5;
+const VAULT_INDEX: u8 = 6;
+const VAULT_PROGRAM_INDEX: u8 = 7;
+const EXTRA_ACCOUNTS_LEN: usize = 7;
+
+#[derive(Accounts)]
+pub struct InitializeExtraAccountMetaList<'info> {
+    /// CHECK: initialized below with the transfer hook account list
+    #[account(
+        init,
+        seeds = [EXTRA_ACCOUNT_METAS_SEED.as_bytes(), mint.key().as_ref()],
+        bump,
+        payer = payer,
+        space = ExtraAccountMetaList::size_of(EXTRA_ACCOUNTS_LEN)?,
+    )]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        constraint = mint.mint_authority == Some(mint_authority.key()).into() @ ErrorCode::InvalidMintAuthority
+    )]
+    pub mint_authority: Signer<'info>,
+
+    /// CHECK: the vault whose shares the mint represents
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_extra_account_meta_list(ctx: Context<InitializeExtraAccountMetaList>) -> Result<()> {
+    // the receiver is the owner stored at offset 32 of the destination token account
+    let receiver = Seed::AccountData {
+        account_index: DESTINATION_INDEX,
+        data_index: 32,
+        length: 32,
+    };
+    let sender = Seed::AccountData {
+        account_index: SOURCE_INDEX,
+        data_index: 32,
+        length: 32,
+    };
+
+    let extra_account_metas = [
+        ExtraAccountMeta::new_with_pubkey(&access_control::ID, false, false)?,
+        ExtraAccountMeta::new_with_pubkey(&ctx.accounts.vault.key(), false, false)?,
+        ExtraAccountMeta::new_with_pubkey(&TOKENIZED_VAULT_PROGRAM_ID, false, false)?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            ACCESS_CONTROL_INDEX,
+            &[
+                Seed::Literal { bytes: USER_ROLE_SEED.as_bytes().to_vec() },
+                receiver.clone(),
+                Seed::Literal { bytes: Role::KYCVerified.to_seed().to_vec() },
+            ],
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            VAULT_PROGRAM_INDEX,
+            &[
+                Seed::Literal { bytes: VAULT_USER_DATA_SEED.as_bytes().to_vec() },
+                Seed::AccountKey { index: VAULT_INDEX },
+                receiver.clone(),
+            ],
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            ACCESS_CONTROL_INDEX,
+            &[
+                Seed::Literal { bytes: ROLE_EXPIRY_SEED.as_bytes().to_vec() },
+                receiver,
+                Seed::Literal { bytes: Role::KYCVerified.to_seed().to_vec() },
+            ],
+            false,
+            false,
+        )?,
+        ExtraAccountMeta::new_external_pda_with_seeds(
+            ACCESS_CONTROL_INDEX,
+            &[
+                Seed::Literal { bytes: USER_ROLE_SEED.as_bytes().to_vec() },
+                sender,
+                Seed::Literal { bytes: Role::Sanctioned.to_seed().to_vec() },
+            ],
+            false,
+            false,
+        )?,
+    ];
+
+    ExtraAccountMetaList::init::<ExecuteInstruction>(
+        &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
+        &extra_account_metas,
+    )?;
+
+    Ok(())
+}
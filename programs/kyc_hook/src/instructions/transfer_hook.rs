@@ -0,0 +1,180 @@
+use access_control::{
+    constants::{ROLE_EXPIRY_SEED, USER_ROLE_SEED},
+    program::AccessControl,
+    state::{Role, RoleExpiry, UserRole},
+};
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::{
+    EXTRA_ACCOUNT_METAS_SEED, TOKENIZED_VAULT_PROGRAM_ID, VAULT_USER_DATA_SEED,
+};
+use crate::errors::ErrorCode;
+
+// offsets into tokenized_vault::state::UserData, discriminator included, pinned by the
+// vault's layout tests. only the prefix is read so accounts created before the later
+// fields were added still work
+pub const USER_DATA_WHITELISTED_OFFSET: usize = 8 + 8;
+pub const USER_DATA_WHITELIST_ROOT_OFFSET: usize = USER_DATA_WHITELISTED_OFFSET + 1 + 6 * 8;
+pub const USER_DATA_WHITELIST_EXPIRES_AT_OFFSET: usize = USER_DATA_WHITELIST_ROOT_OFFSET + 32;
+
+// offset of `whitelist_root` in the zero copy tokenized_vault::state::Vault, pinned by a
+// const assert next to the struct
+pub const VAULT_WHITELIST_ROOT_OFFSET: usize = 8 + 291;
+
+#[derive(Accounts)]
+pub struct TransferHook<'info> {
+    #[account(token::mint = mint)]
+    pub source_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(token::mint = mint)]
+    pub destination_token: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: source owner or delegate
+    pub owner: UncheckedAccount<'info>,
+
+    /// CHECK: transfer hook account list
+    #[account(seeds = [EXTRA_ACCOUNT_METAS_SEED.as_bytes(), mint.key().as_ref()], bump)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+
+    /// CHECK: fixed in the account list at initialization
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: tokenized vault program
+    #[account(address = TOKENIZED_VAULT_PROGRAM_ID)]
+    pub vault_program: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(),
+            destination_token.owner.as_ref(),
+            Role::KYCVerified.to_seed().as_ref()
+        ],
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub kyc_verified: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            VAULT_USER_DATA_SEED.as_bytes(),
+            vault.key().as_ref(),
+            destination_token.owner.as_ref()
+        ],
+        bump,
+        seeds::program = vault_program.key()
+    )]
+    pub user_data: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            ROLE_EXPIRY_SEED.as_bytes(),
+            destination_token.owner.as_ref(),
+            Role::KYCVerified.to_seed().as_ref()
+        ],
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub kyc_expiry: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(),
+            source_token.owner.as_ref(),
+            Role::Sanctioned.to_seed().as_ref()
+        ],
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub sanctioned: UncheckedAccount<'info>,
+}
+
+pub fn handle_transfer_hook(ctx: Context<TransferHook>, _amount: u64) -> Result<()> {
+    // a sanctioned holder's shares stay where they are, the vault can still move them
+    // by burning and minting
+    if has_role(&ctx.accounts.sanctioned)? {
+        return Err(ErrorCode::SenderSanctioned.into());
+    }
+
+    if is_kyc_verified(&ctx.accounts.kyc_verified, &ctx.accounts.kyc_expiry)?
+        || is_whitelisted(&ctx.accounts.user_data, &ctx.accounts.vault)?
+    {
+        return Ok(());
+    }
+
+    Err(ErrorCode::ReceiverNotVerified.into())
+}
+
+fn is_kyc_verified(kyc_verified: &AccountInfo, kyc_expiry: &AccountInfo) -> Result<bool> {
+    if !has_role(kyc_verified)? {
+        return Ok(false);
+    }
+    // the role stays set until someone runs `expire_role`, so the expiry is checked here too
+    Ok(!is_role_expired(kyc_expiry, Clock::get()?.unix_timestamp)?)
+}
+
+fn has_role(user_role: &AccountInfo) -> Result<bool> {
+    if user_role.data_is_empty() || user_role.owner != &access_control::ID {
+        return Ok(false);
+    }
+    let data = user_role.try_borrow_data()?;
+    Ok(UserRole::try_deserialize(&mut &data[..])?.has_role)
+}
+
+// a permanent grant has no expiry account
+fn is_role_expired(role_expiry: &AccountInfo, timestamp: i64) -> Result<bool> {
+    if role_expiry.data_is_empty() || role_expiry.owner != &access_control::ID {
+        return Ok(false);
+    }
+    let data = role_expiry.try_borrow_data()?;
+    Ok(RoleExpiry::try_deserialize(&mut &data[..])?.is_expired(timestamp))
+}
+
+fn is_whitelisted(user_data: &AccountInfo, vault: &AccountInfo) -> Result<bool> {
+    if user_data.data_is_empty() || user_data.owner != &TOKENIZED_VAULT_PROGRAM_ID {
+        return Ok(false);
+    }
+    let data = user_data.try_borrow_data()?;
+    Ok(is_whitelisted_at(
+        &data,
+        whitelist_root(vault)?,
+        Clock::get()?.unix_timestamp,
+    ))
+}
+
+fn whitelist_root(vault: &AccountInfo) -> Result<[u8; 32]> {
+    if vault.owner != &TOKENIZED_VAULT_PROGRAM_ID {
+        return Ok([0; 32]);
+    }
+    let data = vault.try_borrow_data()?;
+    Ok(read_root(&data, VAULT_WHITELIST_ROOT_OFFSET))
+}
+
+fn read_root(data: &[u8], offset: usize) -> [u8; 32] {
+    data.get(offset..offset + 32)
+        .map_or([0; 32], |bytes| bytes.try_into().unwrap())
+}
+
+/// reads the whitelisting out of raw `UserData` account data the way `UserData::is_whitelisted`
+/// does, a missing root is an admin whitelisting and a missing expiry never expires
+pub fn is_whitelisted_at(data: &[u8], whitelist_root: [u8; 32], timestamp: i64) -> bool {
+    if data.get(USER_DATA_WHITELISTED_OFFSET).copied().unwrap_or(0) == 0 {
+        return false;
+    }
+    let root = read_root(data, USER_DATA_WHITELIST_ROOT_OFFSET);
+    if root != [0; 32] && root != whitelist_root {
+        return false;
+    }
+    let expires_at = data
+        .get(USER_DATA_WHITELIST_EXPIRES_AT_OFFSET..USER_DATA_WHITELIST_EXPIRES_AT_OFFSET + 8)
+        .map_or(0, |bytes| i64::from_le_bytes(bytes.try_into().unwrap()));
+    expires_at == 0 || timestamp < expires_at
+}
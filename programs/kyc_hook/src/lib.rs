@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use spl_transfer_hook_interface::instruction::TransferHookInstruction;
+
+pub mod constants;
+pub mod errors;
+pub mod instructions;
+
+use crate::instructions::*;
+
+declare_id!("6fETWwDGXsAu7Mura5bqnNwFrBHUeM1j9WYFZJsT4CeW");
+
+#[program]
+pub mod kyc_hook {
+    use super::*;
+
+    pub fn initialize_extra_account_meta_list(ctx: Context<InitializeExtraAccountMetaList>) -> Result<()> {
+        handle_initialize_extra_account_meta_list(ctx)
+    }
+
+    pub fn transfer_hook(ctx: Context<TransferHook>, amount: u64) -> Result<()> {
+        handle_transfer_hook(ctx, amount)
+    }
+
+    // token-2022 calls the hook with the interface discriminator, not the anchor one
+    pub fn fallback<'info>(
+        program_id: &Pubkey,
+        accounts: &'info [AccountInfo<'info>],
+        data: &[u8],
+    ) -> Result<()> {
+        match TransferHookInstruction::unpack(data)? {
+            TransferHookInstruction::Execute { amount } => {
+                __private::__global::transfer_hook(program_id, accounts, &amount.to_le_bytes())
+            }
+            _ => Err(ProgramError::InvalidInstructionData.into()),
+        }
+    }
+}
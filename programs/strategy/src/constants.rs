@@ -2,6 +2,7 @@ pub const VAULT_SEED: &str = "vault";
 pub const SHARES_SEED: &str = "shares";
 pub const UNDERLYING_SEED: &str = "underlying";
 pub const CONFIG_SEED: &str = "config";
+pub const PAUSE_SEED: &str = "pause";
 pub const TOKEN_ACCOUNT_SEED: &str = "token_account";
 pub const INVEST_TRACKER_SEED: &str = "invest_tracker";
 
@@ -11,6 +12,8 @@ pub const MAX_SQRT_PRICE_X64: u128 = 79226673515401279992447579055;
 pub const MIN_SQRT_PRICE_X64: u128 = 4295048016;
 pub const FEE_BPS: u64 = 10_000;
 pub const DISCRIMINATOR_LEN: usize = 8;
+// v0 is the original unversioned layout of every strategy
+pub const STRATEGY_VERSION: u8 = 1;
 pub const NO_EXPLICIT_SQRT_PRICE_LIMIT: u128 = 0;
 pub const NUM_REWARDS: usize = 3;
 pub const MAX_ASSIGNED_WEIGHT: u16 = 10000; // 100% in bps
@@ -40,4 +40,13 @@ pub enum ErrorCode {
 
     #[msg("Math error")]
     MathError,
+
+    #[msg("Strategies are paused")]
+    Paused,
+
+    #[msg("Strategy is already on the latest layout")]
+    StrategyAlreadyMigrated,
+
+    #[msg("Strategy uses an old layout, run migrate_strategy_account first")]
+    StrategyNotMigrated,
 }
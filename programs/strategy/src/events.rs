@@ -85,3 +85,18 @@ pub struct OrcaAfterSwapEvent {
     pub asset_balance_after: u64,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct StrategiesPauseUpdatedEvent {
+    pub paused: bool,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StrategyMigratedEvent {
+    pub account_key: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+    pub timestamp: i64,
+}
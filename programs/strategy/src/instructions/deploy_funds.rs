@@ -31,5 +31,5 @@ pub fn handle_deploy_funds<'info>(ctx: Context<'_, '_, '_, 'info, DeployFunds<'i
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
     
     strategy.deploy_funds(&ctx.accounts, &ctx.remaining_accounts, amount)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+    ctx.accounts.strategy.save_changes(strategy.as_ref())
 }
\ No newline at end of file
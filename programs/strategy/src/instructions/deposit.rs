@@ -41,6 +41,7 @@ pub fn handle_deposit<'info>(
         return Err(ErrorCode::MaxDepositReached.into());
     }
 
+    let balance_before = ctx.accounts.underlying_token_account.amount;
     transfer(
         ctx.accounts.token_program.to_account_info(),
         ctx.accounts.vault_token_account.to_account_info(), 
@@ -49,6 +50,10 @@ pub fn handle_deposit<'info>(
         &ctx.accounts.underlying_mint,
         amount
     )?;
+    ctx.accounts.underlying_token_account.reload()?;
+
+    // transfer-fee mints deliver less than the requested amount
+    let amount = ctx.accounts.underlying_token_account.amount - balance_before;
 
     strategy.deposit(amount)?;
 
@@ -65,7 +70,7 @@ pub fn handle_deposit<'info>(
         strategy.deploy_funds(&deploy_funds, &ctx.remaining_accounts, amount)?;
     }
 
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])?;
+    ctx.accounts.strategy.save_changes(strategy.as_ref())?;
     
     Ok(())
 }
\ No newline at end of file
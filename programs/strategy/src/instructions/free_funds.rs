@@ -30,5 +30,5 @@ pub fn handle_free_funds<'info>(ctx: Context<'_, '_, '_, 'info, FreeFunds<'info>
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
 
     strategy.free_funds(&ctx.accounts, &ctx.remaining_accounts, amount)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+    ctx.accounts.strategy.save_changes(strategy.as_ref())
 }
\ No newline at end of file
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::{DISCRIMINATOR_LEN, STRATEGY_VERSION};
+use crate::error::ErrorCode;
+use crate::events::StrategyMigratedEvent;
+use crate::state::*;
+use crate::utils::unchecked_strategy::{read_layout, UncheckedStrategy};
+
+#[derive(Accounts)]
+pub struct MigrateStrategyAccount<'info> {
+    /// CHECK: can be any strategy on any supported layout
+    #[account(mut, owner = crate::ID)]
+    pub strategy: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::StrategiesManager.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_strategy_account(ctx: Context<MigrateStrategyAccount>) -> Result<()> {
+    match ctx.accounts.strategy.get_discriminator()? {
+        SimpleStrategy::DISCRIMINATOR => migrate::<SimpleStrategy, SimpleStrategyV0>(ctx),
+        TradeFintechStrategy::DISCRIMINATOR => migrate::<TradeFintechStrategy, TradeFintechStrategyV0>(ctx),
+        OrcaStrategy::DISCRIMINATOR => migrate::<OrcaStrategy, OrcaStrategyV0>(ctx),
+        _ => Err(ErrorCode::InvalidStrategyData.into()),
+    }
+}
+
+fn migrate<T, V0>(ctx: Context<MigrateStrategyAccount>) -> Result<()>
+where
+    T: Strategy + AnchorDeserialize + Space,
+    V0: AnchorDeserialize + Space + Into<T>,
+{
+    let strategy_info = ctx.accounts.strategy.to_account_info();
+    let new_len = DISCRIMINATOR_LEN + T::INIT_SPACE;
+
+    if strategy_info.data_len() == new_len {
+        return Err(ErrorCode::StrategyAlreadyMigrated.into());
+    }
+
+    let strategy = read_layout::<T, V0>(&strategy_info.try_borrow_data()?)?;
+
+    let rent_due = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(strategy_info.lamports());
+
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: strategy_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+
+    strategy_info.realloc(new_len, false)?;
+    ctx.accounts.strategy.save_changes(&strategy)?;
+
+    emit!(StrategyMigratedEvent {
+        account_key: strategy_info.key(),
+        old_version: 0,
+        new_version: STRATEGY_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -2,6 +2,8 @@ pub mod deploy_funds;
 pub mod deposit;
 pub mod free_funds;
 pub mod initialize;
+pub mod migrate_strategy_account;
+pub mod pause;
 pub mod init_strategy;
 pub mod report;
 pub mod report_loss;
@@ -16,6 +18,8 @@ pub use deploy_funds::*;
 pub use deposit::*;
 pub use free_funds::*;
 pub use initialize::*;
+pub use migrate_strategy_account::*;
+pub use pause::*;
 pub use init_strategy::*;
 pub use report::*;
 pub use report_loss::*;
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::{DISCRIMINATOR_LEN, PAUSE_SEED};
+use crate::events::StrategiesPauseUpdatedEvent;
+use crate::state::PauseState;
+
+#[derive(Accounts)]
+pub struct PauseStrategies<'info> {
+    #[account(
+        init_if_needed,
+        seeds = [PAUSE_SEED.as_bytes()],
+        bump,
+        payer = signer,
+        space = DISCRIMINATOR_LEN + PauseState::INIT_SPACE,
+    )]
+    pub pause_state: Account<'info, PauseState>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::Guardian.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnpauseStrategies<'info> {
+    #[account(mut, seeds = [PAUSE_SEED.as_bytes()], bump)]
+    pub pause_state: Account<'info, PauseState>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::StrategiesManager.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+}
+
+pub fn handle_pause_strategies(ctx: Context<PauseStrategies>) -> Result<()> {
+    ctx.accounts.pause_state.paused = true;
+
+    emit!(StrategiesPauseUpdatedEvent {
+        paused: true,
+        authority: ctx.accounts.signer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn handle_unpause_strategies(ctx: Context<UnpauseStrategies>) -> Result<()> {
+    ctx.accounts.pause_state.paused = false;
+
+    emit!(StrategiesPauseUpdatedEvent {
+        paused: false,
+        authority: ctx.accounts.signer.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -27,5 +27,5 @@ pub fn handle_report<'info>(ctx: Context<'_, '_, '_, 'info, Report<'info>>) -> R
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
 
     strategy.report(&ctx.accounts, &ctx.remaining_accounts)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+    ctx.accounts.strategy.save_changes(strategy.as_ref())
 }
\ No newline at end of file
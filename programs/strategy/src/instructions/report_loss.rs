@@ -3,7 +3,8 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::utils::unchecked_strategy::UncheckedStrategy;
 use crate::error::ErrorCode;
-use crate::constants::UNDERLYING_SEED;
+use crate::constants::{PAUSE_SEED, UNDERLYING_SEED};
+use crate::utils::is_paused;
 
 #[derive(Accounts)]
 pub struct ReportLoss<'info> {
@@ -14,6 +15,10 @@ pub struct ReportLoss<'info> {
     #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), strategy.key().as_ref()], bump)]
     pub underlying_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// CHECK: this account may not exist
+    #[account(seeds = [PAUSE_SEED.as_bytes()], bump, constraint = !is_paused(&pause_state)? @ErrorCode::Paused)]
+    pub pause_state: UncheckedAccount<'info>,
+
     #[account(mut, constraint = underlying_mint.key() == strategy.underlying_mint())]
     pub underlying_mint: InterfaceAccount<'info, Mint>,
     
@@ -27,5 +32,5 @@ pub fn handle_report_loss<'info>(ctx: Context<'_, '_, '_, 'info, ReportLoss<'inf
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
 
     strategy.report_loss(&ctx.accounts, &ctx.remaining_accounts, loss)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+    ctx.accounts.strategy.save_changes(strategy.as_ref())
 }
\ No newline at end of file
@@ -6,8 +6,10 @@ use access_control::{
     state::{UserRole, Role}
 };
 
+use crate::error::ErrorCode;
 use crate::utils::unchecked_strategy::UncheckedStrategy;
-use crate::constants::UNDERLYING_SEED;
+use crate::constants::{PAUSE_SEED, UNDERLYING_SEED};
+use crate::utils::is_paused;
 
 #[derive(Accounts)]
 pub struct ReportProfit<'info> {
@@ -32,6 +34,10 @@ pub struct ReportProfit<'info> {
     )]
     pub roles: Account<'info, UserRole>,
 
+    /// CHECK: this account may not exist
+    #[account(seeds = [PAUSE_SEED.as_bytes()], bump, constraint = !is_paused(&pause_state)? @ErrorCode::Paused)]
+    pub pause_state: UncheckedAccount<'info>,
+
     #[account(mut, constraint = roles.check_role()?)]
     pub signer: Signer<'info>,
 
@@ -43,5 +49,5 @@ pub fn handle_report_profit<'info>(ctx: Context<'_, '_, '_, 'info, ReportProfit<
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
 
     strategy.report_profit(&ctx.accounts, &ctx.remaining_accounts, profit)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+    ctx.accounts.strategy.save_changes(strategy.as_ref())
 }
\ No newline at end of file
@@ -25,7 +25,7 @@ pub fn handle_set_performance_fee<'info>(ctx: Context<SetStrategyValue<'info>>,
         fee: new_fee,
     });
 
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+    ctx.accounts.strategy.save_changes(strategy.as_ref())
 }
 
 pub fn handle_set_fee_manager<'info>(ctx: Context<SetStrategyValue<'info>>, recipient: Pubkey) -> Result<()> {
@@ -33,5 +33,5 @@ pub fn handle_set_fee_manager<'info>(ctx: Context<SetStrategyValue<'info>>, reci
 
     let fee_data = &mut strategy.fee_data();
     fee_data.set_fee_manager(recipient)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+    ctx.accounts.strategy.save_changes(strategy.as_ref())
 }
\ No newline at end of file
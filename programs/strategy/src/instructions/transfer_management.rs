@@ -17,5 +17,5 @@ pub fn handle_transfer_management<'info>(ctx: Context<TransferManagement<'info>>
     let mut strategy = ctx.accounts.strategy.from_unchecked()?;
 
     strategy.set_manager(new_manager)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])
+    ctx.accounts.strategy.save_changes(strategy.as_ref())
 }
\ No newline at end of file
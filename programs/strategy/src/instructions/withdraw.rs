@@ -51,7 +51,7 @@ pub fn handle_withdraw<'info>(
     }
 
     strategy.withdraw(amount)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])?;
+    ctx.accounts.strategy.save_changes(strategy.as_ref())?;
     
     token::transfer_with_signer(
         ctx.accounts.token_program.to_account_info(), 
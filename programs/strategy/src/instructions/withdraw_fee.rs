@@ -4,7 +4,8 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 use crate::error::ErrorCode;
 use crate::utils::unchecked_strategy::UncheckedStrategy;
 use crate::utils::token;
-use crate::constants::UNDERLYING_SEED;
+use crate::constants::{PAUSE_SEED, UNDERLYING_SEED};
+use crate::utils::is_paused;
 
 use super::FreeFunds;
 
@@ -17,6 +18,10 @@ pub struct WithdrawFee<'info> {
     #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), strategy.key().as_ref()], bump)]
     pub underlying_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// CHECK: this account may not exist
+    #[account(seeds = [PAUSE_SEED.as_bytes()], bump, constraint = !is_paused(&pause_state)? @ErrorCode::Paused)]
+    pub pause_state: UncheckedAccount<'info>,
+
     #[account(mut, constraint = underlying_mint.key() == strategy.underlying_mint())]
     pub underlying_mint: InterfaceAccount<'info, Mint>,
     
@@ -59,7 +64,7 @@ pub fn handle_withdraw_fee<'info>(
     }
 
     strategy.withdraw_fees(amount)?;
-    strategy.save_changes(&mut &mut ctx.accounts.strategy.try_borrow_mut_data()?[8..])?;
+    ctx.accounts.strategy.save_changes(strategy.as_ref())?;
 
     token::transfer_with_signer(
         ctx.accounts.token_program.to_account_info(), 
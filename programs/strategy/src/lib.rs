@@ -92,4 +92,16 @@ pub mod strategy {
     pub fn free_funds<'info>(ctx:  Context<'_, '_, '_, 'info, FreeFunds<'info>>, amount: u64) -> Result<()> {
         handle_free_funds(ctx, amount)
     }
+
+    pub fn migrate_strategy_account(ctx: Context<MigrateStrategyAccount>) -> Result<()> {
+        handle_migrate_strategy_account(ctx)
+    }
+
+    pub fn pause_strategies(ctx: Context<PauseStrategies>) -> Result<()> {
+        handle_pause_strategies(ctx)
+    }
+
+    pub fn unpause_strategies(ctx: Context<UnpauseStrategies>) -> Result<()> {
+        handle_unpause_strategies(ctx)
+    }
 }
@@ -1,15 +1,19 @@
 pub mod base_strategy;
 pub mod config;
+pub mod pause_state;
 pub mod fee_data;
 pub mod orca_strategy;
 pub mod trade_fintech_strategy;
 pub mod simple_strategy;
+pub mod strategy_v0;
 pub mod strategy_type;
 
 pub use base_strategy::*;
 pub use config::*;
+pub use pause_state::*;
 pub use fee_data::*;
 pub use orca_strategy::*;
 pub use trade_fintech_strategy::*;
 pub use simple_strategy::*;
+pub use strategy_v0::*;
 pub use strategy_type::*;
\ No newline at end of file
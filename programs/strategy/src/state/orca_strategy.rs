@@ -4,6 +4,7 @@ use anchor_spl::token_interface::Mint;
 use super::base_strategy::*;
 use super::fee_data::*;
 use super::StrategyType;
+use crate::constants::STRATEGY_VERSION;
 use crate::error::ErrorCode;
 use crate::events::{
     OrcaInitEvent,
@@ -56,6 +57,8 @@ pub struct OrcaStrategy {
     pub idle_underlying: u64,
 
     pub a_to_b_for_purchase: bool,
+
+    pub version: u8,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -498,6 +501,7 @@ impl StrategyInit for OrcaStrategy {
             performance_fee: config.performance_fee,
             fee_balance: 0,
         };
+        self.version = STRATEGY_VERSION;
 
         emit!(StrategyInitEvent {
             account_key: self.key(),
@@ -3,6 +3,7 @@ use anchor_spl::token_interface::Mint;
 
 use super::base_strategy::*;
 use super::StrategyType;
+use crate::constants::STRATEGY_VERSION;
 use super::fee_data::*;
 
 use crate::error::ErrorCode;
@@ -31,6 +32,8 @@ pub struct SimpleStrategy {
     pub total_invested: u64,
 
     pub fee_data: FeeData,
+
+    pub version: u8,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Default, Clone, Debug)]
@@ -264,6 +267,7 @@ impl StrategyInit for SimpleStrategy {
             performance_fee: config.performance_fee,
             fee_balance: 0,
         };
+        self.version = STRATEGY_VERSION;
 
         emit!(
             StrategyInitEvent 
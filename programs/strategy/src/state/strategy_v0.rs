@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+
+use super::fee_data::FeeData;
+use super::{OrcaStrategy, SimpleStrategy, TradeFintechStrategy};
+use crate::constants::STRATEGY_VERSION;
+
+// snapshots of the unversioned strategy layouts, never edit them.
+// `utils::unchecked_strategy::deserialize` still reads them and `migrate_strategy_account` upgrades them in place.
+
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct SimpleStrategyV0 {
+    pub bump: [u8; 1],
+    pub index_bytes: [u8; 8],
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_acc: Pubkey,
+    pub underlying_decimals: u8,
+    pub total_assets: u64,
+    pub deposit_limit: u64,
+    pub total_invested: u64,
+    pub fee_data: FeeData,
+}
+
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct TradeFintechStrategyV0 {
+    pub bump: [u8; 1],
+    pub index_bytes: [u8; 8],
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_acc: Pubkey,
+    pub underlying_decimals: u8,
+    pub total_invested: u64,
+    pub total_assets: u64,
+    pub deposit_limit: u64,
+    pub deposit_period_ends: i64,
+    pub lock_period_ends: i64,
+    pub fee_data: FeeData,
+}
+
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct OrcaStrategyV0 {
+    pub bump: [u8; 1],
+    pub index_bytes: [u8; 8],
+    pub vault: Pubkey,
+    pub manager: Pubkey,
+    pub underlying_mint: Pubkey,
+    pub underlying_token_acc: Pubkey,
+    pub underlying_decimals: u8,
+    pub total_invested: u64,
+    pub total_assets: u64,
+    pub deposit_limit: u64,
+    pub fee_data: FeeData,
+    pub whirlpool_id: Pubkey,
+    pub asset_mint: Pubkey,
+    pub asset_amount: u64,
+    pub asset_price: u128,
+    pub asset_decimals: u8,
+    pub idle_underlying: u64,
+    pub a_to_b_for_purchase: bool,
+}
+
+impl From<SimpleStrategyV0> for SimpleStrategy {
+    fn from(old: SimpleStrategyV0) -> Self {
+        Self {
+            bump: old.bump,
+            index_bytes: old.index_bytes,
+            vault: old.vault,
+            manager: old.manager,
+            underlying_mint: old.underlying_mint,
+            underlying_token_acc: old.underlying_token_acc,
+            underlying_decimals: old.underlying_decimals,
+            total_assets: old.total_assets,
+            deposit_limit: old.deposit_limit,
+            total_invested: old.total_invested,
+            fee_data: old.fee_data,
+            version: STRATEGY_VERSION,
+        }
+    }
+}
+
+impl From<TradeFintechStrategyV0> for TradeFintechStrategy {
+    fn from(old: TradeFintechStrategyV0) -> Self {
+        Self {
+            bump: old.bump,
+            index_bytes: old.index_bytes,
+            vault: old.vault,
+            manager: old.manager,
+            underlying_mint: old.underlying_mint,
+            underlying_token_acc: old.underlying_token_acc,
+            underlying_decimals: old.underlying_decimals,
+            total_invested: old.total_invested,
+            total_assets: old.total_assets,
+            deposit_limit: old.deposit_limit,
+            deposit_period_ends: old.deposit_period_ends,
+            lock_period_ends: old.lock_period_ends,
+            fee_data: old.fee_data,
+            version: STRATEGY_VERSION,
+        }
+    }
+}
+
+impl From<OrcaStrategyV0> for OrcaStrategy {
+    fn from(old: OrcaStrategyV0) -> Self {
+        Self {
+            bump: old.bump,
+            index_bytes: old.index_bytes,
+            vault: old.vault,
+            manager: old.manager,
+            underlying_mint: old.underlying_mint,
+            underlying_token_acc: old.underlying_token_acc,
+            underlying_decimals: old.underlying_decimals,
+            total_invested: old.total_invested,
+            total_assets: old.total_assets,
+            deposit_limit: old.deposit_limit,
+            fee_data: old.fee_data,
+            whirlpool_id: old.whirlpool_id,
+            asset_mint: old.asset_mint,
+            asset_amount: old.asset_amount,
+            asset_price: old.asset_price,
+            asset_decimals: old.asset_decimals,
+            idle_underlying: old.idle_underlying,
+            a_to_b_for_purchase: old.a_to_b_for_purchase,
+            version: STRATEGY_VERSION,
+        }
+    }
+}
+
+// v1 appended `version`
+const _: () = assert!(SimpleStrategy::INIT_SPACE == SimpleStrategyV0::INIT_SPACE + 1);
+const _: () = assert!(TradeFintechStrategy::INIT_SPACE == TradeFintechStrategyV0::INIT_SPACE + 1);
+const _: () = assert!(OrcaStrategy::INIT_SPACE == OrcaStrategyV0::INIT_SPACE + 1);
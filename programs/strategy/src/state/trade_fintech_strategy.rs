@@ -3,6 +3,7 @@ use anchor_spl::token_interface::Mint;
 
 use super::base_strategy::*;
 use super::StrategyType;
+use crate::constants::STRATEGY_VERSION;
 use super::fee_data::*;
 use crate::error::ErrorCode;
 use crate::events::{StrategyDepositEvent, StrategyInitEvent, StrategyWithdrawEvent};
@@ -31,6 +32,8 @@ pub struct TradeFintechStrategy {
     pub lock_period_ends: i64,
 
     pub fee_data: FeeData,
+
+    pub version: u8,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -302,6 +305,7 @@ impl StrategyInit for TradeFintechStrategy {
             performance_fee: config.performance_fee,
             fee_balance: 0,
         };
+        self.version = STRATEGY_VERSION;
 
         emit!(
             StrategyInitEvent 
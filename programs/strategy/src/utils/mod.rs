@@ -1,6 +1,7 @@
 pub mod execute_swap;
 pub mod get_token_balance;
 pub mod orca_utils;
+pub mod pause;
 pub mod token;
 pub mod unchecked_strategy;
 pub mod whirlpool;
@@ -8,6 +9,7 @@ pub mod whirlpool;
 pub use execute_swap::*;
 pub use get_token_balance::*;
 pub use orca_utils::*;
+pub use pause::*;
 pub use token::*;
 pub use unchecked_strategy::*;
 pub use whirlpool::*;
\ No newline at end of file
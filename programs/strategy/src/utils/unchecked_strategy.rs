@@ -3,6 +3,7 @@ use anchor_lang::{
     Discriminator
 };
 
+use crate::constants::DISCRIMINATOR_LEN;
 use crate::state::*;
 use crate::error::ErrorCode;
 
@@ -12,8 +13,7 @@ pub trait UncheckedStrategy {
     fn manager(&self) -> Pubkey;
     fn vault(&self) -> Pubkey;
     fn from_unchecked(&self) -> Result<Box<dyn Strategy>>;
-    fn save_changes<T>(&self, strategy: Box<T>) -> Result<()> 
-        where T: Strategy + AnchorSerialize;
+    fn save_changes(&self, strategy: &dyn Strategy) -> Result<()>;
 }
 
 impl<'a> UncheckedStrategy for UncheckedAccount<'a> {
@@ -41,12 +41,17 @@ impl<'a> UncheckedStrategy for UncheckedAccount<'a> {
         deserialize(&self.to_account_info())
     }
 
-    fn save_changes<T>(&self, strategy: Box<T>) -> Result<()> 
-    where
-        T: Strategy + AnchorSerialize
-    {
+    // old layouts can be read but are too small for the current one, they have to be migrated before any write
+    fn save_changes(&self, strategy: &dyn Strategy) -> Result<()> {
+        let mut buf = Vec::new();
+        strategy.save_changes(&mut buf)?;
+
         let mut strategy_data = self.try_borrow_mut_data()?;
-        strategy.serialize(&mut &mut strategy_data[8..])?;
+        if strategy_data.len() != DISCRIMINATOR_LEN + buf.len() {
+            return Err(ErrorCode::StrategyNotMigrated.into());
+        }
+
+        strategy_data[DISCRIMINATOR_LEN..].copy_from_slice(&buf);
         Ok(())
     }
 }
@@ -57,18 +62,15 @@ pub fn deserialize(strategy: &AccountInfo) -> Result<Box<dyn Strategy>> {
 
     match discriminator {
         SimpleStrategy::DISCRIMINATOR => {
-            let strategy = SimpleStrategy::try_from_slice(&strategy_data[8..])
-                .map_err(|_| ErrorCode::InvalidStrategyData)?;
+            let strategy = read_layout::<SimpleStrategy, SimpleStrategyV0>(&strategy_data)?;
             Ok(Box::new(strategy))
         }
         TradeFintechStrategy::DISCRIMINATOR => {
-            let strategy = TradeFintechStrategy::try_from_slice(&strategy_data[8..])
-                .map_err(|_| ErrorCode::InvalidStrategyData)?;
+            let strategy = read_layout::<TradeFintechStrategy, TradeFintechStrategyV0>(&strategy_data)?;
             Ok(Box::new(strategy))
         }
         OrcaStrategy::DISCRIMINATOR => {
-            let strategy = OrcaStrategy::try_from_slice(&strategy_data[8..])
-                .map_err(|_| ErrorCode::InvalidStrategyData)?;
+            let strategy = read_layout::<OrcaStrategy, OrcaStrategyV0>(&strategy_data)?;
             Ok(Box::new(strategy))
         }
         _ => {
@@ -76,4 +78,23 @@ pub fn deserialize(strategy: &AccountInfo) -> Result<Box<dyn Strategy>> {
             Err(ErrorCode::InvalidStrategyData.into())
         }
     }
-}
\ No newline at end of file
+}
+
+// reads the current layout or any older one that can still be upgraded to it
+pub fn read_layout<T, V0>(data: &[u8]) -> Result<T>
+where
+    T: AnchorDeserialize + Space,
+    V0: AnchorDeserialize + Space + Into<T>,
+{
+    let body = &data[DISCRIMINATOR_LEN..];
+
+    let strategy = if body.len() == T::INIT_SPACE {
+        T::try_from_slice(body)
+    } else if body.len() == V0::INIT_SPACE {
+        V0::try_from_slice(body).map(Into::into)
+    } else {
+        return Err(ErrorCode::InvalidStrategyData.into());
+    };
+
+    strategy.map_err(|_| ErrorCode::InvalidStrategyData.into())
+}
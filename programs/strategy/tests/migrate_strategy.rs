@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use strategy::constants::{DISCRIMINATOR_LEN, STRATEGY_VERSION};
+use strategy::error::ErrorCode;
+use strategy::state::{FeeData, SimpleStrategy, SimpleStrategyV0};
+use strategy::utils::unchecked_strategy::{read_layout, UncheckedStrategy};
+
+fn with_strategy<R>(data: &mut [u8], f: impl FnOnce(&UncheckedAccount) -> R) -> R {
+    let key = Pubkey::new_unique();
+    let owner = strategy::ID;
+    let mut lamports = 1_000_000;
+    let info = AccountInfo::new(&key, false, true, &mut lamports, data, &owner, false, 0);
+    f(&UncheckedAccount::try_from(&info))
+}
+
+fn v0_account() -> Vec<u8> {
+    let mut data = SimpleStrategy::DISCRIMINATOR.to_vec();
+    data.push(254);
+    data.extend_from_slice(&5u64.to_le_bytes());
+    data.extend_from_slice(&[1; 32]);
+    data.extend_from_slice(&[2; 32]);
+    data.extend_from_slice(&[3; 32]);
+    data.extend_from_slice(&[4; 32]);
+    data.push(6);
+    data.extend_from_slice(&1_000u64.to_le_bytes());
+    data.extend_from_slice(&2_000u64.to_le_bytes());
+    data.extend_from_slice(&300u64.to_le_bytes());
+    data.extend_from_slice(
+        &FeeData { fee_manager: Pubkey::new_from_array([5; 32]), performance_fee: 40, fee_balance: 7 }
+            .try_to_vec()
+            .unwrap(),
+    );
+    assert_eq!(data.len(), DISCRIMINATOR_LEN + SimpleStrategyV0::INIT_SPACE);
+    data
+}
+
+#[test]
+fn reads_baseline_layout() {
+    let strategy = read_layout::<SimpleStrategy, SimpleStrategyV0>(&v0_account()).unwrap();
+
+    assert_eq!(strategy.bump, [254]);
+    assert_eq!(strategy.index_bytes, 5u64.to_le_bytes());
+    assert_eq!(strategy.vault, Pubkey::new_from_array([1; 32]));
+    assert_eq!(strategy.manager, Pubkey::new_from_array([2; 32]));
+    assert_eq!(strategy.underlying_mint, Pubkey::new_from_array([3; 32]));
+    assert_eq!(strategy.underlying_token_acc, Pubkey::new_from_array([4; 32]));
+    assert_eq!(strategy.underlying_decimals, 6);
+    assert_eq!(strategy.total_assets, 1_000);
+    assert_eq!(strategy.deposit_limit, 2_000);
+    assert_eq!(strategy.total_invested, 300);
+    assert_eq!(strategy.fee_data.fee_manager, Pubkey::new_from_array([5; 32]));
+    assert_eq!(strategy.fee_data.performance_fee, 40);
+    assert_eq!(strategy.fee_data.fee_balance, 7);
+    assert_eq!(strategy.version, STRATEGY_VERSION);
+}
+
+#[test]
+fn rejects_unknown_layout() {
+    // one extra byte would be the current layout, two is neither
+    let mut data = v0_account();
+    data.extend_from_slice(&[0, 0]);
+
+    let err = read_layout::<SimpleStrategy, SimpleStrategyV0>(&data).unwrap_err();
+    assert_eq!(err, ErrorCode::InvalidStrategyData.into());
+}
+
+#[test]
+fn refuses_to_write_unmigrated_strategy() {
+    let mut data = v0_account();
+
+    let err = with_strategy(&mut data, |strategy| {
+        let mut loaded = strategy.from_unchecked().unwrap();
+        loaded.fee_data().set_performance_fee(25).unwrap();
+        strategy.save_changes(loaded.as_ref())
+    }).unwrap_err();
+
+    assert_eq!(err, ErrorCode::StrategyNotMigrated.into());
+    assert_eq!(data, v0_account());
+}
+
+#[test]
+fn writes_migrated_strategy() {
+    let mut data = v0_account();
+    let migrated = read_layout::<SimpleStrategy, SimpleStrategyV0>(&data).unwrap();
+    data.resize(DISCRIMINATOR_LEN + SimpleStrategy::INIT_SPACE, 0);
+
+    with_strategy(&mut data, |strategy| {
+        strategy.save_changes(&migrated).unwrap();
+        let mut loaded = strategy.from_unchecked().unwrap();
+        loaded.fee_data().set_performance_fee(25).unwrap();
+        strategy.save_changes(loaded.as_ref()).unwrap();
+    });
+
+    let strategy = SimpleStrategy::try_from_slice(&data[DISCRIMINATOR_LEN..]).unwrap();
+    assert_eq!(strategy.total_assets, 1_000);
+    assert_eq!(strategy.fee_data.performance_fee, 25);
+    assert_eq!(strategy.version, STRATEGY_VERSION);
+}
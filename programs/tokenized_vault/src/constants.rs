@@ -1,5 +1,6 @@
 pub const VAULT_SEED: &str = "vault";
 pub const SHARES_SEED: &str = "shares";
+pub const JUNIOR_SHARES_SEED: &str = "junior_shares";
 pub const SHARES_ACCOUNT_SEED: &str = "shares_account";
 pub const UNDERLYING_SEED: &str = "underlying";
 pub const ROLES_SEED: &str = "roles";
@@ -8,10 +9,36 @@ pub const STRATEGY_DATA_SEED: &str = "strategy_data";
 pub const USER_DATA_SEED: &str = "user_data";
 pub const WITHDRAW_SHARES_ACCOUNT_SEED: &str = "withdraw_shares_account";
 pub const WITHDRAW_REQUEST_SEED: &str = "withdraw_request";
+pub const REFERRAL_SEED: &str = "referral";
+pub const SEIZED_SHARES_ACCOUNT_SEED: &str = "seized_shares_account";
+pub const PRICE_HISTORY_SEED: &str = "price_history";
+pub const PRICE_FEED_SEED: &str = "price_feed";
 
 pub const MAX_BPS: u64 = 10_000;
 pub const FEE_BPS: u64 = 10_000;
 pub const MAX_BPS_EXTENDED: u64 = 1_000_000_000_000;
 
+pub const PAUSE_DEPOSITS: u8 = 1 << 0;
+pub const PAUSE_DIRECT_DEPOSITS: u8 = 1 << 1;
+pub const PAUSE_WITHDRAWALS: u8 = 1 << 2;
+pub const PAUSE_WITHDRAW_REQUESTS: u8 = 1 << 3;
+pub const PAUSE_FULFILLMENTS: u8 = 1 << 4;
+pub const PAUSE_REPORTS: u8 = 1 << 5;
+pub const PAUSE_DEBT_UPDATES: u8 = 1 << 6;
+pub const PAUSE_ALL: u8 = PAUSE_DEPOSITS
+    | PAUSE_DIRECT_DEPOSITS
+    | PAUSE_WITHDRAWALS
+    | PAUSE_WITHDRAW_REQUESTS
+    | PAUSE_FULFILLMENTS
+    | PAUSE_REPORTS
+    | PAUSE_DEBT_UPDATES;
+
+// v0 is the original unversioned layout
+pub const VAULT_VERSION: u8 = 1;
+// v0 is the original `{ deposited, whitelisted }` layout
+pub const USER_DATA_VERSION: u8 = 1;
+
 pub const DISCRIMINATOR_LEN: usize = 8;
 pub const ONE_SHARE_TOKEN: u128 = 1_000_000_000;
+pub const SECONDS_PER_YEAR: u64 = 31_536_000;
+pub const PRICE_HISTORY_CAPACITY: usize = 128;
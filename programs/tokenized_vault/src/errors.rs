@@ -85,4 +85,67 @@ pub enum ErrorCode {
 
     #[msg("Direct withdraw is disabled")]
     DirectWithdrawDisabled,
+
+    #[msg("Vault must be empty")]
+    VaultNotEmpty,
+
+    #[msg("Vault is not tranched")]
+    VaultNotTranched,
+
+    #[msg("Swap output is below the minimum")]
+    SlippageExceeded,
+
+    #[msg("Swap pool does not match the expected mints")]
+    InvalidSwapPool,
+
+    #[msg("Mint has an unsupported token extension")]
+    UnsupportedMintExtension,
+
+    #[msg("Deposit is still locked")]
+    DepositLocked,
+
+    #[msg("Invalid fee")]
+    InvalidFee,
+
+    #[msg("Referrer shares account is missing or invalid")]
+    InvalidReferrerAccount,
+
+    #[msg("Invalid whitelist proof")]
+    InvalidWhitelistProof,
+
+    #[msg("Expiry must be in the future")]
+    InvalidExpiry,
+
+    #[msg("Account is sanctioned")]
+    Sanctioned,
+
+    #[msg("Account is not sanctioned")]
+    NotSanctioned,
+
+    #[msg("Operation is paused")]
+    Paused,
+
+    #[msg("Unknown pause flags")]
+    InvalidPauseFlags,
+
+    #[msg("Vault config is inconsistent")]
+    InvalidVaultConfig,
+
+    #[msg("Unknown vault layout")]
+    InvalidVaultLayout,
+
+    #[msg("User data is missing or has an unknown layout")]
+    InvalidUserData,
+
+    #[msg("Vault is already on the latest layout")]
+    VaultAlreadyMigrated,
+
+    #[msg("Every vault strategy must be passed exactly once")]
+    IncompleteStrategyList,
+
+    #[msg("Underlying and vault shares can't be swept")]
+    InvalidSweepMint,
+
+    #[msg("Vault is tranched")]
+    VaultTranched,
 }
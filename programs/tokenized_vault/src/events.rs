@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::state::VaultSettings;
+
 #[derive(Debug, AnchorDeserialize, AnchorSerialize)]
 pub struct TokenMetaData {
     pub name: String,
@@ -52,6 +54,7 @@ pub struct VaultDepositEvent {
     pub share_mint: Pubkey,
     pub authority: Pubkey,
     pub share_price: u64,
+    pub referrer: Option<Pubkey>,
     pub timestamp: i64,
 }
 
@@ -112,6 +115,7 @@ pub struct StrategyReportedEvent {
 pub struct WhitelistUpdatedEvent {
     pub user: Pubkey,
     pub whitelisted: bool,
+    pub expires_at: i64,
 }
 
 #[event]
@@ -135,6 +139,23 @@ pub struct WithdrawalRequestCanceledEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct WithdrawalRequestSeizedEvent {
+    pub user: Pubkey,
+    pub vault: Pubkey,
+    pub index: u64,
+    pub shares: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeizedSharesReleasedEvent {
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub shares: u64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct WithdrawalRequestFulfilledEvent {
     pub user: Pubkey,
@@ -193,9 +214,128 @@ pub struct VaultUpdateWhitelistedOnlyEvent {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct VaultUpdateFeesInUnderlyingEvent {
+    pub vault_key: Pubkey,
+    pub new_fees_in_underlying: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultUpdateMinDepositLockSecondsEvent {
+    pub vault_key: Pubkey,
+    pub new_min_deposit_lock_seconds: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultUpdateEarlyExitFeeEvent {
+    pub vault_key: Pubkey,
+    pub new_early_exit_fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultUpdatePriceHistoryIntervalEvent {
+    pub vault_key: Pubkey,
+    pub new_interval: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultMigratedEvent {
+    pub vault_key: Pubkey,
+    pub old_version: u8,
+    pub new_version: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultConfigUpdatedEvent {
+    pub vault_key: Pubkey,
+    pub old_config: VaultSettings,
+    pub new_config: VaultSettings,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultUpdateAuditPauseThresholdEvent {
+    pub vault_key: Pubkey,
+    pub new_audit_pause_threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultSweepEvent {
+    pub vault_key: Pubkey,
+    pub mint: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultSyncIdleEvent {
+    pub vault_key: Pubkey,
+    pub surplus: u64,
+    pub total_idle: u64,
+    pub share_price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultAuditEvent {
+    pub vault_key: Pubkey,
+    pub total_idle: u64,
+    pub idle_balance: u64,
+    pub idle_drift_bps: u64,
+    pub total_debt: u64,
+    pub strategies_debt: u64,
+    pub debt_drift_bps: u64,
+    pub total_shares: u64,
+    pub shares_supply: u64,
+    pub shares_drift_bps: u64,
+    pub deposits_paused: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultUpdatePauseFlagsEvent {
+    pub vault_key: Pubkey,
+    pub old_pause_flags: u8,
+    pub new_pause_flags: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultUpdateWhitelistRootEvent {
+    pub vault_key: Pubkey,
+    pub new_whitelist_root: [u8; 32],
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct VaultRemoveStrategyEvent {
     pub vault_key: Pubkey,
     pub strategy_key: Pubkey,
     pub removed_at: i64,
 }
+
+#[event]
+pub struct VaultTranchesEnabledEvent {
+    pub vault_key: Pubkey,
+    pub junior_shares_mint: Pubkey,
+    pub senior_target_rate: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VaultZapEvent {
+    pub vault_key: Pubkey,
+    pub input_mint: Pubkey,
+    pub output_mint: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
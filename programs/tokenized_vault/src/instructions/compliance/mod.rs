@@ -0,0 +1,5 @@
+pub mod release_seized_shares;
+pub mod seize_withdrawal_request;
+
+pub use release_seized_shares::*;
+pub use seize_withdrawal_request::*;
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface}
+};
+
+use crate::constants::{SEIZED_SHARES_ACCOUNT_SEED, SHARES_SEED};
+use crate::errors::ErrorCode;
+use crate::events::SeizedSharesReleasedEvent;
+use crate::state::Vault;
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct ReleaseSeizedShares<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, seeds = [SEIZED_SHARES_ACCOUNT_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub seized_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = shares_mint)]
+    pub recipient_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::ComplianceOfficer.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub shares_token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handle_release_seized_shares(ctx: Context<ReleaseSeizedShares>, shares: u64) -> Result<()> {
+    if shares == 0 || shares > ctx.accounts.seized_shares_account.amount {
+        return Err(ErrorCode::InsufficientShares.into());
+    }
+
+    token::transfer_shares_with_signer(
+        ctx.accounts.shares_token_program.to_account_info(),
+        ctx.accounts.seized_shares_account.to_account_info(),
+        ctx.accounts.recipient_shares_account.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.shares_mint,
+        shares,
+        &ctx.accounts.vault.load()?.seeds(),
+        &ctx.accounts.vault.load()?.seeds_shares(),
+    )?;
+
+    emit!(SeizedSharesReleasedEvent {
+        vault: ctx.accounts.vault.key(),
+        recipient: ctx.accounts.recipient_shares_account.key(),
+        shares,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
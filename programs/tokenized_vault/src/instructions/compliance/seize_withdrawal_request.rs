@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+use anchor_spl::{
+    token_interface::{Mint, TokenAccount, TokenInterface}
+};
+
+use crate::constants::{SEIZED_SHARES_ACCOUNT_SEED, SHARES_SEED, WITHDRAW_SHARES_ACCOUNT_SEED};
+use crate::errors::ErrorCode;
+use crate::events::WithdrawalRequestSeizedEvent;
+use crate::state::{Vault, WithdrawRequest};
+use crate::utils::{token, vault};
+
+#[derive(Accounts)]
+pub struct SeizeWithdrawalRequest<'info> {
+    #[account(mut, close = user)]
+    pub withdraw_request: Account<'info, WithdrawRequest>,
+
+    #[account(mut, address = withdraw_request.vault)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    /// CHECK:
+    #[account(mut, address = withdraw_request.user)]
+    pub user: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            user.key().as_ref(),
+            Role::Sanctioned.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub sanctioned: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut, 
+        seeds = [
+            WITHDRAW_SHARES_ACCOUNT_SEED.as_bytes(), 
+            vault.key().as_ref()
+        ], 
+        bump
+    )]
+    pub withdraw_pool_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed, 
+        seeds = [SEIZED_SHARES_ACCOUNT_SEED.as_bytes(), vault.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        token::mint = shares_mint,
+        token::authority = vault,
+        token::token_program = shares_token_program,
+    )]
+    pub seized_shares_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::ComplianceOfficer.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub shares_token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+// moves the shares locked by a sanctioned user's request into the vault's escrow
+pub fn handle_seize_withdrawal_request(ctx: Context<SeizeWithdrawalRequest>) -> Result<()> {
+    if !vault::is_sanctioned(&ctx.accounts.sanctioned)? {
+        return Err(ErrorCode::NotSanctioned.into());
+    }
+
+    let shares = ctx.accounts.withdraw_request.locked_shares;
+
+    token::transfer_shares_with_signer(
+        ctx.accounts.shares_token_program.to_account_info(),
+        ctx.accounts.withdraw_pool_shares_account.to_account_info(),
+        ctx.accounts.seized_shares_account.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.shares_mint,
+        shares,
+        &ctx.accounts.vault.load()?.seeds(),
+        &ctx.accounts.vault.load()?.seeds_shares(),
+    )?;
+
+    emit!(WithdrawalRequestSeizedEvent {
+        user: ctx.accounts.withdraw_request.user,
+        vault: ctx.accounts.withdraw_request.vault,
+        index: ctx.accounts.withdraw_request.index,
+        shares,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,20 +1,21 @@
 use access_control::state::UserRole;
 use access_control::{
-    constants::USER_ROLE_SEED,
+    constants::{ROLE_EXPIRY_SEED, USER_ROLE_SEED},
     program::AccessControl,
     state::Role
 };
 use anchor_lang::prelude::*;
+use ::accountant::constants::FEE_OVERRIDE_SEED;
 use anchor_spl::{
-    token::Token,
+    associated_token,
     token_interface::{Mint, TokenAccount, TokenInterface}
 };
 
-use crate::constants::{SHARES_SEED, UNDERLYING_SEED, USER_DATA_SEED};
+use crate::constants::{PAUSE_DEPOSITS, PRICE_HISTORY_SEED, SHARES_SEED, UNDERLYING_SEED, REFERRAL_SEED, USER_DATA_SEED};
 
 use crate::events::VaultDepositEvent;
-use crate::state::{UserData, Vault};
-use crate::utils::{accountant, token, vault};
+use crate::state::Vault;
+use crate::utils::{accountant, token, user_data::*, vault};
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
@@ -29,9 +30,35 @@ pub struct Deposit<'info> {
         mut,
         associated_token::mint = shares_mint, 
         associated_token::authority = accountant,
+        associated_token::token_program = shares_token_program,
     )]
     pub accountant_recipient: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            FEE_OVERRIDE_SEED.as_bytes(),
+            accountant.key().as_ref(),
+            vault.key().as_ref()
+        ],
+        bump,
+        seeds::program = ::accountant::ID
+    )]
+    pub vault_fee_override: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        mut,
+        seeds = [
+            accountant.key().as_ref(),
+            token_program.key().as_ref(),
+            underlying_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = associated_token::ID
+    )]
+    pub accountant_token_account: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
@@ -44,27 +71,41 @@ pub struct Deposit<'info> {
     #[account(mut, address = vault.load()?.underlying_mint)]
     pub underlying_mint: InterfaceAccount<'info, Mint>,
 
-    #[account(mut)]
+    #[account(mut, token::authority = receiver)]
     pub user_shares_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// CHECK: created on the first deposit, older layouts are grown on write
     #[account(
-        init_if_needed, 
-        payer = user,
-        space = UserData::LEN,
+        mut,
         seeds = [
             USER_DATA_SEED.as_bytes(), 
             vault.key().as_ref(), 
-            user.key().as_ref()
+            receiver.key().as_ref()
         ], 
         bump
         )]
-    pub user_data: Account<'info, UserData>,  
+    pub user_data: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        mut,
+        seeds = [
+            REFERRAL_SEED.as_bytes(), 
+            vault.key().as_ref(), 
+            receiver.key().as_ref()
+        ], 
+        bump
+        )]
+    pub referral: UncheckedAccount<'info>,
+
+    #[account(mut, token::mint = shares_mint)]
+    pub referrer_shares_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     /// CHECK: this account may not exist
     #[account(
         seeds = [
             USER_ROLE_SEED.as_bytes(), 
-            user.key().as_ref(),
+            receiver.key().as_ref(),
             Role::KYCVerified.to_seed().as_ref()
         ], 
         bump,
@@ -72,64 +113,182 @@ pub struct Deposit<'info> {
     )]
     pub kyc_verified: UncheckedAccount<'info>,
 
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            ROLE_EXPIRY_SEED.as_bytes(), 
+            receiver.key().as_ref(),
+            Role::KYCVerified.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub kyc_expiry: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(mut, seeds = [PRICE_HISTORY_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub price_history: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
+    /// CHECK: the wallet credited with the shares, can be the user itself
+    pub receiver: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
-    pub shares_token_program: Program<'info, Token>,
+    pub shares_token_program: Interface<'info, TokenInterface>,
     pub token_program: Interface<'info, TokenInterface>,
     pub access_control: Program<'info, AccessControl>,
 }
 
-pub fn handle_deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
-    let enter_fee = accountant::enter(&ctx.accounts.accountant, amount)?;
-    let amount_to_deposit = amount - enter_fee;
+pub fn handle_deposit(
+    ctx: Context<Deposit>,
+    amount: u64,
+    referrer: Option<Pubkey>,
+    whitelist_proof: Option<Vec<[u8; 32]>>
+) -> Result<()> {
+    deposit_assets(ctx.accounts, amount, referrer, whitelist_proof)
+}
+
+pub(crate) fn deposit_assets(
+    accounts: &mut Deposit,
+    amount: u64,
+    referrer: Option<Pubkey>,
+    whitelist_proof: Option<Vec<[u8; 32]>>
+) -> Result<()> {
+    vault::validate_not_paused(&accounts.vault, PAUSE_DEPOSITS)?;
+
+    let receiver = accounts.receiver.key();
+    let referral = vault::load_referral(
+        &accounts.referral,
+        &accounts.vault.key(),
+        &accounts.user,
+        &receiver,
+        referrer,
+        &accounts.system_program
+    )?;
+    let mut user_data = load_or_create_user_data(
+        &accounts.user_data,
+        &accounts.vault.key(),
+        &receiver,
+        &accounts.user,
+        &accounts.system_program
+    )?;
+    vault::apply_whitelist_proof(&accounts.vault, &mut user_data, &receiver, whitelist_proof)?;
+
+    let enter_fee = accountant::enter(
+        &accounts.accountant,
+        &accounts.vault.key(),
+        &[accounts.vault_fee_override.to_account_info()],
+        amount
+    )?;
+
+    let pay_in_underlying = enter_fee > 0
+        && vault::fees_in_underlying(&accounts.vault, &accounts.accountant_token_account)?;
+    // the referrer's cut of an underlying fee stays in the vault and is paid out as shares
+    let referral_assets = if pay_in_underlying {
+        vault::referral_cut(&accounts.accountant, &referral, &accounts.referrer_shares_account, enter_fee)?
+    } else {
+        0
+    };
+    let vault_amount = if pay_in_underlying { amount - enter_fee + referral_assets } else { amount };
+
+    let vault_amount = token::transfer_measured(
+        accounts.token_program.to_account_info(),
+        accounts.user_token_account.to_account_info(),
+        &mut accounts.vault_token_account,
+        accounts.user.to_account_info(),
+        &accounts.underlying_mint,
+        vault_amount,
+    )?;
+
+    // with transfer-fee mints the fee is charged on what actually arrived
+    let enter_fee = if pay_in_underlying {
+        enter_fee
+    } else {
+        accountant::enter(
+            &accounts.accountant,
+            &accounts.vault.key(),
+            &[accounts.vault_fee_override.to_account_info()],
+            vault_amount
+        )?
+    };
+    let amount_to_deposit = if pay_in_underlying { vault_amount - referral_assets } else { vault_amount - enter_fee };
 
     vault::validate_deposit(
-        &ctx.accounts.vault, 
-        &ctx.accounts.kyc_verified,
-        &ctx.accounts.user_data,
+        &accounts.vault, 
+        &accounts.kyc_verified,
+        &accounts.kyc_expiry,
+        &user_data,
         false,
         amount_to_deposit
     )?;
 
-    let mut shares = ctx.accounts.vault.load()?.convert_to_shares(amount_to_deposit);
-
-    token::transfer(
-        ctx.accounts.token_program.to_account_info(),
-        ctx.accounts.user_token_account.to_account_info(),
-        ctx.accounts.vault_token_account.to_account_info(),
-        ctx.accounts.user.to_account_info(),
-        &ctx.accounts.underlying_mint,
-        amount,
-    )?;
+    let mut shares = accounts.vault.load()?.convert_to_shares(amount_to_deposit);
 
     token::mint_to(
-        ctx.accounts.shares_token_program.to_account_info(),
-        ctx.accounts.shares_mint.to_account_info(),
-        ctx.accounts.user_shares_account.to_account_info(),
-        ctx.accounts.shares_mint.to_account_info(),
+        accounts.shares_token_program.to_account_info(),
+        accounts.shares_mint.to_account_info(),
+        accounts.user_shares_account.to_account_info(),
+        accounts.shares_mint.to_account_info(),
         shares,
-        &ctx.accounts.vault.load()?.seeds_shares(),
+        &accounts.vault.load()?.seeds_shares(),
     )?;
 
-    if enter_fee > 0 {
-        let fee_shares = ctx.accounts.vault.load()?.convert_to_shares(enter_fee);
-        shares += fee_shares;
+    user_data.handle_deposit(
+        amount,
+        shares,
+        Clock::get()?.unix_timestamp,
+        accounts.user.key() == receiver
+    );
+    save_user_data(&accounts.user_data, &mut user_data, &accounts.user, &accounts.system_program)?;
+
+    let mut referral_shares = 0;
+    if pay_in_underlying {
+        token::transfer(
+            accounts.token_program.to_account_info(),
+            accounts.user_token_account.to_account_info(),
+            accounts.accountant_token_account.to_account_info(),
+            accounts.user.to_account_info(),
+            &accounts.underlying_mint,
+            enter_fee - referral_assets,
+        )?;
+        referral_shares = accounts.vault.load()?.convert_to_shares(referral_assets);
+    } else if enter_fee > 0 {
+        let fee_shares = accounts.vault.load()?.convert_to_shares(enter_fee);
+        referral_shares = vault::referral_cut(
+            &accounts.accountant,
+            &referral,
+            &accounts.referrer_shares_account,
+            fee_shares
+        )?;
+        shares += fee_shares - referral_shares;
         token::mint_to(
-            ctx.accounts.shares_token_program.to_account_info(),
-            ctx.accounts.shares_mint.to_account_info(),
-            ctx.accounts.accountant_recipient.to_account_info(),
-            ctx.accounts.shares_mint.to_account_info(),
-            fee_shares,
-            &ctx.accounts.vault.load()?.seeds_shares(),
+            accounts.shares_token_program.to_account_info(),
+            accounts.shares_mint.to_account_info(),
+            accounts.accountant_recipient.to_account_info(),
+            accounts.shares_mint.to_account_info(),
+            fee_shares - referral_shares,
+            &accounts.vault.load()?.seeds_shares(),
         )?;
     }
 
-    ctx.accounts.user_data.deposited += amount;
+    if let Some(referrer_shares_account) = &accounts.referrer_shares_account {
+        if referral_shares > 0 {
+            shares += referral_shares;
+            token::mint_to(
+                accounts.shares_token_program.to_account_info(),
+                accounts.shares_mint.to_account_info(),
+                referrer_shares_account.to_account_info(),
+                accounts.shares_mint.to_account_info(),
+                referral_shares,
+                &accounts.vault.load()?.seeds_shares(),
+            )?;
+        }
+    }
 
-    let mut vault = ctx.accounts.vault.load_mut()?;
-    vault.handle_deposit(amount, shares);
+    let mut vault = accounts.vault.load_mut()?;
+    vault.handle_deposit(vault_amount, shares);
 
     let share_price = vault.get_share_price();
 
@@ -140,14 +299,16 @@ pub fn handle_deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
         total_share: vault.total_shares(),
         amount,
         share: shares,
-        token_account: ctx.accounts.user_token_account.to_account_info().key(),
-        share_account: ctx.accounts.user_shares_account.to_account_info().key(),
-        token_mint: ctx.accounts.vault_token_account.mint,
-        share_mint: ctx.accounts.shares_mint.to_account_info().key(),
-        authority: ctx.accounts.user.to_account_info().key(),
+        token_account: accounts.user_token_account.to_account_info().key(),
+        share_account: accounts.user_shares_account.to_account_info().key(),
+        token_mint: accounts.vault_token_account.mint,
+        share_mint: accounts.shares_mint.to_account_info().key(),
+        authority: accounts.user.to_account_info().key(),
         share_price,
+        referrer: referral.referrer(),
         timestamp: Clock::get()?.unix_timestamp,
     });
 
-    Ok(())
+    drop(vault);
+    vault::record_share_price(&accounts.vault, &accounts.price_history)
 }
\ No newline at end of file
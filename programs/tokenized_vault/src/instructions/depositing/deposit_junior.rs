@@ -0,0 +1,154 @@
+use access_control::{
+    constants::{ROLE_EXPIRY_SEED, USER_ROLE_SEED},
+    program::AccessControl,
+    state::Role
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::{Mint, TokenAccount, TokenInterface}
+};
+
+use crate::constants::{JUNIOR_SHARES_SEED, PAUSE_DEPOSITS, UNDERLYING_SEED, USER_DATA_SEED};
+
+use crate::errors::ErrorCode;
+use crate::events::VaultDepositEvent;
+use crate::state::Vault;
+use crate::utils::{token, user_data::*, vault};
+
+#[derive(Accounts)]
+pub struct DepositJunior<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(mut)]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, seeds = [JUNIOR_SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub junior_shares_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, address = vault.load()?.underlying_mint)]
+    pub underlying_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, token::mint = junior_shares_mint)]
+    pub user_shares_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: created on the first deposit, older layouts are grown on write
+    #[account(
+        mut,
+        seeds = [
+            USER_DATA_SEED.as_bytes(), 
+            vault.key().as_ref(), 
+            user.key().as_ref()
+        ], 
+        bump
+        )]
+    pub user_data: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            user.key().as_ref(),
+            Role::KYCVerified.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub kyc_verified: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            ROLE_EXPIRY_SEED.as_bytes(), 
+            user.key().as_ref(),
+            Role::KYCVerified.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub kyc_expiry: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub shares_token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub access_control: Program<'info, AccessControl>,
+}
+
+pub fn handle_deposit_junior(ctx: Context<DepositJunior>, amount: u64) -> Result<()> {
+    vault::validate_not_paused(&ctx.accounts.vault, PAUSE_DEPOSITS)?;
+
+    if !ctx.accounts.vault.load()?.is_tranched {
+        return Err(ErrorCode::VaultNotTranched.into());
+    }
+
+    let received = token::transfer_measured(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        &mut ctx.accounts.vault_token_account,
+        ctx.accounts.user.to_account_info(),
+        &ctx.accounts.underlying_mint,
+        amount,
+    )?;
+
+    let mut user_data = load_or_create_user_data(
+        &ctx.accounts.user_data,
+        &ctx.accounts.vault.key(),
+        &ctx.accounts.user.key(),
+        &ctx.accounts.user,
+        &ctx.accounts.system_program
+    )?;
+
+    vault::validate_deposit(
+        &ctx.accounts.vault, 
+        &ctx.accounts.kyc_verified,
+        &ctx.accounts.kyc_expiry,
+        &user_data,
+        false,
+        received
+    )?;
+
+    let timestamp = Clock::get()?.unix_timestamp as u64;
+    let shares = ctx.accounts.vault.load()?.convert_to_junior_shares(received, timestamp);
+
+    token::mint_to(
+        ctx.accounts.shares_token_program.to_account_info(),
+        ctx.accounts.junior_shares_mint.to_account_info(),
+        ctx.accounts.user_shares_account.to_account_info(),
+        ctx.accounts.junior_shares_mint.to_account_info(),
+        shares,
+        &ctx.accounts.vault.load()?.seeds_junior_shares(),
+    )?;
+
+    user_data.deposited += amount;
+    user_data.last_deposit_ts = Clock::get()?.unix_timestamp;
+    save_user_data(&ctx.accounts.user_data, &mut user_data, &ctx.accounts.user, &ctx.accounts.system_program)?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.handle_junior_deposit(received, shares);
+
+    emit!(VaultDepositEvent {
+        vault_key: vault.key,
+        total_debt: vault.total_debt,
+        total_idle: vault.total_idle,
+        total_share: vault.junior_total_shares,
+        amount,
+        share: shares,
+        token_account: ctx.accounts.user_token_account.to_account_info().key(),
+        share_account: ctx.accounts.user_shares_account.to_account_info().key(),
+        token_mint: ctx.accounts.vault_token_account.mint,
+        share_mint: ctx.accounts.junior_shares_mint.to_account_info().key(),
+        authority: ctx.accounts.user.to_account_info().key(),
+        share_price: vault.get_junior_share_price(timestamp),
+        referrer: None,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,21 +1,23 @@
 use access_control::{
-    constants::USER_ROLE_SEED,
+    constants::{ROLE_EXPIRY_SEED, USER_ROLE_SEED},
     program::AccessControl,
     state::Role
 };
 use anchor_lang::prelude::*;
+use ::accountant::constants::FEE_OVERRIDE_SEED;
 use anchor_spl::{
+    associated_token,
     token::Token,
-    token_interface::{Mint, TokenAccount},
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 use strategy::program::Strategy;
 
-use crate::constants::{SHARES_SEED, STRATEGY_DATA_SEED, UNDERLYING_SEED, USER_DATA_SEED};
+use crate::constants::{PAUSE_DIRECT_DEPOSITS, PRICE_HISTORY_SEED, SHARES_SEED, STRATEGY_DATA_SEED, UNDERLYING_SEED, REFERRAL_SEED, USER_DATA_SEED};
 
 use crate::errors::ErrorCode;
 use crate::events::{VaultDepositEvent, UpdatedCurrentDebtForStrategyEvent};
-use crate::state::{StrategyData, UserData, Vault};
-use crate::utils::{accountant, strategy as strategy_utils, token, vault};
+use crate::state::{StrategyData, Vault};
+use crate::utils::{accountant, strategy as strategy_utils, token, user_data::*, vault};
 
 #[derive(Accounts)]
 pub struct DirectDeposit<'info> {
@@ -34,7 +36,7 @@ pub struct DirectDeposit<'info> {
     #[account(mut, address = vault.load()?.underlying_mint)]
     pub underlying_mint: InterfaceAccount<'info, Mint>,
 
-    #[account(mut)]
+    #[account(mut, token::authority = receiver)]
     pub user_shares_account: InterfaceAccount <'info, TokenAccount>,
 
     /// CHECK:
@@ -45,8 +47,34 @@ pub struct DirectDeposit<'info> {
         mut,
         associated_token::mint = shares_mint, 
         associated_token::authority = accountant,
+        associated_token::token_program = shares_token_program,
     )]
     pub accountant_recipient: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            FEE_OVERRIDE_SEED.as_bytes(),
+            accountant.key().as_ref(),
+            vault.key().as_ref()
+        ],
+        bump,
+        seeds::program = ::accountant::ID
+    )]
+    pub vault_fee_override: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        mut,
+        seeds = [
+            accountant.key().as_ref(),
+            token_program.key().as_ref(),
+            underlying_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = associated_token::ID
+    )]
+    pub accountant_token_account: UncheckedAccount<'info>,
     
 
     /// CHECK: Should this be mut?
@@ -72,11 +100,26 @@ pub struct DirectDeposit<'info> {
     )]
     pub strategy_token_account: InterfaceAccount<'info, TokenAccount>,
 
+    /// CHECK: this account may not exist
+    #[account(
+        mut,
+        seeds = [
+            REFERRAL_SEED.as_bytes(), 
+            vault.key().as_ref(), 
+            receiver.key().as_ref()
+        ], 
+        bump
+        )]
+    pub referral: UncheckedAccount<'info>,
+
+    #[account(mut, token::mint = shares_mint)]
+    pub referrer_shares_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
     /// CHECK: this account may not exist
     #[account(
         seeds = [
             USER_ROLE_SEED.as_bytes(), 
-            user.key().as_ref(),
+            receiver.key().as_ref(),
             Role::KYCVerified.to_seed().as_ref()
         ], 
         bump,
@@ -84,63 +127,140 @@ pub struct DirectDeposit<'info> {
     )]
     pub kyc_verified: UncheckedAccount<'info>,
 
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            ROLE_EXPIRY_SEED.as_bytes(), 
+            receiver.key().as_ref(),
+            Role::KYCVerified.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub kyc_expiry: UncheckedAccount<'info>,
+
+    /// CHECK: created on the first deposit, older layouts are grown on write
     #[account(
-        init_if_needed, 
-        payer = user,
-        space = UserData::LEN,
+        mut,
         seeds = [
             USER_DATA_SEED.as_bytes(), 
             vault.key().as_ref(), 
-            user.key().as_ref()
+            receiver.key().as_ref()
         ], 
         bump
         )]
-    pub user_data: Account<'info, UserData>,  
+    pub user_data: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(mut, seeds = [PRICE_HISTORY_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub price_history: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub user: Signer<'info>,
 
+    /// CHECK: the wallet credited with the shares, can be the user itself
+    pub receiver: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
     pub token_program: Program<'info, Token>,
-    pub shares_token_program: Program<'info, Token>,
+    pub shares_token_program: Interface<'info, TokenInterface>,
     pub access_control: Program<'info, AccessControl>,
     pub strategy_program: Program<'info, Strategy>,
 }
 
-pub fn handle_direct_deposit<'info>(ctx: Context<'_, '_, '_, 'info, DirectDeposit<'info>>, amount: u64) -> Result<()> {
-    let enter_fee = accountant::enter(&ctx.accounts.accountant, amount)?;
-    let amount_to_deposit = amount - enter_fee;
+pub fn handle_direct_deposit<'info>(
+    ctx: Context<'_, '_, '_, 'info, DirectDeposit<'info>>,
+    amount: u64,
+    referrer: Option<Pubkey>,
+    whitelist_proof: Option<Vec<[u8; 32]>>
+) -> Result<()> {
+    vault::validate_not_paused(&ctx.accounts.vault, PAUSE_DIRECT_DEPOSITS)?;
+
+    let receiver = ctx.accounts.receiver.key();
+    let referral = vault::load_referral(
+        &ctx.accounts.referral,
+        &ctx.accounts.vault.key(),
+        &ctx.accounts.user,
+        &receiver,
+        referrer,
+        &ctx.accounts.system_program
+    )?;
+    let mut user_data = load_or_create_user_data(
+        &ctx.accounts.user_data,
+        &ctx.accounts.vault.key(),
+        &receiver,
+        &ctx.accounts.user,
+        &ctx.accounts.system_program
+    )?;
+    vault::apply_whitelist_proof(&ctx.accounts.vault, &mut user_data, &receiver, whitelist_proof)?;
+
+    let enter_fee = accountant::enter(
+        &ctx.accounts.accountant,
+        &ctx.accounts.vault.key(),
+        &[ctx.accounts.vault_fee_override.to_account_info()],
+        amount
+    )?;
+
+    let pay_in_underlying = enter_fee > 0
+        && vault::fees_in_underlying(&ctx.accounts.vault, &ctx.accounts.accountant_token_account)?;
+    // the referrer's cut of an underlying fee stays in the vault and is paid out as shares
+    let referral_assets = if pay_in_underlying {
+        vault::referral_cut(&ctx.accounts.accountant, &referral, &ctx.accounts.referrer_shares_account, enter_fee)?
+    } else {
+        0
+    };
+    let vault_amount = if pay_in_underlying { amount - enter_fee + referral_assets } else { amount };
+
+    let vault_amount = token::transfer_measured(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        &mut ctx.accounts.vault_token_account,
+        ctx.accounts.user.to_account_info(),
+        &ctx.accounts.underlying_mint,
+        vault_amount,
+    )?;
+
+    // with transfer-fee mints the fee is charged on what actually arrived
+    let enter_fee = if pay_in_underlying {
+        enter_fee
+    } else {
+        accountant::enter(
+            &ctx.accounts.accountant,
+            &ctx.accounts.vault.key(),
+            &[ctx.accounts.vault_fee_override.to_account_info()],
+            vault_amount
+        )?
+    };
+    let amount_to_deposit = if pay_in_underlying { vault_amount - referral_assets } else { vault_amount - enter_fee };
 
     vault::validate_deposit(
         &ctx.accounts.vault, 
         &ctx.accounts.kyc_verified.to_account_info(),
-        &ctx.accounts.user_data,
+        &ctx.accounts.kyc_expiry.to_account_info(),
+        &user_data,
         true,
         amount_to_deposit
     )?;
 
-    let new_debt = ctx.accounts.strategy_data.current_debt + amount;
+    let new_debt = ctx.accounts.strategy_data.current_debt + vault_amount;
     if new_debt > ctx.accounts.strategy_data.max_debt {
         return Err(ErrorCode::DebtHigherThanMaxDebt.into());
     }
 
     let max_strategy_deposit = strategy_utils::get_max_deposit(&ctx.accounts.strategy.to_account_info())?;
-    if amount > max_strategy_deposit {
+    if vault_amount > max_strategy_deposit {
         return Err(ErrorCode::ExceedDepositLimit.into());
     }
 
-    let mut shares = ctx.accounts.vault.load()?.convert_to_shares(amount_to_deposit);
-
-    token::transfer(
-        ctx.accounts.token_program.to_account_info(),
-        ctx.accounts.user_token_account.to_account_info(),
-        ctx.accounts.vault_token_account.to_account_info(),
-        ctx.accounts.user.to_account_info(),
-        &ctx.accounts.underlying_mint,
-        amount,
+    // the hop into the strategy is charged again on transfer-fee mints and the depositor bears it
+    let strategy_fee = token::transfer_fee(
+        &ctx.accounts.underlying_mint.to_account_info(),
+        Clock::get()?.epoch,
+        vault_amount
     )?;
+    let strategy_amount = vault_amount - strategy_fee;
 
-    ctx.accounts.vault_token_account.reload()?;
+    let mut shares = ctx.accounts.vault.load()?.convert_to_shares(amount_to_deposit - strategy_fee);
 
     strategy_utils::deposit(
         ctx.accounts.strategy.to_account_info(),
@@ -150,7 +270,7 @@ pub fn handle_direct_deposit<'info>(ctx: Context<'_, '_, '_, 'info, DirectDeposi
         ctx.accounts.vault_token_account.to_account_info(),
         ctx.accounts.token_program.to_account_info(),
         ctx.accounts.strategy_program.to_account_info(),
-        amount,
+        vault_amount,
         &[&ctx.accounts.vault.load()?.seeds()],
         ctx.remaining_accounts.to_vec(),
     ).unwrap();
@@ -164,24 +284,63 @@ pub fn handle_direct_deposit<'info>(ctx: Context<'_, '_, '_, 'info, DirectDeposi
         &ctx.accounts.vault.load()?.seeds_shares(),
     )?;
 
-    if enter_fee > 0 {
+    user_data.handle_deposit(
+        amount,
+        shares,
+        Clock::get()?.unix_timestamp,
+        ctx.accounts.user.key() == receiver
+    );
+    save_user_data(&ctx.accounts.user_data, &mut user_data, &ctx.accounts.user, &ctx.accounts.system_program)?;
+
+    let mut referral_shares = 0;
+    if pay_in_underlying {
+        token::transfer(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.user_token_account.to_account_info(),
+            ctx.accounts.accountant_token_account.to_account_info(),
+            ctx.accounts.user.to_account_info(),
+            &ctx.accounts.underlying_mint,
+            enter_fee - referral_assets,
+        )?;
+        referral_shares = ctx.accounts.vault.load()?.convert_to_shares(referral_assets);
+    } else if enter_fee > 0 {
         let fee_shares = ctx.accounts.vault.load()?.convert_to_shares(enter_fee);
-        shares += fee_shares;
+        referral_shares = vault::referral_cut(
+            &ctx.accounts.accountant,
+            &referral,
+            &ctx.accounts.referrer_shares_account,
+            fee_shares
+        )?;
+        shares += fee_shares - referral_shares;
         token::mint_to(
             ctx.accounts.shares_token_program.to_account_info(),
             ctx.accounts.shares_mint.to_account_info(),
             ctx.accounts.accountant_recipient.to_account_info(),
             ctx.accounts.shares_mint.to_account_info(),
-            fee_shares,
+            fee_shares - referral_shares,
             &ctx.accounts.vault.load()?.seeds_shares(),
         )?;
     }
 
+    if let Some(referrer_shares_account) = &ctx.accounts.referrer_shares_account {
+        if referral_shares > 0 {
+            shares += referral_shares;
+            token::mint_to(
+                ctx.accounts.shares_token_program.to_account_info(),
+                ctx.accounts.shares_mint.to_account_info(),
+                referrer_shares_account.to_account_info(),
+                ctx.accounts.shares_mint.to_account_info(),
+                referral_shares,
+                &ctx.accounts.vault.load()?.seeds_shares(),
+            )?;
+        }
+    }
+
     let mut vault = ctx.accounts.vault.load_mut()?;
 
-    ctx.accounts.strategy_data.increase_current_debt(amount)?;
+    ctx.accounts.strategy_data.increase_current_debt(strategy_amount)?;
 
-    vault.handle_direct_deposit(amount, shares);
+    vault.handle_direct_deposit(strategy_amount, shares);
 
     let share_price = vault.get_share_price();
 
@@ -198,6 +357,7 @@ pub fn handle_direct_deposit<'info>(ctx: Context<'_, '_, '_, 'info, DirectDeposi
         share_mint: ctx.accounts.shares_mint.to_account_info().key(),
         authority: ctx.accounts.user.to_account_info().key(),
         share_price,
+        referrer: referral.referrer(),
         timestamp: Clock::get()?.unix_timestamp,
     });
 
@@ -209,5 +369,6 @@ pub fn handle_direct_deposit<'info>(ctx: Context<'_, '_, '_, 'info, DirectDeposi
         new_debt: ctx.accounts.strategy_data.current_debt,
     });
 
-    Ok(())
+    drop(vault);
+    vault::record_share_price(&ctx.accounts.vault, &ctx.accounts.price_history)
 }
\ No newline at end of file
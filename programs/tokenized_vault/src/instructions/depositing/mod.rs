@@ -1,5 +1,9 @@
 pub mod deposit;
+pub mod deposit_junior;
 pub mod direct_deposit;
+pub mod zap_deposit;
 
 pub use deposit::*;
-pub use direct_deposit::*;
\ No newline at end of file
+pub use deposit_junior::*;
+pub use direct_deposit::*;
+pub use zap_deposit::*;
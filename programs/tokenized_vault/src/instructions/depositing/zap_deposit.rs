@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::VaultZapEvent;
+use crate::instructions::deposit::*;
+use crate::utils::WhirlpoolSwap;
+
+#[derive(Accounts)]
+pub struct ZapDeposit<'info> {
+    pub deposit: Deposit<'info>,
+
+    #[account(
+        mut,
+        token::mint = input_mint,
+        token::authority = deposit.user,
+        token::token_program = input_token_program,
+        constraint = user_input_token_account.mint != deposit.underlying_mint.key() @ ErrorCode::InvalidSwapPool
+    )]
+    pub user_input_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub input_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: validated by the whirlpool program
+    #[account(mut)]
+    pub whirlpool: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: validated by the whirlpool program
+    #[account(mut)]
+    pub tick_array_0: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the whirlpool program
+    #[account(mut)]
+    pub tick_array_1: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the whirlpool program
+    #[account(mut)]
+    pub tick_array_2: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the whirlpool program
+    pub oracle: UncheckedAccount<'info>,
+
+    /// CHECK: whirlpool program
+    #[account(address = whirlpool_cpi::ID)]
+    pub whirlpool_program: UncheckedAccount<'info>,
+
+    pub input_token_program: Interface<'info, TokenInterface>,
+    pub memo_program: Program<'info, Memo>,
+}
+
+pub fn handle_zap_deposit(
+    ctx: Context<ZapDeposit>,
+    amount_in: u64,
+    min_underlying_out: u64,
+    referrer: Option<Pubkey>,
+    whitelist_proof: Option<Vec<[u8; 32]>>
+) -> Result<()> {
+    let input_mint = ctx.accounts.user_input_token_account.mint;
+    let underlying_mint = ctx.accounts.deposit.underlying_mint.key();
+
+    let underlying_is_a = swap_direction(
+        &ctx.accounts.token_vault_a.mint,
+        &ctx.accounts.token_vault_b.mint,
+        &underlying_mint,
+        &input_mint
+    )?;
+
+    let underlying_side = (
+        ctx.accounts.deposit.token_program.to_account_info(),
+        ctx.accounts.deposit.underlying_mint.to_account_info(),
+        ctx.accounts.deposit.user_token_account.to_account_info(),
+    );
+    let input_side = (
+        ctx.accounts.input_token_program.to_account_info(),
+        ctx.accounts.input_mint.to_account_info(),
+        ctx.accounts.user_input_token_account.to_account_info(),
+    );
+    let ((token_program_a, token_mint_a, token_owner_account_a), (token_program_b, token_mint_b, token_owner_account_b)) =
+        if underlying_is_a { (underlying_side, input_side) } else { (input_side, underlying_side) };
+
+    let swap = WhirlpoolSwap {
+        whirlpool_program: ctx.accounts.whirlpool_program.to_account_info(),
+        whirlpool: ctx.accounts.whirlpool.to_account_info(),
+        token_program_a,
+        token_program_b,
+        memo_program: ctx.accounts.memo_program.to_account_info(),
+        token_authority: ctx.accounts.deposit.user.to_account_info(),
+        token_mint_a,
+        token_mint_b,
+        token_owner_account_a,
+        token_vault_a: ctx.accounts.token_vault_a.to_account_info(),
+        token_owner_account_b,
+        token_vault_b: ctx.accounts.token_vault_b.to_account_info(),
+        tick_array_0: ctx.accounts.tick_array_0.to_account_info(),
+        tick_array_1: ctx.accounts.tick_array_1.to_account_info(),
+        tick_array_2: ctx.accounts.tick_array_2.to_account_info(),
+        oracle: ctx.accounts.oracle.to_account_info(),
+    };
+
+    let input_before = ctx.accounts.user_input_token_account.amount;
+    let underlying_before = ctx.accounts.deposit.user_token_account.amount;
+
+    // input is sold for underlying, so the pool goes A to B when the underlying is token B
+    swap.swap_exact_in(amount_in, min_underlying_out, !underlying_is_a)?;
+
+    ctx.accounts.user_input_token_account.reload()?;
+    ctx.accounts.deposit.user_token_account.reload()?;
+
+    let amount_out = ctx.accounts.deposit.user_token_account.amount - underlying_before;
+    if amount_out < min_underlying_out {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+
+    let amount_in = input_before - ctx.accounts.user_input_token_account.amount;
+
+    deposit_assets(&mut ctx.accounts.deposit, amount_out, referrer, whitelist_proof)?;
+
+    emit!(VaultZapEvent {
+        vault_key: ctx.accounts.deposit.vault.key(),
+        input_mint,
+        output_mint: underlying_mint,
+        amount_in,
+        amount_out,
+        authority: ctx.accounts.deposit.user.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Returns true when the underlying is token A of the pool and the other mint is token B.
+pub fn swap_direction(
+    mint_a: &Pubkey,
+    mint_b: &Pubkey,
+    underlying_mint: &Pubkey,
+    other_mint: &Pubkey,
+) -> Result<bool> {
+    if mint_a == underlying_mint && mint_b == other_mint {
+        Ok(true)
+    } else if mint_a == other_mint && mint_b == underlying_mint {
+        Ok(false)
+    } else {
+        Err(ErrorCode::InvalidSwapPool.into())
+    }
+}
@@ -1,9 +1,11 @@
+pub mod compliance;
 pub mod depositing;
 pub mod reporting;
 pub mod vaults_management;
 pub mod whitelisting;
 pub mod withdrawal;
 
+pub use compliance::*;
 pub use depositing::*;
 pub use reporting::*;
 pub use vaults_management::*;
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+
+use crate::constants::{MAX_BPS, PAUSE_DEPOSITS, PAUSE_DIRECT_DEPOSITS, SHARES_SEED, STRATEGY_DATA_SEED, UNDERLYING_SEED};
+use crate::errors::ErrorCode;
+use crate::events::VaultAuditEvent;
+use crate::state::{StrategyData, Vault};
+use crate::utils::unchecked::*;
+
+// permissionless, remaining accounts are `[strategy, strategy_data]` pairs covering every vault strategy
+#[derive(Accounts)]
+pub struct AuditVault<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+}
+
+pub fn handle_audit_vault(ctx: Context<AuditVault>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let strategies_debt = strategies_debt(&vault_key, ctx.remaining_accounts, ctx.accounts.vault.load()?.strategies_amount)?;
+
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    let idle_balance = ctx.accounts.vault_token_account.amount;
+    let shares_supply = ctx.accounts.shares_mint.supply;
+
+    // anyone can donate underlying or burn their own shares, only missing funds and unbacked shares count
+    let idle_drift_bps = drift_bps(vault.total_idle, idle_balance.min(vault.total_idle));
+    let debt_drift_bps = drift_bps(vault.total_debt, strategies_debt);
+    let shares_drift_bps = drift_bps(vault.total_shares, shares_supply.max(vault.total_shares));
+
+    let max_drift_bps = idle_drift_bps.max(debt_drift_bps).max(shares_drift_bps);
+    if vault.audit_pause_threshold > 0 && max_drift_bps > vault.audit_pause_threshold {
+        vault.pause_flags |= PAUSE_DEPOSITS | PAUSE_DIRECT_DEPOSITS;
+    }
+
+    emit!(VaultAuditEvent {
+        vault_key,
+        total_idle: vault.total_idle,
+        idle_balance,
+        idle_drift_bps,
+        total_debt: vault.total_debt,
+        strategies_debt,
+        debt_drift_bps,
+        total_shares: vault.total_shares,
+        shares_supply,
+        shares_drift_bps,
+        deposits_paused: vault.is_paused(PAUSE_DEPOSITS),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+fn strategies_debt(vault_key: &Pubkey, remaining_accounts: &[AccountInfo], strategies_amount: u64) -> Result<u64> {
+    if remaining_accounts.len() as u64 != strategies_amount * 2 {
+        return Err(ErrorCode::IncompleteStrategyList.into());
+    }
+
+    let mut audited: Vec<Pubkey> = Vec::new();
+    let mut total: u64 = 0;
+
+    for pair in remaining_accounts.chunks(2) {
+        let (strategy, strategy_data) = (&pair[0], &pair[1]);
+
+        if audited.contains(strategy.key) {
+            return Err(ErrorCode::IncompleteStrategyList.into());
+        }
+        audited.push(*strategy.key);
+
+        let (expected, _) = Pubkey::find_program_address(
+            &[STRATEGY_DATA_SEED.as_bytes(), vault_key.as_ref(), strategy.key.as_ref()],
+            &crate::ID,
+        );
+        if *strategy_data.key != expected || *strategy_data.owner != crate::ID || strategy_data.data_is_empty() {
+            return Err(ErrorCode::InvalidStrategy.into());
+        }
+
+        total += strategy_data.deserialize::<StrategyData>()?.current_debt;
+    }
+
+    Ok(total)
+}
+
+fn drift_bps(expected: u64, actual: u64) -> u64 {
+    let diff = expected.abs_diff(actual) as u128;
+    if diff == 0 {
+        return 0;
+    }
+    if expected == 0 {
+        return MAX_BPS;
+    }
+    std::cmp::min(diff * MAX_BPS as u128 / expected as u128, u64::MAX as u128) as u64
+}
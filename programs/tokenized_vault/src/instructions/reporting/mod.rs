@@ -1,3 +1,9 @@
+pub mod audit_vault;
 pub mod process_report;
+pub mod refresh_price;
+pub mod sync_idle;
 
+pub use audit_vault::*;
 pub use process_report::*;
+pub use refresh_price::*;
+pub use sync_idle::*;
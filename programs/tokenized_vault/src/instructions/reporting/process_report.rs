@@ -1,7 +1,8 @@
 use anchor_lang::prelude::*;
+use ::accountant::constants::FEE_OVERRIDE_SEED;
 use anchor_spl::{
-    token::Token,
-    token_interface::{ Mint, TokenAccount},
+    associated_token,
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 use access_control::{
     constants::USER_ROLE_SEED,
@@ -9,10 +10,10 @@ use access_control::{
     state::{UserRole, Role}
 };
 
-use crate::constants::{ MAX_BPS_EXTENDED, SHARES_ACCOUNT_SEED, SHARES_SEED, STRATEGY_DATA_SEED, ONE_SHARE_TOKEN};
+use crate::constants::{ MAX_BPS_EXTENDED, PAUSE_REPORTS, PRICE_FEED_SEED, PRICE_HISTORY_SEED, SHARES_ACCOUNT_SEED, SHARES_SEED, STRATEGY_DATA_SEED, UNDERLYING_SEED, ONE_SHARE_TOKEN};
 use crate::events::StrategyReportedEvent;
 use crate::state::{Vault, StrategyData};
-use crate::utils::{accountant, strategy, token};
+use crate::utils::{accountant, strategy, token, vault};
 
 #[derive(Accounts)]
 pub struct ProcessReport<'info> {
@@ -48,9 +49,61 @@ pub struct ProcessReport<'info> {
         mut,
         associated_token::mint = shares_mint, 
         associated_token::authority = accountant,
+        associated_token::token_program = token_program,
     )]
     pub accountant_recipient: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            FEE_OVERRIDE_SEED.as_bytes(),
+            accountant.key().as_ref(),
+            vault.key().as_ref()
+        ],
+        bump,
+        seeds::program = ::accountant::ID
+    )]
+    pub vault_fee_override: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            FEE_OVERRIDE_SEED.as_bytes(),
+            accountant.key().as_ref(),
+            strategy.key().as_ref()
+        ],
+        bump,
+        seeds::program = ::accountant::ID
+    )]
+    pub strategy_fee_override: UncheckedAccount<'info>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(address = vault.load()?.underlying_mint)]
+    pub underlying_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        mut,
+        seeds = [
+            accountant.key().as_ref(),
+            underlying_token_program.key().as_ref(),
+            underlying_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = associated_token::ID
+    )]
+    pub accountant_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(mut, seeds = [PRICE_HISTORY_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub price_history: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(mut, seeds = [PRICE_FEED_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub price_feed: UncheckedAccount<'info>,
+
     #[account(
         seeds = [
             USER_ROLE_SEED.as_bytes(), 
@@ -66,10 +119,13 @@ pub struct ProcessReport<'info> {
     pub signer: Signer<'info>,
 
     pub access_control: Program<'info, AccessControl>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub underlying_token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handle_process_report(ctx: Context<ProcessReport>) -> Result<()> {
+    vault::validate_not_paused(&ctx.accounts.vault, PAUSE_REPORTS)?;
+
     let strategy_assets = strategy::get_total_assets(&ctx.accounts.strategy)?;
     let strategy = &ctx.accounts.strategy;
 
@@ -77,18 +133,47 @@ pub fn handle_process_report(ctx: Context<ProcessReport>) -> Result<()> {
     let mut loss: u64 = 0;
     let mut fee_shares: u64 = 0;
 
-    burn_unlocked_shares(&ctx)?;
+    burn_unlocked_shares(
+        &ctx.accounts.vault,
+        &ctx.accounts.shares_mint,
+        &ctx.accounts.vault_shares_token_account,
+        &ctx.accounts.token_program,
+    )?;
     ctx.accounts.vault_shares_token_account.reload()?;
     let current_debt = ctx.accounts.strategy_data.current_debt;
     
     if strategy_assets > current_debt {
         profit = strategy_assets - current_debt;
-        let (total_fees, _) = accountant::report(&ctx.accounts.accountant, profit, 0)?;
-        fee_shares = ctx.accounts.vault.load()?.convert_to_shares(total_fees);
+        let (total_fees, _) = accountant::report(
+            &ctx.accounts.accountant,
+            &ctx.accounts.vault.key(),
+            &strategy.key(),
+            &[
+                ctx.accounts.strategy_fee_override.to_account_info(),
+                ctx.accounts.vault_fee_override.to_account_info(),
+            ],
+            profit,
+            0
+        )?;
+
+        // fees are paid in underlying as far as idle above the minimum allows, the rest in shares
+        let underlying_fees = if total_fees > 0
+            && vault::fees_in_underlying(&ctx.accounts.vault, &ctx.accounts.accountant_token_account)?
+        {
+            std::cmp::min(total_fees, ctx.accounts.vault.load()?.idle_for_fees(0))
+        } else {
+            0
+        };
+
+        if underlying_fees > 0 {
+            pay_fees_in_underlying(&ctx, underlying_fees)?;
+        }
+        let share_fees = total_fees - underlying_fees;
+        fee_shares = ctx.accounts.vault.load()?.convert_to_shares(share_fees);
         handle_profit(&ctx, profit, total_fees)?;
 
         if fee_shares > 0 {
-            issue_fee_shares(&ctx, fee_shares)?;
+            issue_fee_shares(&ctx, share_fees, fee_shares)?;
         }
     } else {
         loss = current_debt - strategy_assets;
@@ -115,10 +200,11 @@ pub fn handle_process_report(ctx: Context<ProcessReport>) -> Result<()> {
         timestamp: Clock::get()?.unix_timestamp,
     });
 
-    Ok(())
+    vault::record_share_price(&ctx.accounts.vault, &ctx.accounts.price_history)?;
+    vault::update_price_feed(&ctx.accounts.vault, &ctx.accounts.price_feed)
 }
 
-fn issue_fee_shares(ctx: &Context<ProcessReport>, fee_shares: u64) -> Result<u64> {
+fn issue_fee_shares(ctx: &Context<ProcessReport>, fees: u64, fee_shares: u64) -> Result<u64> {
     let vault = &mut ctx.accounts.vault.load_mut()?;
 
     token::mint_to(
@@ -130,20 +216,60 @@ fn issue_fee_shares(ctx: &Context<ProcessReport>, fee_shares: u64) -> Result<u64
         &vault.seeds_shares()
     )?;
 
-    vault.total_shares += fee_shares;
+    vault.handle_fee_shares(fees, fee_shares);
     Ok(fee_shares)
 }
 
+fn pay_fees_in_underlying(ctx: &Context<ProcessReport>, fees: u64) -> Result<()> {
+    token::transfer_with_signer(
+        ctx.accounts.underlying_token_program.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.accountant_token_account.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.underlying_mint,
+        fees,
+        &ctx.accounts.vault.load()?.seeds()
+    )?;
+
+    ctx.accounts.vault.load_mut()?.handle_fee_payment(fees);
+    Ok(())
+}
+
 fn handle_profit(ctx: &Context<ProcessReport>, profit: u64, fees: u64) -> Result<()> {
-    let vault = &mut ctx.accounts.vault.load_mut()?;
+    lock_profit(
+        &ctx.accounts.vault,
+        &ctx.accounts.shares_mint,
+        &ctx.accounts.vault_shares_token_account,
+        &ctx.accounts.token_program,
+        profit,
+        fees,
+    )?;
+
+    ctx.accounts.vault.load_mut()?.total_debt += profit;
+
+    Ok(())
+}
+
+// locks `profit - fees` worth of shares until `profit_max_unlock_time`,
+// the caller adds `profit` to the vault funds afterwards
+pub(crate) fn lock_profit<'info>(
+    vault_loader: &AccountLoader<'info, Vault>,
+    shares_mint: &InterfaceAccount<'info, Mint>,
+    vault_shares_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+    profit: u64,
+    fees: u64,
+) -> Result<()> {
+    let vault = &mut vault_loader.load_mut()?;
   
     let mut shares_to_lock = 0;
-    if vault.profit_max_unlock_time != 0 {
+    // tranched vaults hand out profit through the waterfall right away
+    if vault.profit_max_unlock_time != 0 && !vault.is_tranched {
         // we don't lock fee shares
         let amount_to_lock = profit - fees;
         shares_to_lock = vault.convert_to_shares(amount_to_lock);
 
-        let curr_locked_shares = ctx.accounts.vault_shares_token_account.amount;
+        let curr_locked_shares = vault_shares_token_account.amount;
         let newly_locked_shares = curr_locked_shares + shares_to_lock;
     
         let curr_timestamp = get_timestamp()?;
@@ -174,16 +300,16 @@ fn handle_profit(ctx: &Context<ProcessReport>, profit: u64, fees: u64) -> Result
 
         // mint shares to lock
         token::mint_to(
-            ctx.accounts.token_program.to_account_info(),
-            ctx.accounts.shares_mint.to_account_info(),
-            ctx.accounts.vault_shares_token_account.to_account_info(),
-            ctx.accounts.shares_mint.to_account_info(),
+            token_program.to_account_info(),
+            shares_mint.to_account_info(),
+            vault_shares_token_account.to_account_info(),
+            shares_mint.to_account_info(),
             shares_to_lock,
             &vault.seeds_shares()
         )?;
     }
 
-    vault.total_debt += profit;
+    vault.apply_tranche_profit(profit - fees, get_timestamp()?);
     vault.total_shares += shares_to_lock;
 
     Ok(())
@@ -203,6 +329,7 @@ fn handle_loss(ctx: &Context<ProcessReport>, loss: u64) -> Result<()> {
     )?;
 
     let vault = &mut ctx.accounts.vault.load_mut()?;
+    vault.apply_tranche_loss(loss, get_timestamp()?);
     vault.total_debt -= loss;
     vault.last_profit_update = get_timestamp()?;
     vault.total_shares -= shares_to_burn;
@@ -210,10 +337,15 @@ fn handle_loss(ctx: &Context<ProcessReport>, loss: u64) -> Result<()> {
     Ok(())
 }
 
-fn burn_unlocked_shares(ctx: &Context<ProcessReport>) -> Result<()> {
+pub(crate) fn burn_unlocked_shares<'info>(
+    vault_loader: &AccountLoader<'info, Vault>,
+    shares_mint: &InterfaceAccount<'info, Mint>,
+    vault_shares_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Interface<'info, TokenInterface>,
+) -> Result<()> {
     let shares_to_burn: u64 = get_shares_to_burn(
-        &ctx.accounts.vault, 
-        ctx.accounts.vault_shares_token_account.amount
+        vault_loader, 
+        vault_shares_token_account.amount
     )?;
    
     if shares_to_burn == 0 {
@@ -222,16 +354,16 @@ fn burn_unlocked_shares(ctx: &Context<ProcessReport>) -> Result<()> {
 
     // Burn the shares unlocked.
     token::burn_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        ctx.accounts.shares_mint.to_account_info(),
-        ctx.accounts.vault_shares_token_account.to_account_info(),
-        ctx.accounts.vault.to_account_info(),
+        token_program.to_account_info(),
+        shares_mint.to_account_info(),
+        vault_shares_token_account.to_account_info(),
+        vault_loader.to_account_info(),
         shares_to_burn,
-        &ctx.accounts.vault.load()?.seeds(),
+        &vault_loader.load()?.seeds(),
     )?;
 
 
-    let mut vault = ctx.accounts.vault.load_mut()?;
+    let mut vault = vault_loader.load_mut()?;
     vault.total_shares -= shares_to_burn;
 
     Ok(())
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::PRICE_FEED_SEED;
+use crate::state::{PriceFeed, Vault};
+
+// permissionless, the price moves between reports as locked profit unlocks
+#[derive(Accounts)]
+pub struct RefreshPrice<'info> {
+    #[account(mut, seeds = [PRICE_FEED_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    #[account()]
+    pub vault: AccountLoader<'info, Vault>,
+}
+
+pub fn handle_refresh_price(ctx: Context<RefreshPrice>) -> Result<()> {
+    ctx.accounts.price_feed.update(&*ctx.accounts.vault.load()?)
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::{PAUSE_REPORTS, SHARES_ACCOUNT_SEED, SHARES_SEED, UNDERLYING_SEED};
+use crate::errors::ErrorCode;
+use crate::events::VaultSyncIdleEvent;
+use crate::instructions::reporting::process_report::{burn_unlocked_shares, lock_profit};
+use crate::state::Vault;
+use crate::utils::vault;
+
+#[derive(Accounts)]
+pub struct SyncIdle<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, seeds = [SHARES_ACCOUNT_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_shares_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::ReportingManager.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// books underlying sent straight to the vault as profit, locked like a strategy report
+pub fn handle_sync_idle(ctx: Context<SyncIdle>) -> Result<()> {
+    vault::validate_not_paused(&ctx.accounts.vault, PAUSE_REPORTS)?;
+
+    // tranched vaults split profit through the senior waterfall, which only reports drive
+    if ctx.accounts.vault.load()?.is_tranched {
+        return Err(ErrorCode::VaultTranched.into());
+    }
+
+    let total_idle = ctx.accounts.vault.load()?.total_idle;
+    let balance = ctx.accounts.vault_token_account.amount;
+
+    if balance <= total_idle {
+        return Err(ErrorCode::ZeroValue.into());
+    }
+    let surplus = balance - total_idle;
+
+    burn_unlocked_shares(
+        &ctx.accounts.vault,
+        &ctx.accounts.shares_mint,
+        &ctx.accounts.vault_shares_token_account,
+        &ctx.accounts.token_program,
+    )?;
+    ctx.accounts.vault_shares_token_account.reload()?;
+
+    lock_profit(
+        &ctx.accounts.vault,
+        &ctx.accounts.shares_mint,
+        &ctx.accounts.vault_shares_token_account,
+        &ctx.accounts.token_program,
+        surplus,
+        0,
+    )?;
+
+    let mut vault = ctx.accounts.vault.load_mut()?;
+    vault.total_idle += surplus;
+
+    emit!(VaultSyncIdleEvent {
+        vault_key: vault.key,
+        surplus,
+        total_idle: vault.total_idle,
+        share_price: vault.get_share_price(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{Role, UserRole}
+};
+use anchor_spl::{
+    token::Token,
+    token_interface::Mint,
+};
+
+use crate::constants::JUNIOR_SHARES_SEED;
+use crate::errors::ErrorCode;
+use crate::events::VaultTranchesEnabledEvent;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct EnableTranches<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        init, 
+        seeds = [JUNIOR_SHARES_SEED.as_bytes(), vault.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        mint::decimals = 9, 
+        mint::authority = junior_shares_mint,
+    )]
+    pub junior_shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_enable_tranches(ctx: Context<EnableTranches>, senior_target_rate: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    if vault.is_shutdown {
+        return Err(ErrorCode::VaultShutdown.into());
+    }
+
+    // tranche accounting has to start from an empty vault
+    if vault.total_shares != 0 || vault.total_funds() != 0 {
+        return Err(ErrorCode::VaultNotEmpty.into());
+    }
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    vault.enable_tranches(ctx.bumps.junior_shares_mint, senior_target_rate, timestamp as u64);
+
+    emit!(VaultTranchesEnabledEvent {
+        vault_key: vault.key,
+        junior_shares_mint: ctx.accounts.junior_shares_mint.key(),
+        senior_target_rate,
+        timestamp,
+    });
+
+    Ok(())
+}
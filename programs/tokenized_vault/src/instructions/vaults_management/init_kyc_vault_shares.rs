@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{Role, UserRole}
+};
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+    metadata::Metadata,
+};
+use kyc_hook::{
+    cpi::accounts::InitializeExtraAccountMetaList,
+    program::KycHook,
+};
+
+use crate::constants::{
+    VAULT_SEED, 
+    SHARES_SEED, 
+    SHARES_ACCOUNT_SEED, 
+    CONFIG_SEED,
+};
+use crate::state::*;
+use super::init_vault_shares::{create_shares_metadata, finish_init_vault_shares};
+
+#[derive(Accounts)]
+pub struct InitKycVaultShares<'info> {
+    #[account(
+        mut, 
+        seeds = [
+            VAULT_SEED.as_bytes(), 
+            config.next_vault_index.to_le_bytes().as_ref()
+        ], 
+        bump
+    )]
+    pub vault: AccountLoader<'info, Vault>,
+    
+    #[account(
+        init, 
+        seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        mint::decimals = 9, 
+        mint::authority = shares_mint,
+        mint::token_program = token_program,
+        extensions::transfer_hook::authority = shares_mint,
+        extensions::transfer_hook::program_id = kyc_hook_program,
+    )]
+    pub shares_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: We initialize this metadata account via the Metaplex Metadata Program, so we don't have to check it here
+    #[account(mut)]
+    pub metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init, 
+        seeds = [SHARES_ACCOUNT_SEED.as_bytes(), vault.key().as_ref()], 
+        bump, 
+        payer = signer, 
+        token::mint = shares_mint,
+        token::authority = vault,
+        token::token_program = token_program,
+    )]
+    pub shares_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: initialized by the kyc hook program
+    #[account(mut)]
+    pub extra_account_meta_list: UncheckedAccount<'info>,
+    
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    #[account(mut, seeds = [CONFIG_SEED.as_bytes()], bump)]
+    pub config: Box<Account<'info, Config>>,
+    
+    pub access_control: Program<'info, AccessControl>,
+    pub kyc_hook_program: Program<'info, KycHook>,
+    pub token_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    pub metadata_program: Program<'info, Metadata>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle_init_kyc_vault_shares(ctx: Context<InitKycVaultShares>, _index: u64, config: Box<SharesConfig>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let seeds = &[SHARES_SEED.as_bytes(), vault_key.as_ref(), &[ctx.bumps.shares_mint]];
+    let signer = [&seeds[..]];
+
+    kyc_hook::cpi::initialize_extra_account_meta_list(
+        CpiContext::new_with_signer(
+            ctx.accounts.kyc_hook_program.to_account_info(),
+            InitializeExtraAccountMetaList {
+                extra_account_meta_list: ctx.accounts.extra_account_meta_list.to_account_info(),
+                mint: ctx.accounts.shares_mint.to_account_info(),
+                mint_authority: ctx.accounts.shares_mint.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                payer: ctx.accounts.signer.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &signer
+        )
+    )?;
+
+    create_shares_metadata(
+        ctx.accounts.metadata_program.to_account_info(),
+        ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.shares_mint,
+        ctx.accounts.metadata.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+        &vault_key,
+        ctx.bumps.shares_mint,
+        &config,
+    )?;
+
+    finish_init_vault_shares(
+        &ctx.accounts.vault,
+        &mut ctx.accounts.config,
+        &ctx.accounts.shares_mint,
+        &ctx.accounts.shares_token_account,
+        ctx.bumps.shares_mint,
+        *config,
+    )
+}
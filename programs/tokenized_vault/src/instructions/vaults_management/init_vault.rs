@@ -8,6 +8,7 @@ use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::constants::{CONFIG_SEED, VAULT_SEED, UNDERLYING_SEED};
 use crate::state::{Vault, Config, VaultConfig};
+use crate::utils::token;
 
 #[derive(Accounts)]
 pub struct InitVault<'info> {
@@ -60,6 +61,8 @@ pub struct InitVault<'info> {
 }
 
 pub fn handle_init_vault(ctx: Context<InitVault>, config: Box<VaultConfig>) -> Result<()> {
+    token::validate_mint_extensions(&ctx.accounts.underlying_mint.to_account_info())?;
+
     ctx.accounts.vault.load_init()?.init(
         ctx.accounts.config.next_vault_index,
         ctx.bumps.vault,
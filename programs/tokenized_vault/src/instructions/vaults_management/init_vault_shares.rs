@@ -86,17 +86,46 @@ pub struct InitVaultShares<'info> {
 }
 
 pub fn handle_init_vault_shares(ctx: Context<InitVaultShares>, _index: u64, config: Box<SharesConfig>) -> Result<()> {
-    let vault_key = ctx.accounts.vault.key();
-    let seeds = &[SHARES_SEED.as_bytes(), vault_key.as_ref(), &[ctx.bumps.shares_mint]];
-    let signer = [&seeds[..]];
+    create_shares_metadata(
+        ctx.accounts.metadata_program.to_account_info(),
+        ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.shares_mint,
+        ctx.accounts.metadata.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.rent.to_account_info(),
+        &ctx.accounts.vault.key(),
+        ctx.bumps.shares_mint,
+        &config,
+    )?;
 
-    let share_token_name = config.name.clone();
-    let share_token_symbol = config.symbol.clone();
+    finish_init_vault_shares(
+        &ctx.accounts.vault,
+        &mut ctx.accounts.config,
+        &ctx.accounts.shares_mint,
+        &ctx.accounts.shares_token_account,
+        ctx.bumps.shares_mint,
+        *config,
+    )
+}
+
+pub(crate) fn create_shares_metadata<'info>(
+    metadata_program: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    shares_mint: &InterfaceAccount<'info, Mint>,
+    metadata: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    rent: AccountInfo<'info>,
+    vault_key: &Pubkey,
+    shares_bump: u8,
+    config: &SharesConfig,
+) -> Result<()> {
+    let seeds = &[SHARES_SEED.as_bytes(), vault_key.as_ref(), &[shares_bump]];
+    let signer = [&seeds[..]];
 
     let token_data: DataV2 = DataV2 {
-        name: config.name,
-        symbol: config.symbol,
-        uri: config.uri,
+        name: config.name.clone(),
+        symbol: config.symbol.clone(),
+        uri: config.uri.clone(),
         seller_fee_basis_points: 0,
         creators: None,
         collection: None,
@@ -105,15 +134,15 @@ pub fn handle_init_vault_shares(ctx: Context<InitVaultShares>, _index: u64, conf
 
     create_metadata_accounts_v3(
         CpiContext::new_with_signer(
-            ctx.accounts.metadata_program.to_account_info(),
+            metadata_program,
             CreateMetadataAccountsV3 {
-                payer: ctx.accounts.signer.to_account_info(),
-                update_authority: ctx.accounts.shares_mint.to_account_info(),
-                mint: ctx.accounts.shares_mint.to_account_info(),
-                metadata: ctx.accounts.metadata.to_account_info(),
-                mint_authority: ctx.accounts.shares_mint.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                rent: ctx.accounts.rent.to_account_info(),
+                payer,
+                update_authority: shares_mint.to_account_info(),
+                mint: shares_mint.to_account_info(),
+                metadata,
+                mint_authority: shares_mint.to_account_info(),
+                system_program,
+                rent,
             },
             &signer
         ),
@@ -121,12 +150,22 @@ pub fn handle_init_vault_shares(ctx: Context<InitVaultShares>, _index: u64, conf
         false,
         true,
         None,
-    )?;
+    )
+}
 
-    let vault = &mut ctx.accounts.vault.load_mut()?;
-    vault.shares_bump = [ctx.bumps.shares_mint];
+pub(crate) fn finish_init_vault_shares<'info>(
+    vault_loader: &AccountLoader<'info, Vault>,
+    config: &mut Account<'info, Config>,
+    shares_mint: &InterfaceAccount<'info, Mint>,
+    shares_token_account: &InterfaceAccount<'info, TokenAccount>,
+    shares_bump: u8,
+    shares_config: SharesConfig,
+) -> Result<()> {
+    let vault_key = vault_loader.key();
+    let vault = &mut vault_loader.load_mut()?;
+    vault.shares_bump = [shares_bump];
 
-    ctx.accounts.config.next_vault_index += 1;
+    config.next_vault_index += 1;
 
     let underlying_token = TokenData{
         mint: vault.underlying_mint,
@@ -139,12 +178,12 @@ pub fn handle_init_vault_shares(ctx: Context<InitVaultShares>, _index: u64, conf
     };
 
     let share_token = TokenData{
-        mint: ctx.accounts.shares_mint.key(),
-        account: ctx.accounts.shares_token_account.key(),
-        decimals: ctx.accounts.shares_mint.decimals,
+        mint: shares_mint.key(),
+        account: shares_token_account.key(),
+        decimals: shares_mint.decimals,
         metadata: TokenMetaData {
-            name: share_token_name,
-            symbol: share_token_symbol,
+            name: shares_config.name,
+            symbol: shares_config.symbol,
         }
     };
 
@@ -163,5 +202,3 @@ pub fn handle_init_vault_shares(ctx: Context<InitVaultShares>, _index: u64, conf
 
     Ok(())
 }
-
-
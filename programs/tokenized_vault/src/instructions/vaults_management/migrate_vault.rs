@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::{DISCRIMINATOR_LEN, VAULT_VERSION};
+use crate::errors::ErrorCode;
+use crate::events::VaultMigratedEvent;
+use crate::state::{Vault, VaultV0};
+
+#[derive(Accounts)]
+pub struct MigrateVault<'info> {
+    /// CHECK: older layouts can't be loaded as `Vault`, discriminator and size are checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_vault(ctx: Context<MigrateVault>) -> Result<()> {
+    let vault_info = ctx.accounts.vault.to_account_info();
+
+    if vault_info.data_len() < DISCRIMINATOR_LEN
+        || vault_info.try_borrow_data()?[..DISCRIMINATOR_LEN] != Vault::DISCRIMINATOR
+    {
+        return Err(ErrorCode::InvalidVaultLayout.into());
+    }
+
+    let old_version = match vault_info.data_len() {
+        VaultV0::LEN => 0,
+        Vault::LEN => return Err(ErrorCode::VaultAlreadyMigrated.into()),
+        _ => return Err(ErrorCode::InvalidVaultLayout.into()),
+    };
+
+    let rent_due = Rent::get()?
+        .minimum_balance(Vault::LEN)
+        .saturating_sub(vault_info.lamports());
+
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: vault_info.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+
+    // older layouts are a prefix of the current one
+    vault_info.realloc(Vault::LEN, true)?;
+    VaultV0::migrate(&mut vault_info.try_borrow_mut_data()?)?;
+
+    emit!(VaultMigratedEvent {
+        vault_key: vault_info.key(),
+        old_version,
+        new_version: VAULT_VERSION,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
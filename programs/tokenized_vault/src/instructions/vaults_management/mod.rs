@@ -1,19 +1,33 @@
 pub mod add_strategy;
 pub mod close_vault;
+pub mod enable_tranches;
 pub mod initialize;
+pub mod init_kyc_vault_shares;
 pub mod init_vault;
 pub mod init_vault_shares;
+pub mod migrate_vault;
+pub mod pause_vault;
+pub mod price_feed;
+pub mod price_history;
 pub mod remove_strategy;
 pub mod shutdown_vault;
+pub mod sweep;
 pub mod update_debt;
 pub mod vault_setters;
 
 pub use add_strategy::*;
 pub use close_vault::*;
+pub use enable_tranches::*;
 pub use initialize::*;
+pub use init_kyc_vault_shares::*;
 pub use init_vault::*;
 pub use init_vault_shares::*;
+pub use migrate_vault::*;
+pub use pause_vault::*;
+pub use price_feed::*;
+pub use price_history::*;
 pub use remove_strategy::*;
 pub use shutdown_vault::*;
+pub use sweep::*;
 pub use update_debt::*;
 pub use vault_setters::*;
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::PAUSE_ALL;
+use crate::errors::ErrorCode;
+use crate::events::VaultUpdatePauseFlagsEvent;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct PauseVault<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::Guardian.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>
+}
+
+// the guardian can only add flags, clearing them is left to the vaults admin
+pub fn handle_pause_vault(ctx: Context<PauseVault>, flags: u8) -> Result<()> {
+    if flags & !PAUSE_ALL != 0 {
+        return Err(ErrorCode::InvalidPauseFlags.into());
+    }
+
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    let old_pause_flags = vault.pause_flags;
+    vault.pause_flags |= flags;
+
+    emit!(VaultUpdatePauseFlagsEvent {
+        vault_key: vault.key,
+        old_pause_flags,
+        new_pause_flags: vault.pause_flags,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::PRICE_FEED_SEED;
+use crate::state::{PriceFeed, Vault};
+
+#[derive(Accounts)]
+pub struct InitPriceFeed<'info> {
+    #[account(
+        init, 
+        seeds = [PRICE_FEED_SEED.as_bytes(), vault.key().as_ref()], 
+        bump,  
+        payer = signer, 
+        space = PriceFeed::LEN,
+    )]
+    pub price_feed: Account<'info, PriceFeed>,
+
+    #[account()]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_init_price_feed(ctx: Context<InitPriceFeed>) -> Result<()> {
+    ctx.accounts.price_feed.update(&*ctx.accounts.vault.load()?)
+}
@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::PRICE_HISTORY_SEED;
+use crate::events::VaultUpdatePriceHistoryIntervalEvent;
+use crate::state::{PriceHistory, Vault};
+
+#[derive(Accounts)]
+pub struct InitPriceHistory<'info> {
+    #[account(
+        init, 
+        seeds = [PRICE_HISTORY_SEED.as_bytes(), vault.key().as_ref()], 
+        bump,  
+        payer = signer, 
+        space = PriceHistory::LEN,
+    )]
+    pub price_history: Account<'info, PriceHistory>,
+
+    #[account()]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPriceHistoryInterval<'info> {
+    #[account(mut, seeds = [PRICE_HISTORY_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub price_history: Account<'info, PriceHistory>,
+
+    #[account()]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+}
+
+pub fn handle_init_price_history(ctx: Context<InitPriceHistory>, interval: u64) -> Result<()> {
+    ctx.accounts.price_history.init(ctx.accounts.vault.key(), interval);
+
+    let share_price = ctx.accounts.vault.load()?.get_share_price();
+    ctx.accounts.price_history.record(share_price, Clock::get()?.unix_timestamp);
+
+    emit!(VaultUpdatePriceHistoryIntervalEvent {
+        vault_key: ctx.accounts.vault.key(),
+        new_interval: interval,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn handle_set_price_history_interval(ctx: Context<SetPriceHistoryInterval>, interval: u64) -> Result<()> {
+    ctx.accounts.price_history.interval = interval;
+
+    emit!(VaultUpdatePriceHistoryIntervalEvent {
+        vault_key: ctx.accounts.vault.key(),
+        new_interval: interval,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
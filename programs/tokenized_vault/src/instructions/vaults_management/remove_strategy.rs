@@ -51,6 +51,7 @@ pub fn handle_remove_strategy(ctx: Context<RemoveStrategy>, strategy: Pubkey, fo
             return Err(ErrorCode::StrategyHasDebt.into());
         }
         loss = strategy_data.current_debt;
+        vault.apply_tranche_loss(loss, Clock::get()?.unix_timestamp as u64);
         vault.total_debt -= loss;
     }
 
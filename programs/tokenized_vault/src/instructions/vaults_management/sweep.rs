@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::{UserRole, Role}
+};
+
+use crate::constants::{JUNIOR_SHARES_SEED, SHARES_SEED};
+use crate::errors::ErrorCode;
+use crate::events::VaultSweepEvent;
+use crate::state::Vault;
+use crate::utils::token;
+
+#[derive(Accounts)]
+pub struct Sweep<'info> {
+    #[account()]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = vault,
+        token::token_program = token_program,
+    )]
+    pub token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account()]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(mut, token::mint = mint, token::token_program = token_program)]
+    pub recipient: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            signer.key().as_ref(),
+            Role::VaultsAdmin.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub roles: Account<'info, UserRole>,
+
+    #[account(mut, constraint = roles.check_role()?)]
+    pub signer: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+// only stray mints, the underlying and the vault's own shares are accounted for
+pub fn handle_sweep(ctx: Context<Sweep>) -> Result<()> {
+    let vault_key = ctx.accounts.vault.key();
+    let mint = ctx.accounts.mint.key();
+
+    let (shares_mint, _) = Pubkey::find_program_address(&[SHARES_SEED.as_bytes(), vault_key.as_ref()], &crate::ID);
+    let (junior_shares_mint, _) = Pubkey::find_program_address(&[JUNIOR_SHARES_SEED.as_bytes(), vault_key.as_ref()], &crate::ID);
+
+    if mint == ctx.accounts.vault.load()?.underlying_mint || mint == shares_mint || mint == junior_shares_mint {
+        return Err(ErrorCode::InvalidSweepMint.into());
+    }
+
+    let amount = ctx.accounts.token_account.amount;
+    if amount == 0 {
+        return Err(ErrorCode::ZeroValue.into());
+    }
+
+    token::transfer_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_account.to_account_info(),
+        ctx.accounts.recipient.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.mint,
+        amount,
+        &ctx.accounts.vault.load()?.seeds()
+    )?;
+
+    emit!(VaultSweepEvent {
+        vault_key,
+        mint,
+        recipient: ctx.accounts.recipient.key(),
+        amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -12,8 +12,8 @@ use strategy::program::Strategy;
 use crate::events::UpdatedCurrentDebtForStrategyEvent;
 use crate::state::{StrategyData, Vault};
 use crate::errors::ErrorCode;
-use crate::utils::strategy as strategy_utils;
-use crate::constants::{STRATEGY_DATA_SEED, UNDERLYING_SEED};
+use crate::utils::{strategy as strategy_utils, token, vault as vault_utils};
+use crate::constants::{PAUSE_DEBT_UPDATES, STRATEGY_DATA_SEED, UNDERLYING_SEED};
 
 #[derive(Accounts)]
 #[instruction(new_debt: u64)]
@@ -76,6 +76,8 @@ pub fn handle_update_debt<'a, 'b, 'c, 'info>(
     mut ctx: Context<'a, 'b, 'c, 'info, UpdateStrategyDebt<'info>>, 
     new_debt: u64,
 ) -> Result<()> {
+    vault_utils::validate_not_paused(&ctx.accounts.vault, PAUSE_DEBT_UPDATES)?;
+
     let (total_idle, total_debt, new_debt) = handle_internal(&mut ctx, new_debt)?;
 
     let vault_mut = &mut ctx.accounts.vault.load_mut()?;
@@ -164,11 +166,18 @@ fn handle_internal<'a, 'b, 'c, 'info>(
             ctx.remaining_accounts.to_vec()
         )?;
 
-        new_debt = current_debt + assets_to_deposit;
+        // the strategy only books what arrived, transfer fees are lost to the vault
+        let deposited = assets_to_deposit - token::transfer_fee(
+            &ctx.accounts.underlying_mint.to_account_info(),
+            Clock::get()?.epoch,
+            assets_to_deposit
+        )?;
+
+        new_debt = current_debt + deposited;
 
         return Ok((
             vault.total_idle - assets_to_deposit, 
-            vault.total_debt + assets_to_deposit, 
+            vault.total_debt + deposited, 
             new_debt
         ));
     }
@@ -14,9 +14,17 @@ use crate::events::{
     VaultUpdateUserDepositLimitEvent,
     VaultUpdateAccountantEvent,
     VaultUpdateWhitelistedOnlyEvent,
+    VaultUpdateFeesInUnderlyingEvent,
+    VaultUpdateMinDepositLockSecondsEvent,
+    VaultUpdateEarlyExitFeeEvent,
+    VaultUpdateWhitelistRootEvent,
+    VaultUpdatePauseFlagsEvent,
+    VaultConfigUpdatedEvent,
+    VaultUpdateAuditPauseThresholdEvent,
 };
+use crate::constants::{MAX_BPS, PAUSE_ALL};
 use crate::errors::ErrorCode;
-use crate::state::Vault;
+use crate::state::{Vault, VaultConfigPatch};
 
 #[derive(Accounts)]
 pub struct SetVaultProperty<'info> {
@@ -178,4 +186,146 @@ pub fn handle_set_whitelisted_only(ctx: Context<SetVaultProperty>, value: bool)
     });
 
     Ok(())
-}
\ No newline at end of file
+}
+
+pub fn handle_set_fees_in_underlying(ctx: Context<SetVaultProperty>, value: bool) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    if vault.is_shutdown {
+        return Err(ErrorCode::VaultShutdown.into());
+    }
+
+    vault.fees_in_underlying = value;
+
+    emit!(VaultUpdateFeesInUnderlyingEvent {
+        vault_key: vault.key,
+        new_fees_in_underlying: value,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn handle_set_min_deposit_lock_seconds(ctx: Context<SetVaultProperty>, value: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    if vault.is_shutdown {
+        return Err(ErrorCode::VaultShutdown.into());
+    }
+
+    vault.min_deposit_lock_seconds = value;
+
+    emit!(VaultUpdateMinDepositLockSecondsEvent {
+        vault_key: vault.key,
+        new_min_deposit_lock_seconds: value,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn handle_set_early_exit_fee(ctx: Context<SetVaultProperty>, value: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    if vault.is_shutdown {
+        return Err(ErrorCode::VaultShutdown.into());
+    }
+
+    if value > MAX_BPS {
+        return Err(ErrorCode::InvalidFee.into());
+    }
+
+    vault.early_exit_fee = value;
+
+    emit!(VaultUpdateEarlyExitFeeEvent {
+        vault_key: vault.key,
+        new_early_exit_fee: value,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn handle_set_audit_pause_threshold(ctx: Context<SetVaultProperty>, value: u64) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    if vault.is_shutdown {
+        return Err(ErrorCode::VaultShutdown.into());
+    }
+
+    if value > MAX_BPS {
+        return Err(ErrorCode::InvalidVaultConfig.into());
+    }
+
+    vault.audit_pause_threshold = value;
+
+    emit!(VaultUpdateAuditPauseThresholdEvent {
+        vault_key: vault.key,
+        new_audit_pause_threshold: value,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn handle_set_whitelist_root(ctx: Context<SetVaultProperty>, root: [u8; 32]) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    if vault.is_shutdown {
+        return Err(ErrorCode::VaultShutdown.into());
+    }
+
+    vault.whitelist_root = root;
+
+    emit!(VaultUpdateWhitelistRootEvent {
+        vault_key: vault.key,
+        new_whitelist_root: root,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+// unlike shutdown this is reversible, flags not set are unpaused
+pub fn handle_set_pause_flags(ctx: Context<SetVaultProperty>, flags: u8) -> Result<()> {
+    if flags & !PAUSE_ALL != 0 {
+        return Err(ErrorCode::InvalidPauseFlags.into());
+    }
+
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    let old_pause_flags = vault.pause_flags;
+    vault.pause_flags = flags;
+
+    emit!(VaultUpdatePauseFlagsEvent {
+        vault_key: vault.key,
+        old_pause_flags,
+        new_pause_flags: flags,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn handle_update_vault_config(ctx: Context<SetVaultProperty>, patch: VaultConfigPatch) -> Result<()> {
+    let vault = &mut ctx.accounts.vault.load_mut()?;
+
+    if vault.is_shutdown {
+        return Err(ErrorCode::VaultShutdown.into());
+    }
+
+    let old_config = vault.settings();
+    let new_config = old_config.patch(&patch);
+    new_config.validate()?;
+
+    vault.apply_settings(&new_config);
+
+    emit!(VaultConfigUpdatedEvent {
+        vault_key: vault.key,
+        old_config,
+        new_config,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -6,12 +6,14 @@ use access_control::{
 };
 
 use crate::constants::USER_DATA_SEED;
-use crate::state::{Vault, UserData};
+use crate::state::Vault;
+use crate::utils::user_data::*;
 use crate::events::WhitelistUpdatedEvent;
 
 #[derive(Accounts)]
 #[instruction(user: Pubkey)]
 pub struct RevokeWhitelisting<'info> {
+    /// CHECK: older layouts are grown on write
     #[account(
         mut,
         seeds = [
@@ -21,7 +23,7 @@ pub struct RevokeWhitelisting<'info> {
         ], 
         bump,  
     )]
-    pub user_data: Account<'info, UserData>,
+    pub user_data: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub vault: AccountLoader<'info, Vault>,
@@ -46,11 +48,15 @@ pub struct RevokeWhitelisting<'info> {
 }
 
 pub fn handle_revoke_whitelisting(ctx: Context<RevokeWhitelisting>, _user: Pubkey) -> Result<()> {
-    ctx.accounts.user_data.whitelisted = false;
+    let mut user_data = load_user_data(&ctx.accounts.user_data)?;
+    user_data.whitelisted = false;
+    user_data.whitelist_expires_at = 0;
+    save_user_data(&ctx.accounts.user_data, &mut user_data, &ctx.accounts.signer, &ctx.accounts.system_program)?;
 
     emit!(WhitelistUpdatedEvent {
         user: _user,
         whitelisted: false,
+        expires_at: 0,
     });
 
     Ok(())
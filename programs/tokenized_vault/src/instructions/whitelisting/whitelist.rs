@@ -6,24 +6,25 @@ use access_control::{
 };
 
 use crate::constants::USER_DATA_SEED;
-use crate::state::{UserData, Vault};
+use crate::state::Vault;
+use crate::utils::user_data::*;
+use crate::errors::ErrorCode;
 use crate::events::WhitelistUpdatedEvent;
 
 #[derive(Accounts)]
 #[instruction(user: Pubkey)]
 pub struct Whitelist<'info> {
+    /// CHECK: created here when missing, older layouts are grown on write
     #[account(
-        init_if_needed, 
+        mut,
         seeds = [
             USER_DATA_SEED.as_bytes(), 
             vault.key().as_ref(), 
             user.as_ref()
         ], 
         bump,  
-        payer = signer, 
-        space = UserData::LEN,
     )]
-    pub user_data: Account<'info, UserData>,
+    pub user_data: UncheckedAccount<'info>,
 
     #[account(mut)]
     pub vault: AccountLoader<'info, Vault>,
@@ -48,12 +49,36 @@ pub struct Whitelist<'info> {
 }
 
 
-pub fn handle_whitelist(ctx: Context<Whitelist>, _user: Pubkey) -> Result<()> {
-    ctx.accounts.user_data.whitelisted = true;
+pub fn handle_whitelist(ctx: Context<Whitelist>, user: Pubkey) -> Result<()> {
+    set_whitelisted(ctx, user, 0)
+}
+
+// whitelisting again before expiry renews the entry
+pub fn handle_whitelist_with_expiry(ctx: Context<Whitelist>, user: Pubkey, expires_at: i64) -> Result<()> {
+    if expires_at <= Clock::get()?.unix_timestamp {
+        return Err(ErrorCode::InvalidExpiry.into());
+    }
+    set_whitelisted(ctx, user, expires_at)
+}
+
+fn set_whitelisted(ctx: Context<Whitelist>, user: Pubkey, expires_at: i64) -> Result<()> {
+    let mut user_data = load_or_create_user_data(
+        &ctx.accounts.user_data,
+        &ctx.accounts.vault.key(),
+        &user,
+        &ctx.accounts.signer,
+        &ctx.accounts.system_program
+    )?;
+
+    user_data.whitelisted = true;
+    user_data.whitelist_root = [0; 32];
+    user_data.whitelist_expires_at = expires_at;
+    save_user_data(&ctx.accounts.user_data, &mut user_data, &ctx.accounts.signer, &ctx.accounts.system_program)?;
 
     emit!(WhitelistUpdatedEvent {
-        user: _user,
+        user,
         whitelisted: true,
+        expires_at,
     });
     
     Ok(())
@@ -1,12 +1,16 @@
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::Role
+};
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::Token,
-    token_interface::{Mint, TokenAccount}
+    token_interface::{Mint, TokenAccount, TokenInterface}
 };
 
 use crate::events::WithdrawalRequestCanceledEvent;
 use crate::state::{Vault, WithdrawRequest};
-use crate::utils::token;
+use crate::utils::{token, vault};
 use crate::constants::{SHARES_SEED,WITHDRAW_SHARES_ACCOUNT_SEED};
 
 #[derive(Accounts)]
@@ -33,25 +37,40 @@ pub struct CancelWithdrawalRequest<'info> {
     )]
     pub withdraw_pool_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
         
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            user.key().as_ref(),
+            Role::Sanctioned.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub sanctioned: UncheckedAccount<'info>,
+
     #[account(mut, address = withdraw_request.user)]
     pub user: Signer<'info>,
 
-    pub shares_token_program: Program<'info, Token>,
+    pub shares_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+    pub access_control: Program<'info, AccessControl>,
 }
 
 pub fn handle_cancel_withdrawal_request(
     ctx: Context<CancelWithdrawalRequest>, 
 ) -> Result<()> {
+    vault::validate_not_sanctioned(&ctx.accounts.sanctioned)?;
 
-    token::transfer_with_signer(
+    token::transfer_shares_with_signer(
         ctx.accounts.shares_token_program.to_account_info(),
         ctx.accounts.withdraw_pool_token_account.to_account_info(),
         ctx.accounts.user_shares_account.to_account_info(),
         ctx.accounts.vault.to_account_info(),
         &ctx.accounts.shares_mint,
         ctx.accounts.withdraw_request.locked_shares,
-        &ctx.accounts.vault.load()?.seeds()
+        &ctx.accounts.vault.load()?.seeds(),
+        &ctx.accounts.vault.load()?.seeds_shares(),
     )?;
 
     emit!(WithdrawalRequestCanceledEvent {
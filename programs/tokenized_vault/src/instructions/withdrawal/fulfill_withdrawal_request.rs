@@ -1,14 +1,21 @@
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::Role
+};
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::Token,
+    associated_token,
     token_interface::{Mint, TokenAccount, TokenInterface}
 };
 
 use crate::errors::ErrorCode;
 use crate::events::{VaultWithdrawlEvent, WithdrawalRequestFulfilledEvent};
-use crate::state::{UserData, Vault, WithdrawRequest};
-use crate::utils::{token, unchecked::*};
+use crate::state::{Vault, WithdrawRequest};
+use crate::utils::{token, user_data::*, vault};
 use crate::constants::{
+    PAUSE_FULFILLMENTS,
+    PRICE_HISTORY_SEED,
     SHARES_SEED,
     UNDERLYING_SEED,
     WITHDRAW_SHARES_ACCOUNT_SEED,
@@ -58,9 +65,23 @@ pub struct FulfillWithdrawalRequest<'info> {
         mut,
         associated_token::mint = shares_mint, 
         associated_token::authority = accountant,
+        associated_token::token_program = shares_token_program,
     )]
     pub accountant_recipient: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// CHECK: this account may not exist
+    #[account(
+        mut,
+        seeds = [
+            accountant.key().as_ref(),
+            token_program.key().as_ref(),
+            underlying_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = associated_token::ID
+    )]
+    pub accountant_token_account: UncheckedAccount<'info>,
+
     /// CHECK: can be missing
     #[account(
         mut,
@@ -73,34 +94,47 @@ pub struct FulfillWithdrawalRequest<'info> {
         )]
     pub user_data: UncheckedAccount<'info>,
 
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            user.key().as_ref(),
+            Role::Sanctioned.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub sanctioned: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(mut, seeds = [PRICE_HISTORY_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub price_history: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub signer: Signer<'info>,
 
-    pub shares_token_program: Program<'info, Token>,
+    pub shares_token_program: Interface<'info, TokenInterface>,
     pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+    pub access_control: Program<'info, AccessControl>,
 }
 
 pub fn handle_fulfill_withdrawal_request(ctx: Context<FulfillWithdrawalRequest>
 ) -> Result<()> {
+    vault::validate_not_paused(&ctx.accounts.vault, PAUSE_FULFILLMENTS)?;
+    vault::validate_not_sanctioned(&ctx.accounts.sanctioned)?;
 
     let fee_shares = ctx.accounts.withdraw_request.fee_shares;
     let shares_to_burn = ctx.accounts.withdraw_request.locked_shares - fee_shares;
     let assets_to_transfer = ctx.accounts.vault.load()?.convert_to_underlying(shares_to_burn);
 
-    // 0. check if amount >= requestded amount - max_loss
-    let min_amount = (ctx.accounts.withdraw_request.requested_amount as u128 * ctx.accounts.withdraw_request.max_loss as u128) / MAX_BPS as u128;
-    if assets_to_transfer < min_amount as u64 {
-        return Err(ErrorCode::TooMuchLoss.into());
-    }
-
     if assets_to_transfer > ctx.accounts.vault.load()?.total_idle {
         return Err(ErrorCode::InsufficientFunds.into());
     }
 
-    // 1. burn shares
+    // 0. burn shares
     token::burn_with_signer(
-        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.shares_token_program.to_account_info(),
         ctx.accounts.shares_mint.to_account_info(),
         ctx.accounts.withdraw_pool_shares_account.to_account_info(),
         ctx.accounts.vault.to_account_info(),
@@ -108,22 +142,60 @@ pub fn handle_fulfill_withdrawal_request(ctx: Context<FulfillWithdrawalRequest>
         &ctx.accounts.vault.load()?.seeds(),
     )?;
 
-    // 2. trasfer fee shares to accountant
+    // 1. trasfer fee shares to accountant, or their underlying if the vault pays fees in underlying
     if fee_shares > 0 {
-        token::transfer_with_signer(
-            ctx.accounts.shares_token_program.to_account_info(),
-            ctx.accounts.withdraw_pool_shares_account.to_account_info(),
-            ctx.accounts.accountant_recipient.to_account_info(),
-            ctx.accounts.vault.to_account_info(),
-            &ctx.accounts.shares_mint,
-            fee_shares,
-            &ctx.accounts.vault.load()?.seeds()
-        )?;
+        // fee shares are settled in underlying as far as idle above the minimum allows,
+        // the user's assets leave the vault after this so they're kept out of it
+        let underlying_fee_shares = if vault::fees_in_underlying(&ctx.accounts.vault, &ctx.accounts.accountant_token_account)? {
+            let vault = ctx.accounts.vault.load()?;
+            vault.fee_shares_payable_in_underlying(fee_shares, vault.idle_for_fees(assets_to_transfer))
+        } else {
+            0
+        };
+
+        if underlying_fee_shares > 0 {
+            let fee_assets = ctx.accounts.vault.load()?.convert_to_underlying(underlying_fee_shares);
+
+            token::burn_with_signer(
+                ctx.accounts.shares_token_program.to_account_info(),
+                ctx.accounts.shares_mint.to_account_info(),
+                ctx.accounts.withdraw_pool_shares_account.to_account_info(),
+                ctx.accounts.vault.to_account_info(),
+                underlying_fee_shares,
+                &ctx.accounts.vault.load()?.seeds(),
+            )?;
+
+            token::transfer_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.vault_token_account.to_account_info(),
+                ctx.accounts.accountant_token_account.to_account_info(),
+                ctx.accounts.vault.to_account_info(),
+                &ctx.accounts.underlying_mint,
+                fee_assets,
+                &ctx.accounts.vault.load()?.seeds()
+            )?;
+
+            ctx.accounts.vault.load_mut()?.handle_withdraw(fee_assets, underlying_fee_shares);
+        }
+
+        if fee_shares > underlying_fee_shares {
+            token::transfer_shares_with_signer(
+                ctx.accounts.shares_token_program.to_account_info(),
+                ctx.accounts.withdraw_pool_shares_account.to_account_info(),
+                ctx.accounts.accountant_recipient.to_account_info(),
+                ctx.accounts.vault.to_account_info(),
+                &ctx.accounts.shares_mint,
+                fee_shares - underlying_fee_shares,
+                &ctx.accounts.vault.load()?.seeds(),
+                &ctx.accounts.vault.load()?.seeds_shares(),
+            )?;
+        }
     }
 
-    // 3. transfer underlying from vault to user
+    // 2. transfer underlying from vault to user
+    let balance_before = ctx.accounts.user_token_account.amount;
     token::transfer_with_signer(
-        ctx.accounts.shares_token_program.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
         ctx.accounts.vault_token_account.to_account_info(),
         ctx.accounts.user_token_account.to_account_info(),
         ctx.accounts.vault.to_account_info(),
@@ -131,13 +203,21 @@ pub fn handle_fulfill_withdrawal_request(ctx: Context<FulfillWithdrawalRequest>
         assets_to_transfer,
         &ctx.accounts.vault.load()?.seeds()
     )?;
+    ctx.accounts.user_token_account.reload()?;
+
+    // 3. check if received amount >= requestded amount - max_loss
+    let received = ctx.accounts.user_token_account.amount - balance_before;
+    let min_amount = (ctx.accounts.withdraw_request.requested_amount as u128 * ctx.accounts.withdraw_request.max_loss as u128) / MAX_BPS as u128;
+    if received < min_amount as u64 {
+        return Err(ErrorCode::TooMuchLoss.into());
+    }
 
     ctx.accounts.vault.load_mut()?.handle_withdraw(assets_to_transfer, shares_to_burn);
 
     if !ctx.accounts.user_data.data_is_empty() {
-        let mut user_data: UserData = ctx.accounts.user_data.deserialize()?;
-        user_data.handle_withdraw(assets_to_transfer)?;
-        ctx.accounts.user_data.serialize(&user_data)?;
+        let mut user_data = load_user_data(&ctx.accounts.user_data)?;
+        user_data.handle_withdraw(received, shares_to_burn + fee_shares)?;
+        save_user_data(&ctx.accounts.user_data, &mut user_data, &ctx.accounts.signer, &ctx.accounts.system_program)?;
     }
 
     let vault = ctx.accounts.vault.load()?;
@@ -166,5 +246,6 @@ pub fn handle_fulfill_withdrawal_request(ctx: Context<FulfillWithdrawalRequest>
         timestamp: Clock::get()?.unix_timestamp,
     });
 
-    Ok(())
+    drop(vault);
+    vault::record_share_price(&ctx.accounts.vault, &ctx.accounts.price_history)
 }
\ No newline at end of file
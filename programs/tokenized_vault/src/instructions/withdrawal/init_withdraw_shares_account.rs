@@ -5,8 +5,7 @@ use access_control::{
     state::{UserRole, Role}
 };
 use anchor_spl::{
-    token_interface::{Mint, TokenAccount},
-    token::Token,
+    token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
 use crate::constants::{SHARES_SEED, WITHDRAW_SHARES_ACCOUNT_SEED};
@@ -27,6 +26,7 @@ pub struct InitWithdrawSharesAccount<'info> {
         payer = signer, 
         token::mint = shares_mint,
         token::authority = vault,
+        token::token_program = token_program,
     )]
     pub shares_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
@@ -45,7 +45,7 @@ pub struct InitWithdrawSharesAccount<'info> {
     pub signer: Signer<'info>,
     
     pub access_control: Program<'info, AccessControl>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -1,11 +1,15 @@
 pub mod fulfill_withdrawal_request;
 pub mod init_withdraw_shares_account;
 pub mod cancel_withdrawal_request;
+pub mod redeem_junior;
 pub mod request_withdraw;
 pub mod withdraw;
+pub mod zap_redeem;
 
 pub use fulfill_withdrawal_request::*;
 pub use init_withdraw_shares_account::*;
 pub use cancel_withdrawal_request::*;
+pub use redeem_junior::*;
 pub use request_withdraw::*;
 pub use withdraw::*;
+pub use zap_redeem::*;
@@ -0,0 +1,188 @@
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::Role
+};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token::Token,
+    token_interface::{Mint, TokenAccount, TokenInterface}
+};
+
+use strategy::program::Strategy;
+
+use crate::events::VaultWithdrawlEvent;
+use crate::state::Vault;
+use crate::utils::{token, user_data::*, vault as vault_utils};
+use crate::errors::ErrorCode;
+use crate::constants::{
+    JUNIOR_SHARES_SEED,
+    PAUSE_WITHDRAWALS,
+    UNDERLYING_SEED, 
+    USER_DATA_SEED,
+    MAX_BPS,
+};
+
+use super::withdraw::{parse_remaining, validate_max_withdraw, withdraw_assets, AccountsMap};
+
+#[derive(Accounts)]
+pub struct RedeemJunior<'info> {
+    #[account(mut)]
+    pub vault: AccountLoader<'info, Vault>,
+
+    #[account(mut)]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, seeds = [UNDERLYING_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut, seeds = [JUNIOR_SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub junior_shares_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, address = vault.load()?.underlying_mint)]
+    pub underlying_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut, token::mint = junior_shares_mint)]
+    pub user_shares_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: can be missing
+    #[account(
+        mut,
+        seeds = [
+            USER_DATA_SEED.as_bytes(), 
+            vault.key().as_ref(), 
+            user.key().as_ref()
+        ], 
+        bump
+        )]
+    pub user_data: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            user.key().as_ref(),
+            Role::Sanctioned.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub sanctioned: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub access_control: Program<'info, AccessControl>,
+    pub shares_token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub strategy_program: Program<'info, Strategy>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_redeem_junior<'info>(
+    ctx: Context<'_, '_, '_, 'info, RedeemJunior<'info>>, 
+    shares: u64, 
+    max_loss: u64,
+    remaining_accounts_map: AccountsMap
+) -> Result<()> {
+    vault_utils::validate_not_paused(&ctx.accounts.vault, PAUSE_WITHDRAWALS)?;
+    vault_utils::validate_not_sanctioned(&ctx.accounts.sanctioned)?;
+
+    let vault = ctx.accounts.vault.load()?;
+    if !vault.is_tranched {
+        return Err(ErrorCode::VaultNotTranched.into());
+    }
+    if !vault.direct_withdraw_enabled {
+        return Err(ErrorCode::DirectWithdrawDisabled.into());
+    }
+
+    let timestamp = Clock::get()?.unix_timestamp as u64;
+    let assets = vault.convert_junior_to_underlying(shares, timestamp);
+    let user_assets = vault.convert_junior_to_underlying(ctx.accounts.user_shares_account.amount, timestamp);
+    drop(vault);
+
+    if assets == 0 || shares == 0 {
+        return Err(ErrorCode::ZeroValue.into());
+    }
+
+    if ctx.accounts.user_shares_account.amount < shares {
+        return Err(ErrorCode::InsufficientShares.into());
+    }
+
+    let early_exit_fee = vault_utils::early_exit_fee(&ctx.accounts.vault, &ctx.accounts.user_data)?;
+
+    let strategies_with_accounts = parse_remaining(ctx.remaining_accounts, remaining_accounts_map)?;
+
+    validate_max_withdraw(
+        &ctx.accounts.vault,
+        user_assets, 
+        &strategies_with_accounts, 
+        max_loss,
+        assets
+    )?;
+
+    // losses realised here stay with junior, the senior claim is untouched
+    let mut assets_to_transfer = withdraw_assets(
+        &mut ctx.accounts.vault_token_account,
+        &ctx.accounts.underlying_mint.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        &ctx.accounts.strategy_program.to_account_info(),
+        &ctx.accounts.vault,
+        assets,
+        &strategies_with_accounts,
+    )?;
+
+    if assets > assets_to_transfer && max_loss < MAX_BPS {
+        if assets - assets_to_transfer > (assets * max_loss) / MAX_BPS {
+            return Err(ErrorCode::TooMuchLoss.into());
+        }
+    }
+
+    // the early-exit fee stays in the vault for the remaining junior holders
+    assets_to_transfer -= (assets_to_transfer as u128 * early_exit_fee as u128 / MAX_BPS as u128) as u64;
+
+    ctx.accounts.vault.load_mut()?.handle_junior_withdraw(assets_to_transfer, shares);
+
+    token::burn(
+        ctx.accounts.shares_token_program.to_account_info(),
+        ctx.accounts.junior_shares_mint.to_account_info(),
+        ctx.accounts.user_shares_account.to_account_info(),
+        ctx.accounts.user.to_account_info(),
+        shares
+    )?;
+
+    token::transfer_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.vault_token_account.to_account_info(),
+        ctx.accounts.user_token_account.to_account_info(),
+        ctx.accounts.vault.to_account_info(),
+        &ctx.accounts.underlying_mint,
+        assets_to_transfer,
+        &ctx.accounts.vault.load()?.seeds()
+    )?;
+
+    if !ctx.accounts.user_data.data_is_empty() {
+        let mut user_data = load_user_data(&ctx.accounts.user_data)?;
+        user_data.reduce_deposited(assets_to_transfer);
+        save_user_data(&ctx.accounts.user_data, &mut user_data, &ctx.accounts.user, &ctx.accounts.system_program)?;
+    }
+
+    let vault = ctx.accounts.vault.load()?;
+
+    emit!(VaultWithdrawlEvent {
+        vault_key: vault.key,
+        total_idle: vault.total_idle,
+        total_share: vault.junior_total_shares,
+        assets_to_transfer,
+        shares_to_burn: shares,
+        token_account: ctx.accounts.user_token_account.to_account_info().key(),
+        share_account: ctx.accounts.user_shares_account.to_account_info().key(),
+        token_mint: ctx.accounts.vault_token_account.mint,
+        share_mint: ctx.accounts.junior_shares_mint.to_account_info().key(),
+        authority: ctx.accounts.user.to_account_info().key(),
+        share_price: vault.get_junior_share_price(timestamp),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,18 +1,26 @@
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::Role
+};
 use anchor_lang::prelude::*;
+use ::accountant::constants::FEE_OVERRIDE_SEED;
 use anchor_spl::{
-    token::Token,
-    token_interface::{Mint, TokenAccount}
+    token_interface::{Mint, TokenAccount, TokenInterface}
 };
 
 use crate::events::WithdrawalRequestedEvent;
 use crate::state::{Config, Vault, WithdrawRequest};
-use crate::utils::{accountant, token};
+use crate::utils::{accountant, token, vault as vault_utils};
 use crate::errors::ErrorCode;
 use crate::constants::{
     CONFIG_SEED,
+    PAUSE_WITHDRAW_REQUESTS,
     SHARES_SEED,
     WITHDRAW_SHARES_ACCOUNT_SEED,
-    WITHDRAW_REQUEST_SEED
+    USER_DATA_SEED,
+    WITHDRAW_REQUEST_SEED,
+    MAX_BPS,
 };
 
 #[derive(Accounts)]
@@ -52,12 +60,48 @@ pub struct RequestWithdraw<'info> {
     /// CHECK:
     #[account(mut, address = vault.load()?.accountant)]
     pub accountant: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            FEE_OVERRIDE_SEED.as_bytes(),
+            accountant.key().as_ref(),
+            vault.key().as_ref()
+        ],
+        bump,
+        seeds::program = ::accountant::ID
+    )]
+    pub vault_fee_override: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            USER_DATA_SEED.as_bytes(), 
+            vault.key().as_ref(), 
+            user.key().as_ref()
+        ], 
+        bump
+    )]
+    pub user_data: UncheckedAccount<'info>,
     
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            user.key().as_ref(),
+            Role::Sanctioned.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub sanctioned: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
-    pub shares_token_program: Program<'info, Token>,
+    pub shares_token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
+    pub access_control: Program<'info, AccessControl>,
 }
 
 pub fn handle_request_withdraw<'info>(
@@ -65,7 +109,12 @@ pub fn handle_request_withdraw<'info>(
     amount: u64, 
     max_loss: u64,
 ) -> Result<()> {
-    let redemtion_fee = accountant::redeem(&ctx.accounts.accountant, amount)?;
+    let redemtion_fee = accountant::redeem(
+        &ctx.accounts.accountant,
+        &ctx.accounts.vault.key(),
+        &[ctx.accounts.vault_fee_override.to_account_info()],
+        amount
+    )?;
     let assets_to_withdraw = amount - redemtion_fee;
 
     let fee_shares = ctx.accounts.vault.load()?.convert_to_shares(redemtion_fee);
@@ -78,15 +127,20 @@ pub fn handle_request_redeem<'info>(
     shares: u64, 
     max_loss: u64,
 ) -> Result<()> {
-    let redemtion_fee_shares = accountant::redeem(&ctx.accounts.accountant, shares)?;
+    let redemtion_fee_shares = accountant::redeem(
+        &ctx.accounts.accountant,
+        &ctx.accounts.vault.key(),
+        &[ctx.accounts.vault_fee_override.to_account_info()],
+        shares
+    )?;
     let amount = ctx.accounts.vault.load()?.convert_to_underlying(shares-redemtion_fee_shares);
     handle_internal(ctx, amount, shares-redemtion_fee_shares, redemtion_fee_shares, max_loss)
 }
 
 fn handle_internal<'info>(
     ctx: Context<'_, '_, '_, 'info, RequestWithdraw<'info>>,
-    assets: u64,
-    shares_to_burn: u64,
+    mut assets: u64,
+    mut shares_to_burn: u64,
     fee_shares: u64,
     max_loss: u64,
 ) -> Result<()> {
@@ -104,6 +158,31 @@ fn handle_internal<'info>(
         return Err(ErrorCode::ZeroValue.into());
     }
 
+    vault_utils::validate_not_sanctioned(&ctx.accounts.sanctioned)?;
+
+    if vault.is_paused(PAUSE_WITHDRAW_REQUESTS) {
+        return Err(ErrorCode::Paused.into());
+    }
+    drop(vault);
+
+    // an early exit forfeits part of the shares to the remaining holders
+    let early_exit_fee = vault_utils::early_exit_fee(&ctx.accounts.vault, &ctx.accounts.user_data)?;
+    if early_exit_fee > 0 {
+        let early_exit_shares = (shares_to_burn as u128 * early_exit_fee as u128 / MAX_BPS as u128) as u64;
+        assets -= (assets as u128 * early_exit_fee as u128 / MAX_BPS as u128) as u64;
+        shares_to_burn -= early_exit_shares;
+
+        token::burn(
+            ctx.accounts.shares_token_program.to_account_info(),
+            ctx.accounts.shares_mint.to_account_info(),
+            ctx.accounts.user_shares_account.to_account_info(),
+            ctx.accounts.user.to_account_info(),
+            early_exit_shares
+        )?;
+
+        ctx.accounts.vault.load_mut()?.handle_forfeited_shares(early_exit_shares);
+    }
+
     ctx.accounts.withdraw_request.init(
         assets, 
         ctx.accounts.vault.key(),
@@ -118,13 +197,14 @@ fn handle_internal<'info>(
 
     ctx.accounts.config.next_withdraw_request_index += 1;
 
-    token::transfer(
+    token::transfer_shares(
         ctx.accounts.shares_token_program.to_account_info(),
         ctx.accounts.user_shares_account.to_account_info(),
         ctx.accounts.withdraw_pool_token_account.to_account_info(),
         ctx.accounts.user.to_account_info(),
         &ctx.accounts.shares_mint,
         shares_to_burn,
+        &ctx.accounts.vault.load()?.seeds_shares(),
     )?;
 
     emit!(WithdrawalRequestedEvent {
@@ -1,16 +1,24 @@
+use access_control::{
+    constants::USER_ROLE_SEED,
+    program::AccessControl,
+    state::Role
+};
 use anchor_lang::prelude::*;
+use ::accountant::constants::FEE_OVERRIDE_SEED;
 use anchor_spl::{
-    token::Token,
+    associated_token,
     token_interface::{Mint, TokenAccount, TokenInterface}
 };
 
 use strategy::program::Strategy;
 
 use crate::events::VaultWithdrawlEvent;
-use crate::state::{StrategyData, UserData, Vault};
-use crate::utils::{accountant, strategy as strategy_utils, token, unchecked::*};
+use crate::state::{StrategyData, Vault};
+use crate::utils::{accountant, strategy as strategy_utils, token, unchecked::*, user_data::*, vault};
 use crate::errors::ErrorCode;
 use crate::constants::{
+    PAUSE_WITHDRAWALS,
+    PRICE_HISTORY_SEED,
     UNDERLYING_SEED, 
     USER_DATA_SEED,
     SHARES_SEED,
@@ -36,16 +44,42 @@ pub struct Withdraw<'info> {
         mut,
         associated_token::mint = shares_mint, 
         associated_token::authority = accountant,
+        associated_token::token_program = shares_token_program,
     )]
     pub accountant_recipient: Box<InterfaceAccount<'info, TokenAccount>>,
 
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            FEE_OVERRIDE_SEED.as_bytes(),
+            accountant.key().as_ref(),
+            vault.key().as_ref()
+        ],
+        bump,
+        seeds::program = ::accountant::ID
+    )]
+    pub vault_fee_override: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(
+        mut,
+        seeds = [
+            accountant.key().as_ref(),
+            token_program.key().as_ref(),
+            underlying_mint.key().as_ref()
+        ],
+        bump,
+        seeds::program = associated_token::ID
+    )]
+    pub accountant_token_account: UncheckedAccount<'info>,
+
     #[account(mut, seeds = [SHARES_SEED.as_bytes(), vault.key().as_ref()], bump)]
     pub shares_mint: InterfaceAccount<'info, Mint>,
 
     #[account(mut, address = vault.load()?.underlying_mint)]
     pub underlying_mint: InterfaceAccount<'info, Mint>,
 
-    #[account(mut)]
+    #[account(mut, token::authority = owner)]
     pub user_shares_account: InterfaceAccount<'info, TokenAccount>,
 
     /// CHECK: can be missing
@@ -54,18 +88,39 @@ pub struct Withdraw<'info> {
         seeds = [
             USER_DATA_SEED.as_bytes(), 
             vault.key().as_ref(), 
-            user.key().as_ref()
+            owner.key().as_ref()
         ], 
         bump
         )]
     pub user_data: UncheckedAccount<'info>,
 
+    /// CHECK: this account may not exist
+    #[account(
+        seeds = [
+            USER_ROLE_SEED.as_bytes(), 
+            owner.key().as_ref(),
+            Role::Sanctioned.to_seed().as_ref()
+        ], 
+        bump,
+        seeds::program = access_control.key()
+    )]
+    pub sanctioned: UncheckedAccount<'info>,
+
+    /// CHECK: this account may not exist
+    #[account(mut, seeds = [PRICE_HISTORY_SEED.as_bytes(), vault.key().as_ref()], bump)]
+    pub price_history: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub user: Signer<'info>,
 
-    pub shares_token_program: Program<'info, Token>,
+    /// CHECK: owner of the shares, the user must be the owner or its approved delegate
+    pub owner: UncheckedAccount<'info>,
+
+    pub shares_token_program: Interface<'info, TokenInterface>,
     pub token_program: Interface<'info, TokenInterface>,
     pub strategy_program: Program<'info, Strategy>,
+    pub access_control: Program<'info, AccessControl>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Default, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -81,7 +136,7 @@ pub struct AccountsMap {
     pub accounts_map: Vec<AccountsIndexes>,
 }
 
-struct StrategyAccounts<'info> {
+pub(crate) struct StrategyAccounts<'info> {
     strategy_acc: AccountInfo<'info>,
     strategy_token_account: AccountInfo<'info>,
     strategy_data: AccountInfo<'info>,
@@ -94,12 +149,12 @@ pub fn handle_withdraw<'info>(
     max_loss: u64,
     remaining_accounts_map: AccountsMap
 ) -> Result<()> {
-    let redemtion_fee = accountant::redeem(&ctx.accounts.accountant, amount)?;
+    let redemtion_fee = redemption_fee(&ctx.accounts, amount)?;
     let assets_to_withdraw = amount - redemtion_fee;
 
     let fee_shares = ctx.accounts.vault.load()?.convert_to_shares(redemtion_fee);
     let shares_to_burn = ctx.accounts.vault.load()?.convert_to_shares(assets_to_withdraw);
-    handle_internal(ctx, assets_to_withdraw, shares_to_burn, fee_shares, max_loss, remaining_accounts_map)
+    handle_internal(ctx.accounts, ctx.remaining_accounts, assets_to_withdraw, shares_to_burn, fee_shares, max_loss, remaining_accounts_map)
 }
 
 pub fn handle_redeem<'info>(
@@ -108,29 +163,65 @@ pub fn handle_redeem<'info>(
     max_loss: u64,
     remaining_accounts_map: AccountsMap
 ) -> Result<()> {
-    let redemtion_fee_shares = accountant::redeem(&ctx.accounts.accountant, shares)?;
-    let amount = ctx.accounts.vault.load()?.convert_to_underlying(shares-redemtion_fee_shares);
-    handle_internal(ctx, amount, shares-redemtion_fee_shares, redemtion_fee_shares, max_loss, remaining_accounts_map)
+    redeem_shares(ctx.accounts, ctx.remaining_accounts, shares, max_loss, remaining_accounts_map)
+}
+
+pub(crate) fn redeem_shares<'info>(
+    accounts: &mut Withdraw<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    shares: u64,
+    max_loss: u64,
+    remaining_accounts_map: AccountsMap
+) -> Result<()> {
+    let redemtion_fee_shares = redemption_fee(accounts, shares)?;
+    let amount = accounts.vault.load()?.convert_to_underlying(shares-redemtion_fee_shares);
+    handle_internal(accounts, remaining_accounts, amount, shares-redemtion_fee_shares, redemtion_fee_shares, max_loss, remaining_accounts_map)
+}
+
+// the accountant redeeming its own fee shares is not charged again
+fn redemption_fee(accounts: &Withdraw, amount: u64) -> Result<u64> {
+    if accounts.owner.key() == accounts.accountant.key() {
+        return Ok(0);
+    }
+    accountant::redeem(
+        &accounts.accountant,
+        &accounts.vault.key(),
+        &[accounts.vault_fee_override.to_account_info()],
+        amount
+    )
+}
+
+// the accountant's fee shares were never deposited, so they aren't locked either
+fn early_exit_fee(accounts: &Withdraw) -> Result<u64> {
+    if accounts.owner.key() == accounts.accountant.key() {
+        return Ok(0);
+    }
+    vault::early_exit_fee(&accounts.vault, &accounts.user_data)
 }
 
 fn handle_internal<'info>(
-    ctx: Context<'_, '_, '_, 'info, Withdraw<'info>>,
+    accounts: &mut Withdraw<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
     assets: u64,
     shares_to_burn: u64,
     fee_shares: u64,
     max_loss: u64,
     remaining_accounts_map: AccountsMap
 ) -> Result<()> {
-    if !ctx.accounts.vault.load()?.direct_withdraw_enabled {
+    if !accounts.vault.load()?.direct_withdraw_enabled {
         return Err(ErrorCode::DirectWithdrawDisabled.into());
     }
+    vault::validate_not_paused(&accounts.vault, PAUSE_WITHDRAWALS)?;
     if assets == 0 || shares_to_burn == 0 {
         return Err(ErrorCode::ZeroValue.into());
     }
 
-    let vault_token_account = &mut ctx.accounts.vault_token_account;
-    let user_shares_balance = ctx.accounts.user_shares_account.amount;
-    let remaining_accounts = ctx.remaining_accounts;
+    vault::validate_not_sanctioned(&accounts.sanctioned)?;
+
+    let early_exit_fee = early_exit_fee(accounts)?;
+
+    let vault_token_account = &mut accounts.vault_token_account;
+    let user_shares_balance = accounts.user_shares_account.amount;
     let strategies_with_accounts= parse_remaining(remaining_accounts, remaining_accounts_map)?;
 
     if user_shares_balance < shares_to_burn {
@@ -138,68 +229,113 @@ fn handle_internal<'info>(
     }
 
     validate_max_withdraw(
-        &ctx.accounts.vault,
-        user_shares_balance, 
+        &accounts.vault,
+        accounts.vault.load()?.convert_to_underlying(user_shares_balance), 
         &strategies_with_accounts, 
         max_loss,
         assets
     )?;
 
     // todo: hadle min user deposit
-    let assets_to_transfer = withdraw_assets(
+    let mut assets_to_transfer = withdraw_assets(
         vault_token_account,
-        &ctx.accounts.underlying_mint.to_account_info(),
-        &ctx.accounts.token_program.to_account_info(),
-        &ctx.accounts.strategy_program.to_account_info(),
-        &ctx.accounts.vault,
+        &accounts.underlying_mint.to_account_info(),
+        &accounts.token_program.to_account_info(),
+        &accounts.strategy_program.to_account_info(),
+        &accounts.vault,
         assets,
         &strategies_with_accounts,
     )?;
 
-    if assets > assets_to_transfer && max_loss < MAX_BPS {
-        if assets - assets_to_transfer > (assets * max_loss) / MAX_BPS {
-            return Err(ErrorCode::TooMuchLoss.into());
-        }
-    }
+    assets_to_transfer = accounts.vault.load()?.senior_withdraw_cover(assets, assets_to_transfer);
 
-    ctx.accounts.vault.load_mut()?.handle_withdraw(assets_to_transfer, shares_to_burn);
+    // an early exit leaves part of the assets in the vault for the remaining holders
+    let early_exit_assets = (assets_to_transfer as u128 * early_exit_fee as u128 / MAX_BPS as u128) as u64;
+    assets_to_transfer -= early_exit_assets;
+
+    accounts.vault.load_mut()?.handle_senior_withdraw(assets_to_transfer, assets - early_exit_assets, shares_to_burn);
 
     token::burn(
-        ctx.accounts.shares_token_program.to_account_info(),
-        ctx.accounts.shares_mint.to_account_info(),
-        ctx.accounts.user_shares_account.to_account_info(),
-        ctx.accounts.user.to_account_info(),
+        accounts.shares_token_program.to_account_info(),
+        accounts.shares_mint.to_account_info(),
+        accounts.user_shares_account.to_account_info(),
+        accounts.user.to_account_info(),
         shares_to_burn
     )?;
 
     if fee_shares > 0 {
-        token::transfer(
-            ctx.accounts.shares_token_program.to_account_info(),
-            ctx.accounts.user_shares_account.to_account_info(),
-            ctx.accounts.accountant_recipient.to_account_info(),
-            ctx.accounts.user.to_account_info(),
-            &ctx.accounts.shares_mint,
-            fee_shares,
-        )?;
+        // fee shares are settled in underlying as far as idle above the minimum allows
+        let underlying_fee_shares = if vault::fees_in_underlying(&accounts.vault, &accounts.accountant_token_account)? {
+            let vault = accounts.vault.load()?;
+            vault.fee_shares_payable_in_underlying(fee_shares, vault.idle_for_fees(0))
+        } else {
+            0
+        };
+
+        if underlying_fee_shares > 0 {
+            let fee_assets = accounts.vault.load()?.convert_to_underlying(underlying_fee_shares);
+
+            token::burn(
+                accounts.shares_token_program.to_account_info(),
+                accounts.shares_mint.to_account_info(),
+                accounts.user_shares_account.to_account_info(),
+                accounts.user.to_account_info(),
+                underlying_fee_shares
+            )?;
+
+            token::transfer_with_signer(
+                accounts.token_program.to_account_info(),
+                accounts.vault_token_account.to_account_info(),
+                accounts.accountant_token_account.to_account_info(),
+                accounts.vault.to_account_info(),
+                &accounts.underlying_mint,
+                fee_assets,
+                &accounts.vault.load()?.seeds()
+            )?;
+
+            accounts.vault.load_mut()?.handle_withdraw(fee_assets, underlying_fee_shares);
+        }
+
+        if fee_shares > underlying_fee_shares {
+            token::transfer_shares(
+                accounts.shares_token_program.to_account_info(),
+                accounts.user_shares_account.to_account_info(),
+                accounts.accountant_recipient.to_account_info(),
+                accounts.user.to_account_info(),
+                &accounts.shares_mint,
+                fee_shares - underlying_fee_shares,
+                &accounts.vault.load()?.seeds_shares(),
+            )?;
+        }
     }
 
+    let balance_before = accounts.user_token_account.amount;
     token::transfer_with_signer(
-        ctx.accounts.token_program.to_account_info(),
-        ctx.accounts.vault_token_account.to_account_info(),
-        ctx.accounts.user_token_account.to_account_info(),
-        ctx.accounts.vault.to_account_info(),
-        &ctx.accounts.underlying_mint,
+        accounts.token_program.to_account_info(),
+        accounts.vault_token_account.to_account_info(),
+        accounts.user_token_account.to_account_info(),
+        accounts.vault.to_account_info(),
+        &accounts.underlying_mint,
         assets_to_transfer,
-        &ctx.accounts.vault.load()?.seeds()
+        &accounts.vault.load()?.seeds()
     )?;
+    accounts.user_token_account.reload()?;
 
-    if !ctx.accounts.user_data.data_is_empty() {
-        let mut user_data: UserData = ctx.accounts.user_data.deserialize()?;
-        user_data.handle_withdraw(assets_to_transfer)?;
-        ctx.accounts.user_data.serialize(&user_data)?;
+    // the loss bound covers transfer fees taken on the way out, but not the early-exit fee
+    let received = accounts.user_token_account.amount - balance_before + early_exit_assets;
+    if assets > received && max_loss < MAX_BPS {
+        if assets - received > (assets * max_loss) / MAX_BPS {
+            return Err(ErrorCode::TooMuchLoss.into());
+        }
+    }
+
+    if !accounts.user_data.data_is_empty() {
+        let mut user_data = load_user_data(&accounts.user_data)?;
+        user_data.handle_withdraw(assets_to_transfer, shares_to_burn + fee_shares)?;
+        save_user_data(&accounts.user_data, &mut user_data, &accounts.user, &accounts.system_program)?;
     }
 
-    let vault = ctx.accounts.vault.load()?;
+    let vault = accounts.vault.load()?;
     let share_price = vault.get_share_price();
 
     emit!(VaultWithdrawlEvent {
@@ -208,19 +344,20 @@ fn handle_internal<'info>(
         total_share: vault.total_shares(),
         assets_to_transfer,
         shares_to_burn,
-        token_account: ctx.accounts.user_token_account.to_account_info().key(),
-        share_account: ctx.accounts.user_shares_account.to_account_info().key(),
-        token_mint: ctx.accounts.vault_token_account.mint,
-        share_mint: ctx.accounts.shares_mint.to_account_info().key(),
-        authority: ctx.accounts.user.to_account_info().key(),
+        token_account: accounts.user_token_account.to_account_info().key(),
+        share_account: accounts.user_shares_account.to_account_info().key(),
+        token_mint: accounts.vault_token_account.mint,
+        share_mint: accounts.shares_mint.to_account_info().key(),
+        authority: accounts.user.to_account_info().key(),
         share_price,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
-    Ok(())
+    drop(vault);
+    vault::record_share_price(&accounts.vault, &accounts.price_history)
 }
 
-fn parse_remaining<'info>(
+pub(crate) fn parse_remaining<'info>(
     remaining_accounts: &[AccountInfo<'info>], 
     remaining_accounts_map: AccountsMap
 ) -> Result<Box<Vec<StrategyAccounts<'info>>>> {
@@ -251,15 +388,15 @@ fn parse_remaining<'info>(
     Ok(Box::new(strategy_accounts))
 }
 
-fn validate_max_withdraw<'info>(
+pub(crate) fn validate_max_withdraw<'info>(
     vault_acc: &AccountLoader<'info, Vault>,
-    shares: u64, 
+    user_assets: u64, 
     strategies: &Vec<StrategyAccounts<'info>>,
     max_loss: u64,
     assets: u64
 ) -> Result<()> {
     let vault = vault_acc.load()?;
-    let mut max_assets = vault.convert_to_underlying(shares);
+    let mut max_assets = user_assets;
 
     if max_assets > vault.total_idle {
         let mut have = vault.total_idle;
@@ -309,7 +446,7 @@ fn validate_max_withdraw<'info>(
     Ok(())
 }
 
-fn withdraw_assets<'info>(
+pub(crate) fn withdraw_assets<'info>(
     vault_token_account: &mut InterfaceAccount<'info, TokenAccount>,
     underlying_mint: &AccountInfo<'info>,
     token_program: &AccountInfo<'info>,
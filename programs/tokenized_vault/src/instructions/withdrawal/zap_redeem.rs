@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::Memo;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
+
+use crate::errors::ErrorCode;
+use crate::events::VaultZapEvent;
+use crate::instructions::swap_direction;
+use crate::instructions::withdraw::*;
+use crate::utils::WhirlpoolSwap;
+
+#[derive(Accounts)]
+pub struct ZapRedeem<'info> {
+    pub withdraw: Withdraw<'info>,
+
+    #[account(
+        mut,
+        token::mint = output_mint,
+        token::authority = withdraw.user,
+        token::token_program = output_token_program,
+        constraint = user_output_token_account.mint != withdraw.underlying_mint.key() @ ErrorCode::InvalidSwapPool
+    )]
+    pub user_output_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub output_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: validated by the whirlpool program
+    #[account(mut)]
+    pub whirlpool: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub token_vault_a: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub token_vault_b: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: validated by the whirlpool program
+    #[account(mut)]
+    pub tick_array_0: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the whirlpool program
+    #[account(mut)]
+    pub tick_array_1: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the whirlpool program
+    #[account(mut)]
+    pub tick_array_2: UncheckedAccount<'info>,
+
+    /// CHECK: validated by the whirlpool program
+    pub oracle: UncheckedAccount<'info>,
+
+    /// CHECK: whirlpool program
+    #[account(address = whirlpool_cpi::ID)]
+    pub whirlpool_program: UncheckedAccount<'info>,
+
+    pub output_token_program: Interface<'info, TokenInterface>,
+    pub memo_program: Program<'info, Memo>,
+}
+
+pub fn handle_zap_redeem<'info>(
+    ctx: Context<'_, '_, '_, 'info, ZapRedeem<'info>>,
+    shares: u64,
+    max_loss: u64,
+    min_out: u64,
+    remaining_accounts_map: AccountsMap
+) -> Result<()> {
+    let output_mint = ctx.accounts.user_output_token_account.mint;
+    let underlying_mint = ctx.accounts.withdraw.underlying_mint.key();
+
+    let underlying_is_a = swap_direction(
+        &ctx.accounts.token_vault_a.mint,
+        &ctx.accounts.token_vault_b.mint,
+        &underlying_mint,
+        &output_mint
+    )?;
+
+    let underlying_before = ctx.accounts.withdraw.user_token_account.amount;
+
+    redeem_shares(&mut ctx.accounts.withdraw, ctx.remaining_accounts, shares, max_loss, remaining_accounts_map)?;
+
+    // redeem_shares reloads the user's underlying account after paying out
+    let amount_in = ctx.accounts.withdraw.user_token_account.amount - underlying_before;
+
+    let underlying_side = (
+        ctx.accounts.withdraw.token_program.to_account_info(),
+        ctx.accounts.withdraw.underlying_mint.to_account_info(),
+        ctx.accounts.withdraw.user_token_account.to_account_info(),
+    );
+    let output_side = (
+        ctx.accounts.output_token_program.to_account_info(),
+        ctx.accounts.output_mint.to_account_info(),
+        ctx.accounts.user_output_token_account.to_account_info(),
+    );
+    let ((token_program_a, token_mint_a, token_owner_account_a), (token_program_b, token_mint_b, token_owner_account_b)) =
+        if underlying_is_a { (underlying_side, output_side) } else { (output_side, underlying_side) };
+
+    let swap = WhirlpoolSwap {
+        whirlpool_program: ctx.accounts.whirlpool_program.to_account_info(),
+        whirlpool: ctx.accounts.whirlpool.to_account_info(),
+        token_program_a,
+        token_program_b,
+        memo_program: ctx.accounts.memo_program.to_account_info(),
+        token_authority: ctx.accounts.withdraw.user.to_account_info(),
+        token_mint_a,
+        token_mint_b,
+        token_owner_account_a,
+        token_vault_a: ctx.accounts.token_vault_a.to_account_info(),
+        token_owner_account_b,
+        token_vault_b: ctx.accounts.token_vault_b.to_account_info(),
+        tick_array_0: ctx.accounts.tick_array_0.to_account_info(),
+        tick_array_1: ctx.accounts.tick_array_1.to_account_info(),
+        tick_array_2: ctx.accounts.tick_array_2.to_account_info(),
+        oracle: ctx.accounts.oracle.to_account_info(),
+    };
+
+    let output_before = ctx.accounts.user_output_token_account.amount;
+
+    // underlying is sold for the output mint, so the pool goes A to B when the underlying is token A
+    swap.swap_exact_in(amount_in, min_out, underlying_is_a)?;
+
+    ctx.accounts.user_output_token_account.reload()?;
+    let amount_out = ctx.accounts.user_output_token_account.amount - output_before;
+    if amount_out < min_out {
+        return Err(ErrorCode::SlippageExceeded.into());
+    }
+
+    emit!(VaultZapEvent {
+        vault_key: ctx.accounts.withdraw.vault.key(),
+        input_mint: underlying_mint,
+        output_mint,
+        amount_in,
+        amount_out,
+        authority: ctx.accounts.withdraw.user.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -7,7 +7,7 @@ pub mod events;
 
 use anchor_lang::prelude::*;
 
-pub use state::{SharesConfig, VaultConfig};
+pub use state::{SharesConfig, VaultConfig, VaultConfigPatch};
 pub use instructions::*;
 
 declare_id!("8Y5ZEEnhiNdvGHbfiZVj2eSawrNrQTKd9jPEFqnnKizC");
@@ -28,16 +28,40 @@ pub mod tokenized_vault {
         handle_init_vault_shares(ctx, index, config)
     }
 
+    pub fn init_kyc_vault_shares(ctx: Context<InitKycVaultShares>, index: u64, config: Box<SharesConfig>) -> Result<()> {
+        handle_init_kyc_vault_shares(ctx, index, config)
+    }
+
     pub fn init_withdraw_shares_account(ctx: Context<InitWithdrawSharesAccount>) -> Result<()> {
         handle_init_withdraw_pool(ctx)
     }
 
-    pub fn deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
-        handle_deposit(ctx, amount)
+    pub fn deposit(
+        ctx: Context<Deposit>,
+        amount: u64,
+        referrer: Option<Pubkey>,
+        whitelist_proof: Option<Vec<[u8; 32]>>
+    ) -> Result<()> {
+        handle_deposit(ctx, amount, referrer, whitelist_proof)
+    }
+
+    pub fn direct_deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, DirectDeposit<'info>>,
+        amount: u64,
+        referrer: Option<Pubkey>,
+        whitelist_proof: Option<Vec<[u8; 32]>>
+    ) -> Result<()> {
+        handle_direct_deposit(ctx, amount, referrer, whitelist_proof)
     }
 
-    pub fn direct_deposit<'info>(ctx: Context<'_, '_, '_, 'info, DirectDeposit<'info>>, amount: u64) -> Result<()> {
-        handle_direct_deposit(ctx, amount)
+    pub fn zap_deposit(
+        ctx: Context<ZapDeposit>,
+        amount_in: u64,
+        min_underlying_out: u64,
+        referrer: Option<Pubkey>,
+        whitelist_proof: Option<Vec<[u8; 32]>>
+    ) -> Result<()> {
+        handle_zap_deposit(ctx, amount_in, min_underlying_out, referrer, whitelist_proof)
     }
 
     pub fn withdraw<'info>(
@@ -58,6 +82,29 @@ pub mod tokenized_vault {
         handle_redeem(ctx, shares, max_loss, remaining_accounts_map)
     }
 
+    pub fn zap_redeem<'info>(
+        ctx: Context<'_, '_, '_, 'info, ZapRedeem<'info>>, 
+        shares: u64, 
+        max_loss: u64,
+        min_out: u64,
+        remaining_accounts_map: AccountsMap
+    ) -> Result<()> {
+        handle_zap_redeem(ctx, shares, max_loss, min_out, remaining_accounts_map)
+    }
+
+    pub fn deposit_junior(ctx: Context<DepositJunior>, amount: u64) -> Result<()> {
+        handle_deposit_junior(ctx, amount)
+    }
+
+    pub fn redeem_junior<'info>(
+        ctx: Context<'_, '_, '_, 'info, RedeemJunior<'info>>, 
+        shares: u64, 
+        max_loss: u64,
+        remaining_accounts_map: AccountsMap
+    ) -> Result<()> {
+        handle_redeem_junior(ctx, shares, max_loss, remaining_accounts_map)
+    }
+
     pub fn request_withdraw<'info>(
         ctx: Context<'_, '_, '_, 'info, RequestWithdraw<'info>>, 
         amount: u64, 
@@ -78,6 +125,14 @@ pub mod tokenized_vault {
         handle_cancel_withdrawal_request(ctx)
     }
 
+    pub fn seize_withdrawal_request(ctx: Context<SeizeWithdrawalRequest>) -> Result<()> {
+        handle_seize_withdrawal_request(ctx)
+    }
+
+    pub fn release_seized_shares(ctx: Context<ReleaseSeizedShares>, shares: u64) -> Result<()> {
+        handle_release_seized_shares(ctx, shares)
+    }
+
     pub fn fulfill_withdrawal_request(ctx: Context<FulfillWithdrawalRequest>, 
     ) -> Result<()> {
         handle_fulfill_withdrawal_request(ctx)
@@ -102,6 +157,10 @@ pub mod tokenized_vault {
         handle_whitelist(ctx, user)
     }
 
+    pub fn whitelist_with_expiry(ctx: Context<Whitelist>, user: Pubkey, expires_at: i64) -> Result<()> {
+        handle_whitelist_with_expiry(ctx, user, expires_at)
+    }
+
     pub fn revoke_whitelisting(ctx: Context<RevokeWhitelisting>, user: Pubkey) -> Result<()> {
         handle_revoke_whitelisting(ctx, user)
     }
@@ -138,6 +197,70 @@ pub mod tokenized_vault {
         handle_set_accountant(ctx, value)
     }
 
+    pub fn set_fees_in_underlying(ctx: Context<SetVaultProperty>, value: bool) -> Result<()> {
+        handle_set_fees_in_underlying(ctx, value)
+    }
+
+    pub fn set_min_deposit_lock_seconds(ctx: Context<SetVaultProperty>, value: u64) -> Result<()> {
+        handle_set_min_deposit_lock_seconds(ctx, value)
+    }
+
+    pub fn set_early_exit_fee(ctx: Context<SetVaultProperty>, value: u64) -> Result<()> {
+        handle_set_early_exit_fee(ctx, value)
+    }
+
+    pub fn set_audit_pause_threshold(ctx: Context<SetVaultProperty>, value: u64) -> Result<()> {
+        handle_set_audit_pause_threshold(ctx, value)
+    }
+
+    pub fn set_whitelist_root(ctx: Context<SetVaultProperty>, root: [u8; 32]) -> Result<()> {
+        handle_set_whitelist_root(ctx, root)
+    }
+
+    pub fn set_pause_flags(ctx: Context<SetVaultProperty>, flags: u8) -> Result<()> {
+        handle_set_pause_flags(ctx, flags)
+    }
+
+    pub fn update_vault_config(ctx: Context<SetVaultProperty>, patch: VaultConfigPatch) -> Result<()> {
+        handle_update_vault_config(ctx, patch)
+    }
+
+    pub fn init_price_history(ctx: Context<InitPriceHistory>, interval: u64) -> Result<()> {
+        handle_init_price_history(ctx, interval)
+    }
+
+    pub fn set_price_history_interval(ctx: Context<SetPriceHistoryInterval>, interval: u64) -> Result<()> {
+        handle_set_price_history_interval(ctx, interval)
+    }
+
+    pub fn init_price_feed(ctx: Context<InitPriceFeed>) -> Result<()> {
+        handle_init_price_feed(ctx)
+    }
+
+    pub fn audit_vault(ctx: Context<AuditVault>) -> Result<()> {
+        handle_audit_vault(ctx)
+    }
+
+    pub fn sync_idle(ctx: Context<SyncIdle>) -> Result<()> {
+        handle_sync_idle(ctx)
+    }
+
+    pub fn sweep(ctx: Context<Sweep>) -> Result<()> {
+        handle_sweep(ctx)
+    }
+
+    pub fn refresh_price(ctx: Context<RefreshPrice>) -> Result<()> {
+        handle_refresh_price(ctx)
+    }
+
+    pub fn migrate_vault(ctx: Context<MigrateVault>) -> Result<()> {
+        handle_migrate_vault(ctx)
+    }
+
+    pub fn pause_vault(ctx: Context<PauseVault>, flags: u8) -> Result<()> {
+        handle_pause_vault(ctx, flags)
+    }
+
     pub fn process_report(ctx: Context<ProcessReport>) -> Result<()> {
         handle_process_report(ctx)
     }
@@ -149,4 +272,8 @@ pub mod tokenized_vault {
     pub fn close_vault(ctx: Context<CloseVault>) -> Result<()> {
         handle_close_vault(ctx)
     }
+
+    pub fn enable_tranches(ctx: Context<EnableTranches>, senior_target_rate: u64) -> Result<()> {
+        handle_enable_tranches(ctx, senior_target_rate)
+    }
 }
\ No newline at end of file
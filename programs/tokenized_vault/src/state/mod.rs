@@ -1,11 +1,21 @@
 pub mod config;
 pub mod strategy_data;
 pub mod vault;
+pub mod vault_v0;
 pub mod user_data;
+pub mod user_data_v0;
+pub mod referral;
+pub mod price_history;
+pub mod price_feed;
 pub mod withdraw_request;
 
 pub use config::*;
 pub use strategy_data::*;
 pub use vault::*;
+pub use vault_v0::*;
 pub use user_data::*;
+pub use user_data_v0::*;
+pub use referral::*;
+pub use price_history::*;
+pub use price_feed::*;
 pub use withdraw_request::*;
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::DISCRIMINATOR_LEN;
+use crate::state::Vault;
+
+/// Read-only share price feed for external programs, the layout is stable and only grows into `reserved`.
+///
+/// | offset | field                 | type     |
+/// |--------|-----------------------|----------|
+/// | 0      | discriminator         | [u8; 8]  |
+/// | 8      | vault                 | Pubkey   |
+/// | 40     | price                 | u64      |
+/// | 48     | exponent              | i32      |
+/// | 52     | confidence            | u64      |
+/// | 60     | last_update_slot      | u64      |
+/// | 68     | last_update_timestamp | i64      |
+/// | 76     | reserved              | [u64; 4] |
+///
+/// All integers are little-endian. One share token is worth `price * 10^exponent` underlying tokens.
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct PriceFeed {
+    pub vault: Pubkey,
+    pub price: u64,
+    pub exponent: i32,
+    /// how much the price still rises once the locked profit is fully unlocked, same exponent as `price`
+    pub confidence: u64,
+    pub last_update_slot: u64,
+    pub last_update_timestamp: i64,
+    pub reserved: [u64; 4],
+}
+
+impl PriceFeed {
+    pub const LEN: usize = DISCRIMINATOR_LEN + PriceFeed::INIT_SPACE;
+
+    pub fn update(&mut self, vault: &Vault) -> Result<()> {
+        let clock = Clock::get()?;
+
+        self.vault = vault.key;
+        self.price = vault.get_share_price();
+        // `get_share_price` is in underlying base units per share token, scaled by 10^6
+        self.exponent = -(6 + vault.underlying_decimals as i32);
+        self.confidence = vault.get_unlocked_share_price()?.saturating_sub(self.price);
+        self.last_update_slot = clock.slot;
+        self.last_update_timestamp = clock.unix_timestamp;
+
+        Ok(())
+    }
+}
+
+const _: () = assert!(PriceFeed::LEN == 108);
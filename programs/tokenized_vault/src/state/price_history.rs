@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DISCRIMINATOR_LEN, MAX_BPS, PRICE_HISTORY_CAPACITY, SECONDS_PER_YEAR};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, InitSpace)]
+pub struct PriceSnapshot {
+    pub timestamp: i64,
+    pub share_price: u64,
+}
+
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct PriceHistory {
+    pub vault: Pubkey,
+    /// min seconds between two snapshots
+    pub interval: u64,
+    /// slot the next snapshot goes to, the oldest one once the buffer is full
+    pub head: u64,
+    #[max_len(PRICE_HISTORY_CAPACITY)]
+    pub snapshots: Vec<PriceSnapshot>,
+}
+
+impl PriceHistory {
+    pub const LEN: usize = DISCRIMINATOR_LEN + PriceHistory::INIT_SPACE;
+
+    pub fn init(&mut self, vault: Pubkey, interval: u64) {
+        self.vault = vault;
+        self.interval = interval;
+        self.head = 0;
+        self.snapshots = Vec::with_capacity(PRICE_HISTORY_CAPACITY);
+    }
+
+    /// returns false if the last snapshot is younger than `interval`
+    pub fn record(&mut self, share_price: u64, timestamp: i64) -> bool {
+        if let Some(last) = self.latest() {
+            if timestamp.saturating_sub(last.timestamp) < self.interval as i64 {
+                return false;
+            }
+        }
+
+        let snapshot = PriceSnapshot { timestamp, share_price };
+
+        if self.snapshots.len() < PRICE_HISTORY_CAPACITY {
+            self.snapshots.push(snapshot);
+        } else {
+            self.snapshots[self.head as usize] = snapshot;
+        }
+        self.head = (self.head + 1) % PRICE_HISTORY_CAPACITY as u64;
+
+        true
+    }
+
+    pub fn latest(&self) -> Option<PriceSnapshot> {
+        let len = self.snapshots.len();
+        if len == 0 {
+            return None;
+        }
+        // while the buffer is filling up `head` equals `len`
+        Some(self.snapshots[(self.head as usize + len - 1) % len])
+    }
+
+    /// snapshots from the oldest to the latest
+    pub fn chronological(&self) -> impl Iterator<Item = PriceSnapshot> + '_ {
+        let len = self.snapshots.len();
+        (0..len).map(move |i| self.snapshots[(self.head as usize + i) % len])
+    }
+
+    /// time-weighted average share price over `[now - window, now]`,
+    /// each snapshot's price holds until the next one. Clipped to the oldest snapshot.
+    pub fn twap(&self, window: i64, now: i64) -> Option<u64> {
+        let start = now.checked_sub(window)?;
+        let ends = self.chronological()
+            .skip(1)
+            .map(|snapshot| snapshot.timestamp)
+            .chain(std::iter::once(now));
+
+        let mut weighted: u128 = 0;
+        let mut elapsed: u128 = 0;
+
+        for (snapshot, end) in self.chronological().zip(ends) {
+            let from = snapshot.timestamp.max(start);
+            let to = end.min(now);
+
+            if to > from {
+                weighted += snapshot.share_price as u128 * (to - from) as u128;
+                elapsed += (to - from) as u128;
+            }
+        }
+
+        if elapsed == 0 {
+            return self.latest().map(|snapshot| snapshot.share_price);
+        }
+
+        Some((weighted / elapsed) as u64)
+    }
+
+    /// trailing APY in bps from the price in effect at `now - window` to the latest snapshot, not compounded.
+    /// Clipped to the oldest snapshot, negative after losses.
+    pub fn apy(&self, window: i64, now: i64) -> Option<i64> {
+        let start = now.checked_sub(window)?;
+        let latest = self.latest()?;
+
+        let first = self.chronological()
+            .take_while(|snapshot| snapshot.timestamp <= start)
+            .last()
+            .or_else(|| self.chronological().next())?;
+
+        let from = first.timestamp.max(start);
+        let elapsed = latest.timestamp.checked_sub(from)?;
+
+        if elapsed <= 0 || first.share_price == 0 {
+            return None;
+        }
+
+        let change = latest.share_price as i128 - first.share_price as i128;
+        let apy = change * MAX_BPS as i128 * SECONDS_PER_YEAR as i128
+            / (first.share_price as i128 * elapsed as i128);
+
+        i64::try_from(apy).ok()
+    }
+}
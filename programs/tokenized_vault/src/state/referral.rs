@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::DISCRIMINATOR_LEN;
+
+#[account]
+#[derive(Default, Debug, InitSpace)]
+pub struct Referral {
+    /// set once on the first deposit that names a referrer
+    pub referrer: Pubkey,
+}
+
+impl Referral {
+    pub const LEN: usize = DISCRIMINATOR_LEN + Referral::INIT_SPACE;
+
+    pub fn set_referrer(&mut self, user: &Pubkey, referrer: Option<Pubkey>) {
+        if self.referrer != Pubkey::default() {
+            return;
+        }
+
+        if let Some(referrer) = referrer {
+            if referrer != *user {
+                self.referrer = referrer;
+            }
+        }
+    }
+
+    pub fn referrer(&self) -> Option<Pubkey> {
+        if self.referrer == Pubkey::default() {
+            None
+        } else {
+            Some(self.referrer)
+        }
+    }
+}
@@ -1,24 +1,85 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::DISCRIMINATOR_LEN;
+use crate::constants::{DISCRIMINATOR_LEN, ONE_SHARE_TOKEN};
 
 #[account]
 #[derive(Default, Debug, InitSpace)]
 pub struct UserData {
     pub deposited: u64,
     pub whitelisted: bool,
+    /// senior shares only, junior deposits and redeems just move `deposited`
+    pub shares: u64,
+    /// underlying per `ONE_SHARE_TOKEN` shares paid on average for `shares`. entry fees are
+    /// part of the price and withdrawals count what was received, so `realized_pnl` is net of fees
+    pub avg_entry_price: u64,
+    pub total_deposited: u64,
+    pub total_withdrawn: u64,
+    pub realized_pnl: i64,
+    pub last_deposit_ts: i64,
+    /// root the user proved membership under, zeroed when whitelisted by the admin
+    pub whitelist_root: [u8; 32],
+    /// 0 means the whitelisting never expires
+    pub whitelist_expires_at: i64,
+    /// layout version, older accounts are grown to the current layout on their next write
+    pub version: u8,
 }
 
 impl UserData {
     pub const LEN: usize = DISCRIMINATOR_LEN + UserData::INIT_SPACE;
 
-    pub fn handle_withdraw(&mut self, amount: u64) -> Result<()> {
+    // someone else depositing for the user doesn't restart their lock, it only starts one
+    // when there was none
+    pub fn handle_deposit(&mut self, assets: u64, shares: u64, timestamp: i64, by_receiver: bool) {
+        let cost_basis = self.cost_basis(self.shares) + assets as u128;
+
+        self.shares += shares;
+        if self.shares > 0 {
+            self.avg_entry_price = (cost_basis * ONE_SHARE_TOKEN / self.shares as u128) as u64;
+        }
+
+        self.deposited += assets;
+        self.total_deposited += assets;
+        if by_receiver || self.last_deposit_ts == 0 {
+            self.last_deposit_ts = timestamp;
+        }
+    }
+
+    // shares received by transfer have no basis here, so only the tracked part realizes pnl
+    pub fn handle_withdraw(&mut self, assets: u64, shares: u64) -> Result<()> {
+        let tracked_shares = std::cmp::min(shares, self.shares);
+        if tracked_shares > 0 {
+            let tracked_assets = (assets as u128 * tracked_shares as u128 / shares as u128) as i128;
+            let cost_basis = self.cost_basis(tracked_shares) as i128;
+            self.realized_pnl += (tracked_assets - cost_basis) as i64;
+            self.shares -= tracked_shares;
+        }
+
+        if self.shares == 0 {
+            self.avg_entry_price = 0;
+        }
+
+        self.total_withdrawn += assets;
+        self.reduce_deposited(assets);
+
+        Ok(())
+    }
+
+    // a proof only holds while its root is current
+    pub fn is_whitelisted(&self, whitelist_root: [u8; 32], timestamp: i64) -> bool {
+        self.whitelisted
+            && (self.whitelist_root == [0; 32] || self.whitelist_root == whitelist_root)
+            && (self.whitelist_expires_at == 0 || timestamp < self.whitelist_expires_at)
+    }
+
+    pub fn reduce_deposited(&mut self, amount: u64) {
         if self.deposited < amount {
             self.deposited = 0;
         } else {
             self.deposited -= amount;
         }
+    }
 
-        Ok(())
+    fn cost_basis(&self, shares: u64) -> u128 {
+        self.avg_entry_price as u128 * shares as u128 / ONE_SHARE_TOKEN
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::DISCRIMINATOR_LEN;
+use crate::state::UserData;
+
+// snapshot of the unversioned `UserData` layout, never edit it.
+// `load_user_data` still reads it and `save_user_data` grows it to the current layout.
+#[derive(AnchorDeserialize, InitSpace)]
+pub struct UserDataV0 {
+    pub deposited: u64,
+    pub whitelisted: bool,
+}
+
+impl UserDataV0 {
+    pub const LEN: usize = DISCRIMINATOR_LEN + UserDataV0::INIT_SPACE;
+}
+
+// the position and pnl tracking starts from the first write after the upgrade
+impl From<UserDataV0> for UserData {
+    fn from(old: UserDataV0) -> Self {
+        UserData {
+            deposited: old.deposited,
+            whitelisted: old.whitelisted,
+            ..Default::default()
+        }
+    }
+}
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::Mint;
 
-use crate::constants::{DISCRIMINATOR_LEN, ONE_SHARE_TOKEN, VAULT_SEED, SHARES_SEED, MAX_BPS_EXTENDED};
+use crate::errors::ErrorCode;
+use crate::constants::{DISCRIMINATOR_LEN, ONE_SHARE_TOKEN, VAULT_SEED, SHARES_SEED, JUNIOR_SHARES_SEED, MAX_BPS, MAX_BPS_EXTENDED, SECONDS_PER_YEAR, VAULT_VERSION};
 
+// `C` keeps the declared field order, so appended fields land after the deployed layout
 #[account(zero_copy(unsafe))]
-#[repr(packed)]
+#[repr(C, packed)]
 #[derive(Default, Debug, InitSpace)]
 pub struct Vault {
     pub bump: [u8; 1],
@@ -40,8 +42,52 @@ pub struct Vault {
     pub full_profit_unlock_date: u64,
     pub profit_unlocking_rate: u64,
     pub last_profit_update: u64,
+
+    // pay accountant fees in underlying from idle instead of minting shares
+    pub fees_in_underlying: bool,
+
+    // tranched mode: the regular shares mint is the senior tranche, junior owns the rest of the funds
+    pub is_tranched: bool,
+    pub junior_shares_bump: [u8; 1],
+    pub junior_total_shares: u64,
+    pub senior_assets: u64,
+    // annual senior target rate in bps
+    pub senior_target_rate: u64,
+    pub last_tranche_update: u64,
+
+    // deposits are locked for this long, early exits pay `early_exit_fee` bps to the remaining holders
+    pub min_deposit_lock_seconds: u64,
+    pub early_exit_fee: u64,
+
+    // merkle root of whitelisted users, proofs are checked on deposit
+    pub whitelist_root: [u8; 32],
+
+    // bitmask of PAUSE_* flags, each one freezes a single path until cleared
+    pub pause_flags: u8,
+
+    // layout version, bump it together with `migrate_vault` when the layout changes
+    pub version: u8,
+
+    // `audit_vault` pauses deposits once any accounting drift goes above this many bps, 0 disables it
+    pub audit_pause_threshold: u64,
+
+    // junior profit locked at `last_tranche_update`, unlocks linearly over `profit_max_unlock_time`
+    pub junior_locked_profit: u64,
+
+    // senior target return earned since the last report, accrued whenever `senior_assets` changes
+    pub senior_accrued_profit: u64,
+    pub last_senior_accrual: u64,
+
+    // new fields are carved out of here so the account size stays the same
+    pub reserved: [u64; 12],
 }
 
+// the kyc hook can't depend on this crate and reads the root straight out of the account data
+const _: () = assert!(
+    kyc_hook::instructions::VAULT_WHITELIST_ROOT_OFFSET
+        == DISCRIMINATOR_LEN + std::mem::offset_of!(Vault, whitelist_root)
+);
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct VaultConfig {
     pub deposit_limit: u64,
@@ -55,6 +101,85 @@ pub struct VaultConfig {
     pub direct_withdraw_enabled: bool,
 }
 
+// every field the vaults admin can change after init
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct VaultSettings {
+    pub deposit_limit: u64,
+    pub user_deposit_limit: u64,
+    pub min_user_deposit: u64,
+    pub accountant: Pubkey,
+    pub profit_max_unlock_time: u64,
+    pub kyc_verified_only: bool,
+    pub direct_deposit_enabled: bool,
+    pub whitelisted_only: bool,
+    pub direct_withdraw_enabled: bool,
+    pub minimum_total_idle: u64,
+    pub fees_in_underlying: bool,
+    pub min_deposit_lock_seconds: u64,
+    pub early_exit_fee: u64,
+    pub audit_pause_threshold: u64,
+}
+
+// unset fields keep their current value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct VaultConfigPatch {
+    pub deposit_limit: Option<u64>,
+    pub user_deposit_limit: Option<u64>,
+    pub min_user_deposit: Option<u64>,
+    pub accountant: Option<Pubkey>,
+    pub profit_max_unlock_time: Option<u64>,
+    pub kyc_verified_only: Option<bool>,
+    pub direct_deposit_enabled: Option<bool>,
+    pub whitelisted_only: Option<bool>,
+    pub direct_withdraw_enabled: Option<bool>,
+    pub minimum_total_idle: Option<u64>,
+    pub fees_in_underlying: Option<bool>,
+    pub min_deposit_lock_seconds: Option<u64>,
+    pub early_exit_fee: Option<u64>,
+    pub audit_pause_threshold: Option<u64>,
+}
+
+impl VaultSettings {
+    pub fn patch(&self, patch: &VaultConfigPatch) -> Self {
+        Self {
+            deposit_limit: patch.deposit_limit.unwrap_or(self.deposit_limit),
+            user_deposit_limit: patch.user_deposit_limit.unwrap_or(self.user_deposit_limit),
+            min_user_deposit: patch.min_user_deposit.unwrap_or(self.min_user_deposit),
+            accountant: patch.accountant.unwrap_or(self.accountant),
+            profit_max_unlock_time: patch.profit_max_unlock_time.unwrap_or(self.profit_max_unlock_time),
+            kyc_verified_only: patch.kyc_verified_only.unwrap_or(self.kyc_verified_only),
+            direct_deposit_enabled: patch.direct_deposit_enabled.unwrap_or(self.direct_deposit_enabled),
+            whitelisted_only: patch.whitelisted_only.unwrap_or(self.whitelisted_only),
+            direct_withdraw_enabled: patch.direct_withdraw_enabled.unwrap_or(self.direct_withdraw_enabled),
+            minimum_total_idle: patch.minimum_total_idle.unwrap_or(self.minimum_total_idle),
+            fees_in_underlying: patch.fees_in_underlying.unwrap_or(self.fees_in_underlying),
+            min_deposit_lock_seconds: patch.min_deposit_lock_seconds.unwrap_or(self.min_deposit_lock_seconds),
+            early_exit_fee: patch.early_exit_fee.unwrap_or(self.early_exit_fee),
+            audit_pause_threshold: patch.audit_pause_threshold.unwrap_or(self.audit_pause_threshold),
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.early_exit_fee > MAX_BPS {
+            return Err(ErrorCode::InvalidFee.into());
+        }
+
+        if self.audit_pause_threshold > MAX_BPS {
+            return Err(ErrorCode::InvalidVaultConfig.into());
+        }
+
+        if self.user_deposit_limit > 0 && self.min_user_deposit > self.user_deposit_limit {
+            return Err(ErrorCode::InvalidVaultConfig.into());
+        }
+
+        if self.user_deposit_limit > self.deposit_limit {
+            return Err(ErrorCode::InvalidVaultConfig.into());
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct SharesConfig {
     pub name: String,
@@ -80,6 +205,14 @@ impl Vault {
         ]
     }
 
+    pub fn seeds_junior_shares(&self) -> [&[u8]; 3] {
+        [
+            &JUNIOR_SHARES_SEED.as_bytes(),
+            self.key.as_ref(),
+            self.junior_shares_bump.as_ref(),
+        ]
+    }
+
     pub fn init(
         &mut self,
         index: u64,
@@ -112,26 +245,208 @@ impl Vault {
         self.total_shares = 0;
         self.total_idle = 0;
 
+        self.version = VAULT_VERSION;
+
         Ok(())
     }
+
+    pub fn settings(&self) -> VaultSettings {
+        VaultSettings {
+            deposit_limit: self.deposit_limit,
+            user_deposit_limit: self.user_deposit_limit,
+            min_user_deposit: self.min_user_deposit,
+            accountant: self.accountant,
+            profit_max_unlock_time: self.profit_max_unlock_time,
+            kyc_verified_only: self.kyc_verified_only,
+            direct_deposit_enabled: self.direct_deposit_enabled,
+            whitelisted_only: self.whitelisted_only,
+            direct_withdraw_enabled: self.direct_withdraw_enabled,
+            minimum_total_idle: self.minimum_total_idle,
+            fees_in_underlying: self.fees_in_underlying,
+            min_deposit_lock_seconds: self.min_deposit_lock_seconds,
+            early_exit_fee: self.early_exit_fee,
+            audit_pause_threshold: self.audit_pause_threshold,
+        }
+    }
+
+    pub fn apply_settings(&mut self, settings: &VaultSettings) {
+        self.deposit_limit = settings.deposit_limit;
+        self.user_deposit_limit = settings.user_deposit_limit;
+        self.min_user_deposit = settings.min_user_deposit;
+        self.accountant = settings.accountant;
+        self.profit_max_unlock_time = settings.profit_max_unlock_time;
+        self.kyc_verified_only = settings.kyc_verified_only;
+        self.direct_deposit_enabled = settings.direct_deposit_enabled;
+        self.whitelisted_only = settings.whitelisted_only;
+        self.direct_withdraw_enabled = settings.direct_withdraw_enabled;
+        self.minimum_total_idle = settings.minimum_total_idle;
+        self.fees_in_underlying = settings.fees_in_underlying;
+        self.min_deposit_lock_seconds = settings.min_deposit_lock_seconds;
+        self.early_exit_fee = settings.early_exit_fee;
+        self.audit_pause_threshold = settings.audit_pause_threshold;
+    }
+
     pub fn shutdown(&mut self) {
         self.is_shutdown = true;
         self.deposit_limit = 0;
     }
 
+    pub fn enable_tranches(&mut self, junior_shares_bump: u8, senior_target_rate: u64, timestamp: u64) {
+        self.is_tranched = true;
+        self.junior_shares_bump = [junior_shares_bump];
+        self.senior_target_rate = senior_target_rate;
+        self.last_tranche_update = timestamp;
+        self.last_senior_accrual = timestamp;
+    }
+
+    pub fn is_paused(&self, flag: u8) -> bool {
+        self.pause_flags & flag != 0
+    }
+
     pub fn handle_deposit(&mut self, amount: u64, shares: u64) {
         self.total_idle += amount;
         self.total_shares += shares;
+        if self.is_tranched {
+            self.accrue_senior_profit(current_timestamp());
+            self.senior_assets += amount;
+        }
     }
 
     pub fn handle_direct_deposit(&mut self, amount: u64, shares: u64) {
         self.total_debt += amount;
         self.total_shares += shares;
+        if self.is_tranched {
+            self.accrue_senior_profit(current_timestamp());
+            self.senior_assets += amount;
+        }
     }
 
     pub fn handle_withdraw(&mut self, amount: u64, shares: u64) {
+        self.handle_senior_withdraw(amount, amount, shares);
+    }
+
+    // `claim` is the part of the senior tranche being redeemed, `amount` what actually leaves the vault
+    pub fn handle_senior_withdraw(&mut self, amount: u64, claim: u64, shares: u64) {
         self.total_idle -= amount;
         self.total_shares -= shares;
+        if self.is_tranched {
+            self.accrue_senior_profit(current_timestamp());
+            self.senior_assets -= std::cmp::min(claim, self.senior_assets);
+        }
+    }
+
+    // shares burned without paying out their assets, which accrue to the remaining holders
+    pub fn handle_forfeited_shares(&mut self, shares: u64) {
+        self.total_shares -= shares;
+    }
+
+    pub fn handle_junior_deposit(&mut self, amount: u64, shares: u64) {
+        self.total_idle += amount;
+        self.junior_total_shares += shares;
+    }
+
+    pub fn handle_junior_withdraw(&mut self, amount: u64, shares: u64) {
+        self.total_idle -= amount;
+        self.junior_total_shares -= shares;
+    }
+
+    pub fn handle_fee_shares(&mut self, assets: u64, shares: u64) {
+        self.total_shares += shares;
+        if self.is_tranched {
+            self.accrue_senior_profit(current_timestamp());
+            self.senior_assets += assets;
+        }
+    }
+
+    /// Books the senior target return on the current `senior_assets` up to `timestamp`.
+    /// Called before every change of `senior_assets`, so capital only earns for the time it was in the vault.
+    pub fn accrue_senior_profit(&mut self, timestamp: u64) {
+        let elapsed = timestamp.saturating_sub(self.last_senior_accrual);
+        self.senior_accrued_profit += (self.senior_assets as u128
+            * self.senior_target_rate as u128
+            * elapsed as u128
+            / (SECONDS_PER_YEAR as u128 * MAX_BPS as u128)) as u64;
+        self.last_senior_accrual = std::cmp::max(timestamp, self.last_senior_accrual);
+    }
+
+    /// Senior gets profit up to its target return accrued since the last report, the rest goes to junior.
+    /// Junior's part is locked like regular profit so it can't be skimmed by depositing right before a report.
+    /// Must be called before the profit is added to the vault funds.
+    pub fn apply_tranche_profit(&mut self, profit: u64, timestamp: u64) {
+        if !self.is_tranched {
+            return;
+        }
+
+        self.accrue_senior_profit(timestamp);
+        let senior_profit = std::cmp::min(profit, self.senior_accrued_profit);
+        // whatever the profit doesn't cover is not carried over to the next report
+        self.senior_accrued_profit = 0;
+
+        // still locked profit restarts its unlock period together with the new one
+        self.junior_locked_profit = if self.profit_max_unlock_time != 0 {
+            self.junior_locked_profit_at(timestamp) + profit - senior_profit
+        } else {
+            0
+        };
+
+        self.senior_assets += senior_profit;
+        self.last_tranche_update = timestamp;
+    }
+
+    /// Junior absorbs losses first, starting with its locked profit, senior only takes what junior can't cover.
+    /// Must be called before the loss is removed from the vault funds.
+    pub fn apply_tranche_loss(&mut self, loss: u64, timestamp: u64) {
+        if !self.is_tranched {
+            return;
+        }
+
+        self.accrue_senior_profit(timestamp);
+
+        let locked = self.junior_locked_profit_at(timestamp);
+        if locked > 0 {
+            // scale the locked amount so it keeps unlocking at the same pace
+            let remaining = locked - std::cmp::min(loss, locked);
+            self.junior_locked_profit = (self.junior_locked_profit as u128 * remaining as u128 / locked as u128) as u64;
+        }
+
+        let junior_loss = std::cmp::min(loss, self.total_funds() - self.senior_funds());
+        self.senior_assets -= std::cmp::min(loss - junior_loss, self.senior_assets);
+    }
+
+    /// Amount a senior withdrawal pays out when freeing funds from strategies realised a loss.
+    /// Junior absorbs losses first, so the withdrawal is still paid in full from idle
+    /// as long as the funds left after the loss cover the whole senior tranche.
+    pub fn senior_withdraw_cover(&self, requested: u64, withdrawn: u64) -> u64 {
+        if !self.is_tranched || withdrawn >= requested {
+            return withdrawn;
+        }
+
+        if self.senior_assets <= self.total_funds() && requested <= self.total_idle {
+            requested
+        } else {
+            withdrawn
+        }
+    }
+
+    pub fn handle_fee_payment(&mut self, amount: u64) {
+        self.total_idle -= amount;
+    }
+
+    // idle that can go to the accountant without dipping below `minimum_total_idle`,
+    // `reserved` is idle already promised to the user in the same instruction
+    pub fn idle_for_fees(&self, reserved: u64) -> u64 {
+        self.total_idle
+            .saturating_sub(reserved)
+            .saturating_sub(self.minimum_total_idle)
+    }
+
+    // how many of `fee_shares` can be paid out of `idle`, the rest goes to the accountant as shares
+    pub fn fee_shares_payable_in_underlying(&self, fee_shares: u64, idle: u64) -> u64 {
+        if self.convert_to_underlying(fee_shares) <= idle {
+            fee_shares
+        } else {
+            std::cmp::min(self.convert_to_shares(idle), fee_shares)
+        }
     }
 
     pub fn max_deposit(&self) -> u64 {
@@ -146,7 +461,7 @@ impl Vault {
         if self.total_shares() == 0 {
             amount
         } else {
-            (amount as u128 * self.total_shares() as u128 / self.total_funds() as u128) as u64
+            (amount as u128 * self.total_shares() as u128 / self.senior_funds() as u128) as u64
         }
     } 
 
@@ -154,7 +469,24 @@ impl Vault {
         if self.total_shares() == 0 {
             shares
         } else {
-            (shares as u128 * self.total_funds() as u128 / self.total_shares() as u128) as u64
+            (shares as u128 * self.senior_funds() as u128 / self.total_shares() as u128) as u64
+        }
+    }
+
+    pub fn convert_to_junior_shares(&self, amount: u64, timestamp: u64) -> u64 {
+        let junior_funds = self.junior_funds_at(timestamp);
+        if self.junior_total_shares == 0 || junior_funds == 0 {
+            amount
+        } else {
+            (amount as u128 * self.junior_total_shares as u128 / junior_funds as u128) as u64
+        }
+    }
+
+    pub fn convert_junior_to_underlying(&self, shares: u64, timestamp: u64) -> u64 {
+        if self.junior_total_shares == 0 {
+            shares
+        } else {
+            (shares as u128 * self.junior_funds_at(timestamp) as u128 / self.junior_total_shares as u128) as u64
         }
     }
 
@@ -162,6 +494,33 @@ impl Vault {
         self.total_debt + self.total_idle
     }
 
+    // funds backing the regular shares, all of them unless the vault is tranched
+    pub fn senior_funds(&self) -> u64 {
+        if self.is_tranched {
+            std::cmp::min(self.senior_assets, self.total_funds())
+        } else {
+            self.total_funds()
+        }
+    }
+
+    // funds backing the junior shares, without the profit that is still locked
+    pub fn junior_funds_at(&self, timestamp: u64) -> u64 {
+        self.total_funds() - self.senior_funds() - self.junior_locked_profit_at(timestamp)
+    }
+
+    pub fn junior_locked_profit_at(&self, timestamp: u64) -> u64 {
+        let elapsed = timestamp.saturating_sub(self.last_tranche_update);
+        if !self.is_tranched || elapsed >= self.profit_max_unlock_time {
+            return 0;
+        }
+
+        let locked = (self.junior_locked_profit as u128
+            * (self.profit_max_unlock_time - elapsed) as u128
+            / self.profit_max_unlock_time as u128) as u64;
+        // losses booked outside the waterfall can leave less than the locked amount
+        std::cmp::min(locked, self.total_funds() - self.senior_funds())
+    }
+
     pub fn unlocked_shares(&self) -> Result<u64> {
         let curr_timestamp = Clock::get()?.unix_timestamp as u64;
         let mut curr_unlocked_shares = 0;
@@ -179,18 +538,48 @@ impl Vault {
         self.total_shares - self.unlocked_shares().unwrap()
     }
 
+    // profit shares that haven't been unlocked yet
+    pub fn locked_shares(&self) -> Result<u64> {
+        let curr_timestamp = Clock::get()?.unix_timestamp as u64;
+
+        if self.full_profit_unlock_date <= curr_timestamp {
+            return Ok(0);
+        }
+
+        Ok((self.profit_unlocking_rate * (self.full_profit_unlock_date - curr_timestamp)) / MAX_BPS_EXTENDED)
+    }
+
     /// Calculates the price of one share token with scaling to avoid overflow/underflow
     /// Returns the scaled share price (actual price = returned value / SCALING_FACTOR)
     pub fn get_share_price(&self) -> u64 {
-        const SCALING_FACTOR: u128 = 1_000_000; // 10^6 for 6 decimal places of precision
-        let scaled_one_share_token = ONE_SHARE_TOKEN * SCALING_FACTOR;
+        share_price(self.senior_funds(), self.total_shares())
+    }
 
-        if self.total_shares() == 0 {
-            // If there are no shares, return the scaling factor (representing 1.0)
-            scaled_one_share_token as u64
-        } else {
-            // Scale up total funds before division to maintain precision|
-            (scaled_one_share_token * self.total_funds() as u128 / self.total_shares() as u128) as u64
-        }
+    /// Share price once all the currently locked profit is unlocked
+    pub fn get_unlocked_share_price(&self) -> Result<u64> {
+        let shares = self.total_shares().saturating_sub(self.locked_shares()?);
+        Ok(share_price(self.senior_funds(), shares))
+    }
+
+    /// Same as `get_share_price` for the junior tranche shares
+    pub fn get_junior_share_price(&self, timestamp: u64) -> u64 {
+        share_price(self.junior_funds_at(timestamp), self.junior_total_shares)
+    }
+}
+
+fn current_timestamp() -> u64 {
+    Clock::get().unwrap().unix_timestamp as u64
+}
+
+fn share_price(funds: u64, shares: u64) -> u64 {
+    const SCALING_FACTOR: u128 = 1_000_000; // 10^6 for 6 decimal places of precision
+    let scaled_one_share_token = ONE_SHARE_TOKEN * SCALING_FACTOR;
+
+    if shares == 0 {
+        // If there are no shares, return the scaling factor (representing 1.0)
+        scaled_one_share_token as u64
+    } else {
+        // Scale up total funds before division to maintain precision|
+        (scaled_one_share_token * funds as u128 / shares as u128) as u64
     }
 }
\ No newline at end of file
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{DISCRIMINATOR_LEN, VAULT_VERSION};
+use crate::errors::ErrorCode;
+use crate::state::Vault;
+
+// snapshot of the unversioned `Vault` layout as deployed, vaults of this size are migrated by `migrate_vault`.
+// never edit it, the current layout must keep it as a prefix.
+// `repr(C)` pins the declared field order, plain `repr(packed)` lets rustc reorder fields and break the prefix.
+#[zero_copy(unsafe)]
+#[repr(C, packed)]
+#[derive(Default, Debug, InitSpace)]
+pub struct VaultV0 {
+    pub bump: [u8; 1],
+    pub index_buffer: [u8; 8],
+    pub shares_bump: [u8; 1],
+
+    pub key: Pubkey,
+
+    pub underlying_mint: Pubkey,
+    pub underlying_token_acc: Pubkey,
+    pub underlying_decimals: u8,
+
+    pub accountant: Pubkey,
+
+    pub total_debt: u64,
+    pub total_shares: u64,
+    pub minimum_total_idle: u64,
+    pub total_idle: u64,
+    pub deposit_limit: u64,
+    pub user_deposit_limit: u64,
+    pub min_user_deposit: u64,
+    pub strategies_amount: u64,
+
+    pub is_shutdown: bool,
+
+    pub kyc_verified_only: bool,
+    pub direct_deposit_enabled: bool,
+    pub whitelisted_only: bool,
+    pub direct_withdraw_enabled: bool,
+
+    pub profit_max_unlock_time: u64,
+    pub full_profit_unlock_date: u64,
+    pub profit_unlocking_rate: u64,
+    pub last_profit_update: u64,
+}
+
+// same guarantees `#[account(zero_copy(unsafe))]` gives `Vault`
+unsafe impl bytemuck::Pod for VaultV0 {}
+unsafe impl bytemuck::Zeroable for VaultV0 {}
+
+impl VaultV0 {
+    pub const LEN: usize = DISCRIMINATOR_LEN + VaultV0::INIT_SPACE;
+
+    // upgrades a v0 vault in place, `data` is the whole account already grown to `Vault::LEN`.
+    // everything past the v0 prefix is zeroed, which is the disabled default of every field added since.
+    pub fn migrate(data: &mut [u8]) -> Result<()> {
+        if data.len() != Vault::LEN {
+            return Err(ErrorCode::InvalidVaultLayout.into());
+        }
+
+        data[VaultV0::LEN..].fill(0);
+
+        let vault: &mut Vault = bytemuck::from_bytes_mut(&mut data[DISCRIMINATOR_LEN..]);
+        vault.version = VAULT_VERSION;
+
+        Ok(())
+    }
+}
+
+const _: () = assert!(core::mem::size_of::<VaultV0>() == VaultV0::INIT_SPACE);
+const _: () = assert!(core::mem::size_of::<Vault>() == Vault::INIT_SPACE);
+// fields added before versioning (84 bytes), then `version` and `reserved`.
+// later fields must shrink `reserved` instead of growing the account
+const _: () = assert!(Vault::INIT_SPACE == VaultV0::INIT_SPACE + 84 + 1 + 16 * 8);
@@ -1,26 +1,44 @@
 use anchor_lang::prelude::*;
  
-use accountant::utils::UncheckedAccountant;
+use accountant::utils::{load_fee_overrides, UncheckedAccountant};
 
-pub fn report(acccountant: &UncheckedAccount, profit: u64, loss: u64) -> Result<(u64,u64)>{
+pub fn report(
+    acccountant: &UncheckedAccount,
+    vault: &Pubkey,
+    strategy: &Pubkey,
+    fee_overrides: &[AccountInfo],
+    profit: u64,
+    loss: u64
+) -> Result<(u64,u64)>{
     let acc = acccountant.from_unchecked()?;
+    let overrides = load_fee_overrides(&acccountant.key(), fee_overrides)?;
     acc.report(
+        vault,
+        strategy,
+        &overrides,
         profit, 
         loss
     )
 }
 
-pub fn redeem(acccountant: &UncheckedAccount, amount: u64) -> Result<u64>{
+pub fn redeem(acccountant: &UncheckedAccount, vault: &Pubkey, fee_overrides: &[AccountInfo], amount: u64) -> Result<u64>{
     let acc = acccountant.from_unchecked()?;
-    acc.redeem(amount)
+    let overrides = load_fee_overrides(&acccountant.key(), fee_overrides)?;
+    acc.redeem(vault, &overrides, amount)
 }
 
-pub fn enter(acccountant: &UncheckedAccount, amount: u64) -> Result<u64>{
+pub fn enter(acccountant: &UncheckedAccount, vault: &Pubkey, fee_overrides: &[AccountInfo], amount: u64) -> Result<u64>{
     let acc = acccountant.from_unchecked()?;
-    acc.enter(amount)
+    let overrides = load_fee_overrides(&acccountant.key(), fee_overrides)?;
+    acc.enter(vault, &overrides, amount)
 }
 
 pub fn performance_fee(acccountant: &UncheckedAccount) -> Result<u64>{
     let acc = acccountant.from_unchecked()?;
-    Ok(acc.performance_fee())
-}
\ No newline at end of file
+    acc.performance_fee()
+}
+
+pub fn referral_fee(acccountant: &UncheckedAccount) -> Result<u64>{
+    let acc = acccountant.from_unchecked()?;
+    Ok(acc.referral_fee())
+}
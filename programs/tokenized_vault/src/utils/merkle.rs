@@ -0,0 +1,18 @@
+use anchor_lang::solana_program::keccak;
+use anchor_lang::prelude::*;
+
+pub fn whitelist_leaf(user: &Pubkey) -> [u8; 32] {
+    keccak::hashv(&[user.as_ref()]).to_bytes()
+}
+
+// pairs are hashed in sorted order so proofs don't carry a direction
+pub fn verify(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |hash, node| {
+        if hash <= *node {
+            keccak::hashv(&[&hash, node]).to_bytes()
+        } else {
+            keccak::hashv(&[node, &hash]).to_bytes()
+        }
+    });
+    computed == root
+}
@@ -1,11 +1,17 @@
 pub mod accountant;
+pub mod merkle;
 pub mod strategy;
+pub mod swap;
 pub mod token;
 pub mod unchecked;
+pub mod user_data;
 pub mod vault;
 
 pub use accountant::*;
+pub use merkle::*;
 pub use strategy::*;
+pub use swap::*;
 pub use token::*;
 pub use unchecked::*;
+pub use user_data::*;
 pub use vault::*;
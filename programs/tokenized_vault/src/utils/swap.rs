@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+use strategy::constants::{AMOUNT_SPECIFIED_IS_INPUT, NO_EXPLICIT_SQRT_PRICE_LIMIT};
+
+/// Accounts of a whirlpool `swap_v2`, tokens A and B can be on different token programs.
+pub struct WhirlpoolSwap<'info> {
+    pub whirlpool_program: AccountInfo<'info>,
+    pub whirlpool: AccountInfo<'info>,
+    pub token_program_a: AccountInfo<'info>,
+    pub token_program_b: AccountInfo<'info>,
+    pub memo_program: AccountInfo<'info>,
+    pub token_authority: AccountInfo<'info>,
+    pub token_mint_a: AccountInfo<'info>,
+    pub token_mint_b: AccountInfo<'info>,
+    pub token_owner_account_a: AccountInfo<'info>,
+    pub token_vault_a: AccountInfo<'info>,
+    pub token_owner_account_b: AccountInfo<'info>,
+    pub token_vault_b: AccountInfo<'info>,
+    pub tick_array_0: AccountInfo<'info>,
+    pub tick_array_1: AccountInfo<'info>,
+    pub tick_array_2: AccountInfo<'info>,
+    pub oracle: AccountInfo<'info>,
+}
+
+impl<'info> WhirlpoolSwap<'info> {
+    /// Swaps exactly `amount` in, failing when less than `min_out` comes out.
+    /// `a_to_b` is the pool direction: true sells token A for token B.
+    pub fn swap_exact_in(&self, amount: u64, min_out: u64, a_to_b: bool) -> Result<()> {
+        let cpi_accounts = whirlpool_cpi::cpi::accounts::SwapV2 {
+            token_program_a: self.token_program_a.clone(),
+            token_program_b: self.token_program_b.clone(),
+            memo_program: self.memo_program.clone(),
+            token_authority: self.token_authority.clone(),
+            whirlpool: self.whirlpool.clone(),
+            token_mint_a: self.token_mint_a.clone(),
+            token_mint_b: self.token_mint_b.clone(),
+            token_owner_account_a: self.token_owner_account_a.clone(),
+            token_vault_a: self.token_vault_a.clone(),
+            token_owner_account_b: self.token_owner_account_b.clone(),
+            token_vault_b: self.token_vault_b.clone(),
+            tick_array_0: self.tick_array_0.clone(),
+            tick_array_1: self.tick_array_1.clone(),
+            tick_array_2: self.tick_array_2.clone(),
+            oracle: self.oracle.clone(),
+        };
+
+        whirlpool_cpi::cpi::swap_v2(
+            CpiContext::new(self.whirlpool_program.clone(), cpi_accounts),
+            amount,
+            min_out,
+            NO_EXPLICIT_SQRT_PRICE_LIMIT,
+            AMOUNT_SPECIFIED_IS_INPUT,
+            a_to_b,
+            None,
+        )
+    }
+}
@@ -1,10 +1,33 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token::{self, MintTo, Burn},
-    token_2022,
-    token_interface::Mint,
+    token,
+    token_2022::{
+        self,
+        spl_token_2022::{
+            extension::{
+                transfer_fee::TransferFeeConfig, BaseStateWithExtensions, ExtensionType,
+                StateWithExtensions,
+            },
+            state::Mint as MintState,
+        },
+    },
+    token_interface::{self, Burn, Mint, MintTo, TokenAccount},
 };
 
+use crate::errors::ErrorCode;
+
+// extensions that leave transfer amounts measurable and the vault's balances under its control
+const SUPPORTED_MINT_EXTENSIONS: [ExtensionType; 8] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::MintCloseAuthority,
+    ExtensionType::MetadataPointer,
+    ExtensionType::TokenMetadata,
+    ExtensionType::GroupPointer,
+    ExtensionType::TokenGroup,
+    ExtensionType::GroupMemberPointer,
+    ExtensionType::TokenGroupMember,
+];
+
 pub fn get_athority<'a>(
     token_program: AccountInfo<'a>
 ) -> Result<Pubkey> {
@@ -88,6 +111,53 @@ pub fn transfer<'a>(
     }
 }
 
+/// Transfers `amount` and returns what actually arrived in `to`, which is less for transfer-fee mints.
+pub fn transfer_measured<'a>(
+    token_program: AccountInfo<'a>,
+    from: AccountInfo<'a>,
+    to: &mut InterfaceAccount<'a, TokenAccount>,
+    authority: AccountInfo<'a>,
+    mint: &InterfaceAccount<'a, Mint>,
+    amount: u64,
+) -> Result<u64> {
+    let balance_before = to.amount;
+    transfer(token_program, from, to.to_account_info(), authority, mint, amount)?;
+    to.reload()?;
+    Ok(to.amount - balance_before)
+}
+
+/// Fee a transfer-fee mint withholds when `amount` is moved during `epoch`, zero for other mints.
+pub fn transfer_fee(mint: &AccountInfo, epoch: u64, amount: u64) -> Result<u64> {
+    if mint.owner != &token_2022::ID {
+        return Ok(0);
+    }
+
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+    match state.get_extension::<TransferFeeConfig>() {
+        Ok(config) => Ok(config
+            .calculate_epoch_fee(epoch, amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?),
+        Err(_) => Ok(0),
+    }
+}
+
+pub fn validate_mint_extensions(mint: &AccountInfo) -> Result<()> {
+    if mint.owner != &token_2022::ID {
+        return Ok(());
+    }
+
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<MintState>::unpack(&data)?;
+    for extension in state.get_extension_types()? {
+        if !SUPPORTED_MINT_EXTENSIONS.contains(&extension) {
+            return Err(ErrorCode::UnsupportedMintExtension.into());
+        }
+    }
+
+    Ok(())
+}
+
 pub fn mint_to<'a>(
     token_program: AccountInfo<'a>,
     mint: AccountInfo<'a>,
@@ -96,7 +166,7 @@ pub fn mint_to<'a>(
     amount: u64,
     seeds: &[&[u8]],
 ) -> Result<()> {
-    token::mint_to(
+    token_interface::mint_to(
         CpiContext::new_with_signer(
             token_program,
             MintTo {
@@ -117,7 +187,7 @@ pub fn burn<'a>(
     authority: AccountInfo<'a>,
     amount: u64
 ) -> Result<()> {
-    token::burn(
+    token_interface::burn(
         CpiContext::new(
             token_program,
             Burn {
@@ -138,7 +208,7 @@ pub fn burn_with_signer<'a>(
     amount: u64,
     seeds: &[&[u8]],
 ) -> Result<()> {
-    token::burn(
+    token_interface::burn(
         CpiContext::new_with_signer(
             token_program,
             Burn {
@@ -152,3 +222,40 @@ pub fn burn_with_signer<'a>(
     )
 }
 
+
+// hooked Token-2022 share mints are moved by burning and re-minting,
+// so the vault's own share movements never go through the transfer hook
+pub fn transfer_shares<'a>(
+    token_program: AccountInfo<'a>,
+    from: AccountInfo<'a>,
+    to: AccountInfo<'a>,
+    authority: AccountInfo<'a>,
+    mint: &InterfaceAccount<'a, Mint>,
+    amount: u64,
+    mint_seeds: &[&[u8]],
+) -> Result<()> {
+    if token_program.key() != token_2022::ID {
+        return transfer(token_program, from, to, authority, mint, amount);
+    }
+
+    burn(token_program.clone(), mint.to_account_info(), from, authority, amount)?;
+    mint_to(token_program, mint.to_account_info(), to, mint.to_account_info(), amount, mint_seeds)
+}
+
+pub fn transfer_shares_with_signer<'a>(
+    token_program: AccountInfo<'a>,
+    from: AccountInfo<'a>,
+    to: AccountInfo<'a>,
+    authority: AccountInfo<'a>,
+    mint: &InterfaceAccount<'a, Mint>,
+    amount: u64,
+    seeds: &[&[u8]],
+    mint_seeds: &[&[u8]],
+) -> Result<()> {
+    if token_program.key() != token_2022::ID {
+        return transfer_with_signer(token_program, from, to, authority, mint, amount, seeds);
+    }
+
+    burn_with_signer(token_program.clone(), mint.to_account_info(), from, authority, amount, seeds)?;
+    mint_to(token_program, mint.to_account_info(), to, mint.to_account_info(), amount, mint_seeds)
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
 
 use crate::errors::ErrorCode;
 
@@ -12,7 +13,11 @@ impl<'a> AccountInfoExt for AccountInfo<'a> {
         where T: AnchorDeserialize 
     {
         let data = self.try_borrow_data()?;
-        Ok(T::try_from_slice(&data[8..]).unwrap())
+        if data.len() < 8 {
+            return Err(ErrorCode::SerializationError.into());
+        }
+        // like anchor's `Account`, trailing bytes from a larger allocation are fine
+        T::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::SerializationError.into())
     }
 
     fn serialize<T>(&self, account: T) -> Result<()> 
@@ -23,4 +28,93 @@ impl<'a> AccountInfoExt for AccountInfo<'a> {
             writer.write_all(&vec).map_err(|_| ErrorCode::SerializationError.into())
         })
     }
-}
\ No newline at end of file
+}
+// anchor's `init` for accounts only some paths create, `seeds` must include the bump
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let signer_seeds: &[&[&[u8]]] = &[seeds];
+
+    if account.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                system_program::CreateAccount {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+                signer_seeds,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    // the address can be prefunded by anyone, `create_account` would fail on it
+    let rent_due = rent.saturating_sub(account.lamports());
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: account.clone() },
+            signer_seeds,
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: account.clone() },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )
+}
+
+// grows a program owned account in place, the payer covers the extra rent
+pub fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<()> {
+    let rent_due = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+
+    if rent_due > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            rent_due,
+        )?;
+    }
+
+    account.realloc(space, true)?;
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::constants::{DISCRIMINATOR_LEN, USER_DATA_SEED, USER_DATA_VERSION};
+use crate::errors::ErrorCode;
+use crate::state::{UserData, UserDataV0};
+use crate::utils::unchecked::*;
+
+// reads the current layout or any older one, the fields added since come back zeroed
+pub fn load_user_data(user_data: &AccountInfo) -> Result<UserData> {
+    if user_data.data_is_empty() || *user_data.owner != crate::ID {
+        return Err(ErrorCode::InvalidUserData.into());
+    }
+
+    let data = user_data.try_borrow_data()?;
+    if data.len() < DISCRIMINATOR_LEN || data[..DISCRIMINATOR_LEN] != UserData::DISCRIMINATOR {
+        return Err(ErrorCode::InvalidUserData.into());
+    }
+
+    let body = &data[DISCRIMINATOR_LEN..];
+    let record = match data.len() {
+        UserData::LEN => UserData::try_from_slice(body),
+        UserDataV0::LEN => UserDataV0::try_from_slice(body).map(Into::into),
+        _ => return Err(ErrorCode::InvalidUserData.into()),
+    };
+
+    record.map_err(|_| ErrorCode::InvalidUserData.into())
+}
+
+// the account is created on the first deposit or whitelisting of `user`
+pub fn load_or_create_user_data<'info>(
+    user_data: &AccountInfo<'info>,
+    vault_key: &Pubkey,
+    user: &Pubkey,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<UserData> {
+    if !user_data.data_is_empty() {
+        return load_user_data(user_data);
+    }
+
+    let (_, bump) = Pubkey::find_program_address(
+        &[USER_DATA_SEED.as_bytes(), vault_key.as_ref(), user.as_ref()],
+        &crate::ID,
+    );
+    create_pda_account(
+        user_data,
+        payer,
+        system_program,
+        UserData::LEN,
+        &[USER_DATA_SEED.as_bytes(), vault_key.as_ref(), user.as_ref(), &[bump]],
+    )?;
+
+    Ok(UserData::default())
+}
+
+// older layouts are grown to the current one before writing, the payer covers the extra rent
+pub fn save_user_data<'info>(
+    user_data: &AccountInfo<'info>,
+    record: &mut UserData,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<()> {
+    if user_data.data_len() < UserData::LEN {
+        grow_account(user_data, payer, system_program, UserData::LEN)?;
+    }
+
+    record.version = USER_DATA_VERSION;
+    record.try_serialize(&mut &mut user_data.try_borrow_mut_data()?[..])
+}
@@ -1,14 +1,17 @@
-use access_control::state::UserRole;
+use access_control::state::{RoleExpiry, UserRole};
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
 
+use crate::constants::{FEE_BPS, REFERRAL_SEED};
 use crate::errors::ErrorCode;
-use crate::state::{UserData, Vault};
-use crate::utils::unchecked::*;
+use crate::state::{PriceFeed, PriceHistory, Referral, UserData, Vault};
+use crate::utils::{accountant, merkle, unchecked::*, user_data::load_user_data};
 
 pub fn validate_deposit<'info>(
     vault_loader: &AccountLoader<'info, Vault>,
     kyc_verified: &AccountInfo<'info>,
-    user_data: &Account<'info, UserData>,
+    kyc_expiry: &AccountInfo<'info>,
+    user_data: &UserData,
     is_direct: bool,
     amount: u64
 ) -> Result<()> {
@@ -39,15 +42,200 @@ pub fn validate_deposit<'info>(
         return Err(ErrorCode::ExceedDepositLimit.into());
     }
 
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    // expired attestations are treated as absent even before `expire_role` revokes them
     if vault.kyc_verified_only {
-        if kyc_verified.data_is_empty() || !kyc_verified.deserialize::<UserRole>()?.has_role {
+        if kyc_verified.data_is_empty()
+            || !kyc_verified.deserialize::<UserRole>()?.has_role
+            || is_role_expired(kyc_expiry, timestamp)?
+        {
             return Err(ErrorCode::KYCRequired.into());
         }
     }
 
-    if vault.whitelisted_only && !user_data.whitelisted {
+    if vault.whitelisted_only && !user_data.is_whitelisted(vault.whitelist_root, timestamp) {
         return Err(ErrorCode::NotWhitelisted.into());
     }
 
     Ok(())
 }
+
+pub fn apply_whitelist_proof<'info>(
+    vault_loader: &AccountLoader<'info, Vault>,
+    user_data: &mut UserData,
+    user: &Pubkey,
+    proof: Option<Vec<[u8; 32]>>,
+) -> Result<()> {
+    let proof = match proof {
+        Some(proof) => proof,
+        None => return Ok(()),
+    };
+
+    let root = vault_loader.load()?.whitelist_root;
+    if root == [0; 32] || !merkle::verify(&proof, root, merkle::whitelist_leaf(user)) {
+        return Err(ErrorCode::InvalidWhitelistProof.into());
+    }
+
+    user_data.whitelisted = true;
+    user_data.whitelist_root = root;
+    user_data.whitelist_expires_at = 0;
+    Ok(())
+}
+
+pub fn validate_not_paused<'info>(vault_loader: &AccountLoader<'info, Vault>, flag: u8) -> Result<()> {
+    if vault_loader.load()?.is_paused(flag) {
+        return Err(ErrorCode::Paused.into());
+    }
+    Ok(())
+}
+
+// sanctioned users can't move their position until the entry is revoked or expires
+pub fn validate_not_sanctioned<'info>(sanctioned: &AccountInfo<'info>) -> Result<()> {
+    if is_sanctioned(sanctioned)? {
+        return Err(ErrorCode::Sanctioned.into());
+    }
+    Ok(())
+}
+
+pub fn is_sanctioned<'info>(sanctioned: &AccountInfo<'info>) -> Result<bool> {
+    if sanctioned.data_is_empty() {
+        return Ok(false);
+    }
+    // an expired entry keeps freezing the position until anyone runs `expire_role` on it
+    Ok(sanctioned.deserialize::<UserRole>()?.has_role)
+}
+
+// roles granted without an expiry have no `RoleExpiry` account
+pub fn is_role_expired<'info>(role_expiry: &AccountInfo<'info>, timestamp: i64) -> Result<bool> {
+    if role_expiry.data_is_empty() {
+        return Ok(false);
+    }
+    Ok(role_expiry.deserialize::<RoleExpiry>()?.is_expired(timestamp))
+}
+
+// the price history is optional, vaults without one just skip it
+pub fn record_share_price<'info>(
+    vault_loader: &AccountLoader<'info, Vault>,
+    price_history: &AccountInfo<'info>,
+) -> Result<()> {
+    if price_history.data_is_empty() {
+        return Ok(());
+    }
+
+    let share_price = vault_loader.load()?.get_share_price();
+    let mut history = price_history.deserialize::<PriceHistory>()?;
+
+    if history.record(share_price, Clock::get()?.unix_timestamp) {
+        price_history.serialize(history)?;
+    }
+    Ok(())
+}
+
+// vaults without a price feed skip the update
+pub fn update_price_feed<'info>(
+    vault_loader: &AccountLoader<'info, Vault>,
+    price_feed: &AccountInfo<'info>,
+) -> Result<()> {
+    if price_feed.data_is_empty() {
+        return Ok(());
+    }
+
+    let mut feed = price_feed.deserialize::<PriceFeed>()?;
+    feed.update(&*vault_loader.load()?)?;
+    price_feed.serialize(feed)
+}
+
+// the accountant token account is optional, fees fall back to shares without it
+pub fn fees_in_underlying<'info>(
+    vault_loader: &AccountLoader<'info, Vault>,
+    accountant_token_account: &AccountInfo<'info>,
+) -> Result<bool> {
+    Ok(vault_loader.load()?.fees_in_underlying && !accountant_token_account.data_is_empty())
+}
+
+// returns the early-exit fee in bps, 0 once the deposit lock has elapsed. shares held
+// without a `UserData` may have just been moved out of a locked account, so they count as locked
+pub fn early_exit_fee<'info>(
+    vault_loader: &AccountLoader<'info, Vault>,
+    user_data: &AccountInfo<'info>,
+) -> Result<u64> {
+    let vault = vault_loader.load()?;
+    if vault.min_deposit_lock_seconds == 0 {
+        return Ok(0);
+    }
+
+    if !user_data.data_is_empty() {
+        let last_deposit_ts = load_user_data(user_data)?.last_deposit_ts;
+        if Clock::get()?.unix_timestamp >= last_deposit_ts + vault.min_deposit_lock_seconds as i64 {
+            return Ok(0);
+        }
+    }
+
+    if vault.early_exit_fee == 0 {
+        return Err(ErrorCode::DepositLocked.into());
+    }
+
+    Ok(vault.early_exit_fee)
+}
+
+// a referrer is only recorded when the receiver deposits for themselves and names one,
+// so paying into someone else's account can't pick their referrer
+pub fn load_referral<'info>(
+    referral: &UncheckedAccount<'info>,
+    vault_key: &Pubkey,
+    user: &Signer<'info>,
+    receiver: &Pubkey,
+    referrer: Option<Pubkey>,
+    system_program: &Program<'info, System>,
+) -> Result<Referral> {
+    if !referral.data_is_empty() {
+        return referral.deserialize::<Referral>();
+    }
+
+    let mut record = Referral::default();
+    if user.key() == *receiver {
+        record.set_referrer(receiver, referrer);
+    }
+    if record.referrer().is_none() {
+        return Ok(record);
+    }
+
+    let (_, bump) = Pubkey::find_program_address(
+        &[REFERRAL_SEED.as_bytes(), vault_key.as_ref(), receiver.as_ref()],
+        &crate::ID,
+    );
+    create_pda_account(
+        referral,
+        user,
+        system_program,
+        Referral::LEN,
+        &[REFERRAL_SEED.as_bytes(), vault_key.as_ref(), receiver.as_ref(), &[bump]],
+    )?;
+    record.try_serialize(&mut &mut referral.try_borrow_mut_data()?[..])?;
+
+    Ok(record)
+}
+
+// returns the referrer's cut of the entry fee, 0 when the depositor has no referrer
+pub fn referral_cut<'info>(
+    accountant: &UncheckedAccount<'info>,
+    referral: &Referral,
+    referrer_shares_account: &Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+    enter_fee: u64,
+) -> Result<u64> {
+    let referrer = match referral.referrer() {
+        Some(referrer) => referrer,
+        None => return Ok(0),
+    };
+
+    let referral_fee = accountant::referral_fee(accountant)?;
+    if enter_fee == 0 || referral_fee == 0 {
+        return Ok(0);
+    }
+
+    match referrer_shares_account {
+        Some(account) if account.owner == referrer => Ok(enter_fee * referral_fee / FEE_BPS),
+        _ => Err(ErrorCode::InvalidReferrerAccount.into()),
+    }
+}
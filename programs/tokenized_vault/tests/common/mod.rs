@@ -0,0 +1,24 @@
+use std::sync::Once;
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{entrypoint::SUCCESS, program_stubs};
+
+/// Timestamp `Clock::get` returns once `set_clock` ran.
+pub const NOW: i64 = 1_000_000;
+
+struct ClockStub;
+
+impl program_stubs::SyscallStubs for ClockStub {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock { unix_timestamp: NOW, ..Default::default() };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        SUCCESS
+    }
+}
+
+pub fn set_clock() {
+    static STUBS: Once = Once::new();
+    STUBS.call_once(|| {
+        program_stubs::set_syscall_stubs(Box::new(ClockStub));
+    });
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use common::{set_clock, NOW};
+use tokenized_vault::errors::ErrorCode;
+use tokenized_vault::state::{UserData, Vault};
+use tokenized_vault::utils::early_exit_fee;
+
+const LOCK: u64 = 3_600;
+
+fn vault_account(early_exit_fee: u64) -> Vec<u8> {
+    let vault = Vault {
+        min_deposit_lock_seconds: LOCK,
+        early_exit_fee,
+        ..Default::default()
+    };
+    let mut data = Vault::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(&vault));
+    data
+}
+
+fn user_data_account(last_deposit_ts: i64) -> Vec<u8> {
+    let user_data = UserData { last_deposit_ts, ..Default::default() };
+    let mut data = Vec::new();
+    user_data.try_serialize(&mut data).unwrap();
+    data
+}
+
+fn fee_for(vault_data: &mut [u8], user_data: &mut [u8]) -> Result<u64> {
+    set_clock();
+
+    let (vault_key, user_key) = (Pubkey::new_unique(), Pubkey::new_unique());
+    let (mut vault_lamports, mut user_lamports) = (1_000_000, 1_000_000);
+    let vault_info = AccountInfo::new(
+        &vault_key, false, true, &mut vault_lamports, vault_data, &tokenized_vault::ID, false, 0,
+    );
+    let user_info = AccountInfo::new(
+        &user_key, false, true, &mut user_lamports, user_data, &tokenized_vault::ID, false, 0,
+    );
+    let vault = AccountLoader::<Vault>::try_from(&vault_info)?;
+
+    early_exit_fee(&vault, &user_info)
+}
+
+#[test]
+fn no_fee_once_the_lock_elapsed() {
+    let mut vault = vault_account(0);
+    let mut user_data = user_data_account(NOW - LOCK as i64);
+
+    assert_eq!(fee_for(&mut vault, &mut user_data).unwrap(), 0);
+}
+
+#[test]
+fn early_exit_pays_the_fee() {
+    let mut vault = vault_account(200);
+    let mut user_data = user_data_account(NOW - 60);
+
+    assert_eq!(fee_for(&mut vault, &mut user_data).unwrap(), 200);
+}
+
+#[test]
+fn early_exit_without_fee_is_rejected() {
+    let mut vault = vault_account(0);
+    let mut user_data = user_data_account(NOW - 60);
+
+    let err = fee_for(&mut vault, &mut user_data).unwrap_err();
+
+    assert_eq!(err, ErrorCode::DepositLocked.into());
+}
+
+#[test]
+fn shares_without_user_data_stay_locked() {
+    let mut vault = vault_account(0);
+
+    let err = fee_for(&mut vault, &mut []).unwrap_err();
+
+    assert_eq!(err, ErrorCode::DepositLocked.into());
+}
+
+#[test]
+fn shares_without_user_data_pay_the_early_exit_fee() {
+    let mut vault = vault_account(200);
+
+    assert_eq!(fee_for(&mut vault, &mut []).unwrap(), 200);
+}
+
+#[test]
+fn deposits_by_someone_else_do_not_restart_the_lock() {
+    let mut user_data = UserData::default();
+
+    // the first deposit starts the lock whoever pays it
+    user_data.handle_deposit(1_000, 1_000, 10, false);
+    assert_eq!(user_data.last_deposit_ts, 10);
+
+    user_data.handle_deposit(1, 1, 20, false);
+    assert_eq!(user_data.last_deposit_ts, 10);
+
+    user_data.handle_deposit(1, 1, 30, true);
+    assert_eq!(user_data.last_deposit_ts, 30);
+}
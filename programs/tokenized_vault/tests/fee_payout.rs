@@ -0,0 +1,39 @@
+mod common;
+
+use common::set_clock;
+use tokenized_vault::state::Vault;
+
+// 1000 idle backing 1000 shares, 600 of it has to stay in the vault
+fn vault() -> Vault {
+    set_clock();
+    Vault {
+        total_idle: 1_000,
+        total_shares: 1_000,
+        minimum_total_idle: 600,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn fees_only_use_idle_above_the_minimum() {
+    let vault = vault();
+
+    assert_eq!(vault.idle_for_fees(0), 400);
+    assert_eq!(vault.idle_for_fees(300), 100);
+    assert_eq!(vault.idle_for_fees(500), 0);
+}
+
+#[test]
+fn fee_shares_covered_by_idle_are_paid_in_underlying() {
+    let vault = vault();
+
+    assert_eq!(vault.fee_shares_payable_in_underlying(250, vault.idle_for_fees(0)), 250);
+}
+
+#[test]
+fn fee_shares_beyond_idle_stay_shares() {
+    let vault = vault();
+
+    assert_eq!(vault.fee_shares_payable_in_underlying(500, vault.idle_for_fees(0)), 400);
+    assert_eq!(vault.fee_shares_payable_in_underlying(500, vault.idle_for_fees(1_000)), 0);
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use kyc_hook::instructions::{
+    is_whitelisted_at, USER_DATA_WHITELISTED_OFFSET, USER_DATA_WHITELIST_EXPIRES_AT_OFFSET,
+    USER_DATA_WHITELIST_ROOT_OFFSET,
+};
+use tokenized_vault::state::{UserData, UserDataV0};
+
+const ROOT: [u8; 32] = [7; 32];
+
+fn current_account(whitelisted: bool, whitelist_expires_at: i64) -> Vec<u8> {
+    account_with_root(whitelisted, ROOT, whitelist_expires_at)
+}
+
+fn account_with_root(
+    whitelisted: bool,
+    whitelist_root: [u8; 32],
+    whitelist_expires_at: i64,
+) -> Vec<u8> {
+    let user_data = UserData {
+        deposited: 500,
+        whitelisted,
+        shares: 400,
+        whitelist_root,
+        whitelist_expires_at,
+        ..Default::default()
+    };
+    let mut data = Vec::new();
+    user_data.try_serialize(&mut data).unwrap();
+    assert_eq!(data.len(), UserData::LEN);
+    data
+}
+
+fn v0_account(whitelisted: bool) -> Vec<u8> {
+    let mut data = UserData::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&500u64.to_le_bytes());
+    data.push(whitelisted as u8);
+    assert_eq!(data.len(), UserDataV0::LEN);
+    data
+}
+
+#[test]
+fn hook_offsets_match_user_data_layout() {
+    let data = current_account(true, 0x0102_0304_0506_0708);
+
+    assert_eq!(data[USER_DATA_WHITELISTED_OFFSET], 1);
+    assert_eq!(
+        data[USER_DATA_WHITELIST_ROOT_OFFSET..USER_DATA_WHITELIST_ROOT_OFFSET + 32],
+        ROOT
+    );
+    assert_eq!(
+        data[USER_DATA_WHITELIST_EXPIRES_AT_OFFSET..USER_DATA_WHITELIST_EXPIRES_AT_OFFSET + 8],
+        0x0102_0304_0506_0708i64.to_le_bytes()
+    );
+}
+
+#[test]
+fn reads_current_layout() {
+    assert!(is_whitelisted_at(&current_account(true, 0), ROOT, 100));
+    assert!(is_whitelisted_at(&current_account(true, 200), ROOT, 100));
+    assert!(!is_whitelisted_at(&current_account(false, 0), ROOT, 100));
+}
+
+#[test]
+fn rejects_expired_whitelisting() {
+    assert!(!is_whitelisted_at(&current_account(true, 100), ROOT, 100));
+    assert!(!is_whitelisted_at(&current_account(true, 50), ROOT, 100));
+}
+
+#[test]
+fn rejects_proofs_against_a_replaced_root() {
+    assert!(!is_whitelisted_at(&current_account(true, 0), [8; 32], 100));
+}
+
+#[test]
+fn admin_whitelisting_holds_under_any_root() {
+    assert!(is_whitelisted_at(
+        &account_with_root(true, [0; 32], 0),
+        [8; 32],
+        100
+    ));
+}
+
+#[test]
+fn reads_legacy_layout() {
+    assert!(is_whitelisted_at(&v0_account(true), ROOT, 100));
+    assert!(!is_whitelisted_at(&v0_account(false), ROOT, 100));
+}
+
+#[test]
+fn rejects_truncated_data() {
+    assert!(!is_whitelisted_at(&UserData::DISCRIMINATOR, ROOT, 100));
+}
+
+#[test]
+fn hook_reads_accounts_of_this_vault_program() {
+    assert_eq!(
+        kyc_hook::constants::TOKENIZED_VAULT_PROGRAM_ID,
+        tokenized_vault::ID
+    );
+}
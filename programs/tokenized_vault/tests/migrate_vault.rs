@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use tokenized_vault::constants::VAULT_VERSION;
+use tokenized_vault::errors::ErrorCode;
+use tokenized_vault::state::{Vault, VaultV0};
+
+fn v0_vault() -> VaultV0 {
+    VaultV0 {
+        bump: [254],
+        index_buffer: 7u64.to_le_bytes(),
+        shares_bump: [253],
+        key: Pubkey::new_from_array([1; 32]),
+        underlying_mint: Pubkey::new_from_array([2; 32]),
+        underlying_token_acc: Pubkey::new_from_array([3; 32]),
+        underlying_decimals: 9,
+        accountant: Pubkey::new_from_array([4; 32]),
+        total_debt: 1_000,
+        total_shares: 2_000,
+        minimum_total_idle: 3_000,
+        total_idle: 4_000,
+        deposit_limit: 5_000,
+        user_deposit_limit: 6_000,
+        min_user_deposit: 7_000,
+        strategies_amount: 2,
+        is_shutdown: false,
+        kyc_verified_only: true,
+        direct_deposit_enabled: true,
+        whitelisted_only: false,
+        direct_withdraw_enabled: true,
+        profit_max_unlock_time: 86_400,
+        full_profit_unlock_date: 1_700_086_400,
+        profit_unlocking_rate: 123_456,
+        last_profit_update: 1_700_000_000,
+    }
+}
+
+fn v0_account(vault: &VaultV0) -> Vec<u8> {
+    let mut data = Vault::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(vault));
+    assert_eq!(data.len(), VaultV0::LEN);
+    data
+}
+
+#[test]
+fn migrates_baseline_sized_vault() {
+    let old = v0_vault();
+    let mut data = v0_account(&old);
+    // realloc may hand back dirty memory, the migration must not rely on it
+    data.resize(Vault::LEN, 0xAA);
+
+    VaultV0::migrate(&mut data).unwrap();
+
+    assert_eq!(data[..8], Vault::DISCRIMINATOR);
+    let vault: &Vault = bytemuck::from_bytes(&data[8..]);
+
+    assert_eq!(vault.bump, old.bump);
+    assert_eq!(vault.index_buffer, old.index_buffer);
+    assert_eq!(vault.shares_bump, old.shares_bump);
+    assert_eq!({ vault.key }, { old.key });
+    assert_eq!({ vault.underlying_mint }, { old.underlying_mint });
+    assert_eq!({ vault.underlying_token_acc }, { old.underlying_token_acc });
+    assert_eq!(vault.underlying_decimals, old.underlying_decimals);
+    assert_eq!({ vault.accountant }, { old.accountant });
+    assert_eq!({ vault.total_debt }, { old.total_debt });
+    assert_eq!({ vault.total_shares }, { old.total_shares });
+    assert_eq!({ vault.minimum_total_idle }, { old.minimum_total_idle });
+    assert_eq!({ vault.total_idle }, { old.total_idle });
+    assert_eq!({ vault.deposit_limit }, { old.deposit_limit });
+    assert_eq!({ vault.user_deposit_limit }, { old.user_deposit_limit });
+    assert_eq!({ vault.min_user_deposit }, { old.min_user_deposit });
+    assert_eq!({ vault.strategies_amount }, { old.strategies_amount });
+    assert_eq!(vault.is_shutdown, old.is_shutdown);
+    assert_eq!(vault.kyc_verified_only, old.kyc_verified_only);
+    assert_eq!(vault.direct_deposit_enabled, old.direct_deposit_enabled);
+    assert_eq!(vault.whitelisted_only, old.whitelisted_only);
+    assert_eq!(vault.direct_withdraw_enabled, old.direct_withdraw_enabled);
+    assert_eq!({ vault.profit_max_unlock_time }, { old.profit_max_unlock_time });
+    assert_eq!({ vault.full_profit_unlock_date }, { old.full_profit_unlock_date });
+    assert_eq!({ vault.profit_unlocking_rate }, { old.profit_unlocking_rate });
+    assert_eq!({ vault.last_profit_update }, { old.last_profit_update });
+
+    // everything added since the baseline starts disabled
+    assert!(!vault.fees_in_underlying);
+    assert!(!vault.is_tranched);
+    assert_eq!(vault.junior_shares_bump, [0]);
+    assert_eq!({ vault.junior_total_shares }, 0);
+    assert_eq!({ vault.senior_assets }, 0);
+    assert_eq!({ vault.senior_target_rate }, 0);
+    assert_eq!({ vault.last_tranche_update }, 0);
+    assert_eq!({ vault.min_deposit_lock_seconds }, 0);
+    assert_eq!({ vault.early_exit_fee }, 0);
+    assert_eq!(vault.whitelist_root, [0; 32]);
+    assert_eq!(vault.pause_flags, 0);
+    assert_eq!({ vault.audit_pause_threshold }, 0);
+    assert_eq!({ vault.junior_locked_profit }, 0);
+    assert_eq!({ vault.senior_accrued_profit }, 0);
+    assert_eq!({ vault.last_senior_accrual }, 0);
+    assert_eq!({ vault.reserved }, [0; 12]);
+
+    assert_eq!(vault.version, VAULT_VERSION);
+}
+
+// the unversioned account holding the values of `v0_vault`, encoded from the field list of that
+// program's IDL rather than from a host build, which may reorder the plain `repr(packed)` fields
+const BASELINE_DUMP: &[u8] = include_bytes!("fixtures/vault_v0.bin");
+
+#[test]
+fn reads_baseline_account_dump() {
+    assert_eq!(BASELINE_DUMP.len(), VaultV0::LEN);
+    assert_eq!(BASELINE_DUMP[..8], Vault::DISCRIMINATOR);
+
+    let vault: &VaultV0 = bytemuck::from_bytes(&BASELINE_DUMP[8..]);
+    assert_eq!(bytemuck::bytes_of(vault), bytemuck::bytes_of(&v0_vault()));
+    assert_eq!({ vault.accountant }, Pubkey::new_from_array([4; 32]));
+    assert_eq!({ vault.last_profit_update }, 1_700_000_000);
+
+    let mut data = BASELINE_DUMP.to_vec();
+    data.resize(Vault::LEN, 0);
+    VaultV0::migrate(&mut data).unwrap();
+
+    let migrated: &Vault = bytemuck::from_bytes(&data[8..]);
+    assert_eq!({ migrated.total_idle }, 4_000);
+    assert_eq!(migrated.version, VAULT_VERSION);
+}
+
+#[test]
+fn rejects_buffer_not_grown_to_current_layout() {
+    let mut data = v0_account(&v0_vault());
+    let err = VaultV0::migrate(&mut data).unwrap_err();
+    assert_eq!(err, ErrorCode::InvalidVaultLayout.into());
+
+    data.resize(Vault::LEN + 1, 0);
+    let err = VaultV0::migrate(&mut data).unwrap_err();
+    assert_eq!(err, ErrorCode::InvalidVaultLayout.into());
+}
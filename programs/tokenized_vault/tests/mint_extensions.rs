@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token_2022::{
+    self,
+    spl_token_2022::{
+        extension::{
+            permanent_delegate::PermanentDelegate, transfer_fee::TransferFeeConfig,
+            BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        },
+        state::Mint,
+    },
+};
+use tokenized_vault::errors::ErrorCode;
+use tokenized_vault::utils::token::{transfer_fee, validate_mint_extensions};
+
+fn mint_with(extension: ExtensionType) -> Vec<u8> {
+    let len = ExtensionType::try_calculate_account_len::<Mint>(&[extension]).unwrap();
+    let mut data = vec![0; len];
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+    match extension {
+        ExtensionType::TransferFeeConfig => {
+            state.init_extension::<TransferFeeConfig>(true).unwrap();
+        }
+        ExtensionType::PermanentDelegate => {
+            state.init_extension::<PermanentDelegate>(true).unwrap();
+        }
+        _ => unimplemented!(),
+    }
+    state.base = Mint { decimals: 6, is_initialized: true, ..Default::default() };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    data
+}
+
+fn with_transfer_fee(mut data: Vec<u8>, basis_points: u16) -> Vec<u8> {
+    let mut state = StateWithExtensionsMut::<Mint>::unpack(&mut data).unwrap();
+    let config = state.get_extension_mut::<TransferFeeConfig>().unwrap();
+    config.newer_transfer_fee.maximum_fee = u64::MAX.into();
+    config.newer_transfer_fee.transfer_fee_basis_points = basis_points.into();
+    data
+}
+
+fn fee(data: &mut [u8], owner: &Pubkey, amount: u64) -> u64 {
+    let key = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let info = AccountInfo::new(&key, false, false, &mut lamports, data, owner, false, 0);
+    transfer_fee(&info, 0, amount).unwrap()
+}
+
+fn validate(data: &mut [u8], owner: &Pubkey) -> Result<()> {
+    let key = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let info = AccountInfo::new(&key, false, false, &mut lamports, data, owner, false, 0);
+    validate_mint_extensions(&info)
+}
+
+#[test]
+fn accepts_transfer_fee_mint() {
+    let mut data = mint_with(ExtensionType::TransferFeeConfig);
+
+    validate(&mut data, &token_2022::ID).unwrap();
+}
+
+#[test]
+fn accepts_legacy_token_mint() {
+    let mut data = vec![0; Mint::LEN];
+
+    validate(&mut data, &anchor_spl::token::ID).unwrap();
+}
+
+#[test]
+fn rejects_permanent_delegate_mint() {
+    let mut data = mint_with(ExtensionType::PermanentDelegate);
+
+    let err = validate(&mut data, &token_2022::ID).unwrap_err();
+
+    assert_eq!(err, ErrorCode::UnsupportedMintExtension.into());
+}
+
+#[test]
+fn transfer_fee_is_charged_on_the_moved_amount() {
+    let mut data = with_transfer_fee(mint_with(ExtensionType::TransferFeeConfig), 100);
+
+    assert_eq!(fee(&mut data, &token_2022::ID, 10_000), 100);
+}
+
+#[test]
+fn transfer_fee_is_zero_without_the_extension() {
+    let mut data = vec![0; Mint::LEN];
+
+    assert_eq!(fee(&mut data, &anchor_spl::token::ID, 10_000), 0);
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use tokenized_vault::constants::{PRICE_HISTORY_CAPACITY, SECONDS_PER_YEAR};
+use tokenized_vault::state::PriceHistory;
+
+const INTERVAL: i64 = 3_600;
+const PRICE: u64 = 1_000_000;
+
+fn history() -> PriceHistory {
+    let mut history = PriceHistory::default();
+    history.init(Pubkey::new_unique(), INTERVAL as u64);
+    history
+}
+
+#[test]
+fn full_buffer_overwrites_the_oldest_snapshot() {
+    let mut history = history();
+    let count = PRICE_HISTORY_CAPACITY as i64 + 2;
+    for i in 0..count {
+        assert!(history.record(PRICE + i as u64, i * INTERVAL));
+    }
+
+    let snapshots: Vec<_> = history.chronological().collect();
+    assert_eq!(snapshots.len(), PRICE_HISTORY_CAPACITY);
+    assert_eq!(snapshots[0].timestamp, 2 * INTERVAL);
+    assert_eq!(history.latest().unwrap().timestamp, (count - 1) * INTERVAL);
+}
+
+#[test]
+fn snapshots_inside_the_interval_are_skipped() {
+    let mut history = history();
+
+    assert!(history.record(PRICE, 0));
+    assert!(!history.record(2 * PRICE, INTERVAL - 1));
+
+    assert_eq!(history.snapshots.len(), 1);
+    assert_eq!(history.latest().unwrap().share_price, PRICE);
+}
+
+#[test]
+fn twap_and_apy_over_a_window() {
+    let mut history = history();
+    history.record(PRICE, 0);
+    history.record(2 * PRICE, INTERVAL);
+
+    // half the window at each price
+    assert_eq!(history.twap(2 * INTERVAL, 2 * INTERVAL), Some(PRICE * 3 / 2));
+
+    let year = SECONDS_PER_YEAR as i64;
+    let mut yearly = self::history();
+    yearly.record(PRICE, 0);
+    yearly.record(PRICE * 11 / 10, year);
+    assert_eq!(yearly.apy(year, year), Some(1_000));
+}
+
+#[test]
+fn apy_needs_two_snapshots_apart() {
+    let mut history = history();
+    assert_eq!(history.apy(INTERVAL, INTERVAL), None);
+
+    history.record(PRICE, 0);
+    assert_eq!(history.apy(INTERVAL, INTERVAL), None);
+}
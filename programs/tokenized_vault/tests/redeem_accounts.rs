@@ -0,0 +1,80 @@
+use accountant::utils::vault::{redeem_instruction, RedeemAccounts};
+use anchor_lang::prelude::*;
+use anchor_lang::InstructionData;
+use tokenized_vault::AccountsMap;
+
+// the accountant can't depend on this crate, so the redeem it builds by hand is checked against the generated one here
+#[test]
+fn accountant_redeem_matches_vault_redeem() {
+    let keys: Vec<Pubkey> = (0..21).map(|_| Pubkey::new_unique()).collect();
+    let mut lamports = vec![0u64; keys.len()];
+    let mut data: Vec<Vec<u8>> = vec![Vec::new(); keys.len()];
+    let owner = Pubkey::default();
+
+    let mut infos: Vec<AccountInfo> = keys
+        .iter()
+        .zip(lamports.iter_mut())
+        .zip(data.iter_mut())
+        .map(|((key, lamports), data)| AccountInfo::new(key, false, false, lamports, data, &owner, false, 0))
+        .collect();
+    let mut next = || infos.remove(0);
+
+    let redeem = RedeemAccounts {
+        vault: next(),
+        user_token_account: next(),
+        vault_token_account: next(),
+        accountant: next(),
+        accountant_recipient: next(),
+        vault_fee_override: next(),
+        accountant_token_account: next(),
+        shares_mint: next(),
+        underlying_mint: next(),
+        user_shares_account: next(),
+        user_data: next(),
+        sanctioned: next(),
+        price_history: next(),
+        user: next(),
+        owner: next(),
+        shares_token_program: next(),
+        token_program: next(),
+        strategy_program: next(),
+        access_control: next(),
+        system_program: next(),
+        vault_program: next(),
+    };
+
+    let withdraw = tokenized_vault::accounts::Withdraw {
+        vault: keys[0],
+        user_token_account: keys[1],
+        vault_token_account: keys[2],
+        accountant: keys[3],
+        accountant_recipient: keys[4],
+        vault_fee_override: keys[5],
+        accountant_token_account: keys[6],
+        shares_mint: keys[7],
+        underlying_mint: keys[8],
+        user_shares_account: keys[9],
+        user_data: keys[10],
+        sanctioned: keys[11],
+        price_history: keys[12],
+        user: keys[13],
+        owner: keys[14],
+        shares_token_program: keys[15],
+        token_program: keys[16],
+        strategy_program: keys[17],
+        access_control: keys[18],
+        system_program: keys[19],
+    };
+
+    let ix = redeem_instruction(&redeem, 1_000).unwrap();
+    let expected_data = tokenized_vault::instruction::Redeem {
+        shares: 1_000,
+        max_loss: 0,
+        remaining_accounts_map: AccountsMap { accounts_map: vec![] },
+    }
+    .data();
+
+    assert_eq!(ix.program_id, tokenized_vault::ID);
+    assert_eq!(ix.accounts, withdraw.to_account_metas(None));
+    assert_eq!(ix.data, expected_data);
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use tokenized_vault::state::Referral;
+
+#[test]
+fn first_referrer_is_kept() {
+    let user = Pubkey::new_unique();
+    let referrer = Pubkey::new_unique();
+    let mut referral = Referral::default();
+
+    referral.set_referrer(&user, Some(referrer));
+    referral.set_referrer(&user, Some(Pubkey::new_unique()));
+
+    assert_eq!(referral.referrer(), Some(referrer));
+}
+
+#[test]
+fn self_referral_is_ignored() {
+    let user = Pubkey::new_unique();
+    let mut referral = Referral::default();
+
+    referral.set_referrer(&user, Some(user));
+    assert_eq!(referral.referrer(), None);
+
+    referral.set_referrer(&user, None);
+    assert_eq!(referral.referrer(), None);
+}
@@ -0,0 +1,46 @@
+use access_control::state::RoleExpiry;
+use anchor_lang::prelude::*;
+use tokenized_vault::state::UserData;
+use tokenized_vault::utils::is_role_expired;
+
+const NOW: i64 = 1_000_000;
+
+fn role_expiry_account(expires_at: Option<i64>) -> Vec<u8> {
+    let mut data = Vec::new();
+    if let Some(expires_at) = expires_at {
+        RoleExpiry { expires_at }.try_serialize(&mut data).unwrap();
+    }
+    data
+}
+
+fn expired(data: &mut [u8]) -> bool {
+    let key = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let info = AccountInfo::new(
+        &key, false, false, &mut lamports, data, &access_control::ID, false, 0,
+    );
+    is_role_expired(&info, NOW).unwrap()
+}
+
+#[test]
+fn roles_without_expiry_never_expire() {
+    assert!(!expired(&mut role_expiry_account(None)));
+}
+
+#[test]
+fn roles_expire_at_their_deadline() {
+    assert!(!expired(&mut role_expiry_account(Some(NOW + 1))));
+    assert!(expired(&mut role_expiry_account(Some(NOW))));
+}
+
+#[test]
+fn whitelisting_lapses_at_its_expiry() {
+    let user_data = UserData {
+        whitelisted: true,
+        whitelist_expires_at: NOW,
+        ..Default::default()
+    };
+
+    assert!(user_data.is_whitelisted([0; 32], NOW - 1));
+    assert!(!user_data.is_whitelisted([0; 32], NOW));
+}
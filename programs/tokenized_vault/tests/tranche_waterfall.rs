@@ -0,0 +1,143 @@
+mod common;
+
+use common::{set_clock, NOW};
+use tokenized_vault::constants::SECONDS_PER_YEAR;
+use tokenized_vault::state::Vault;
+
+const YEAR: u64 = SECONDS_PER_YEAR;
+
+// 1000 senior and 500 junior, senior targets 10% a year
+fn tranched_vault(profit_max_unlock_time: u64) -> Vault {
+    Vault {
+        is_tranched: true,
+        total_idle: 1_500,
+        senior_assets: 1_000,
+        junior_total_shares: 500,
+        senior_target_rate: 1_000,
+        profit_max_unlock_time,
+        ..Default::default()
+    }
+}
+
+fn report_profit(vault: &mut Vault, profit: u64, timestamp: u64) {
+    vault.apply_tranche_profit(profit, timestamp);
+    vault.total_idle += profit;
+}
+
+fn report_loss(vault: &mut Vault, loss: u64, timestamp: u64) {
+    vault.apply_tranche_loss(loss, timestamp);
+    vault.total_idle -= loss;
+}
+
+#[test]
+fn profit_pays_senior_target_and_locks_junior_rest() {
+    let mut vault = tranched_vault(100);
+
+    report_profit(&mut vault, 300, YEAR);
+
+    assert_eq!(vault.senior_funds(), 1_100);
+    assert_eq!({ vault.junior_locked_profit }, 200);
+    assert_eq!(vault.junior_funds_at(YEAR), 500);
+    assert_eq!(vault.junior_funds_at(YEAR + 50), 600);
+    assert_eq!(vault.junior_funds_at(YEAR + 100), 700);
+}
+
+#[test]
+fn junior_deposit_right_before_report_gets_no_profit() {
+    let mut vault = tranched_vault(100);
+
+    let shares = vault.convert_to_junior_shares(500, YEAR);
+    vault.handle_junior_deposit(500, shares);
+    report_profit(&mut vault, 300, YEAR);
+
+    assert_eq!(vault.convert_junior_to_underlying(shares, YEAR), 500);
+    assert!(vault.convert_junior_to_underlying(shares, YEAR + 100) > 500);
+}
+
+#[test]
+fn senior_deposit_right_before_report_only_earns_from_then_on() {
+    set_clock();
+    let mut vault = tranched_vault(100);
+    let now = NOW as u64;
+
+    vault.handle_deposit(1_000_000, 1_000_000);
+    report_profit(&mut vault, 10_000, now);
+
+    // only the 1000 that were in the vault since 0 earned the target rate
+    let senior_profit = 1_000 * now / 10 / YEAR;
+    assert_eq!(vault.senior_funds(), 1_001_000 + senior_profit);
+    assert_eq!({ vault.junior_locked_profit }, 10_000 - senior_profit);
+}
+
+#[test]
+fn senior_accrual_follows_the_tranche_size() {
+    let mut vault = tranched_vault(100);
+
+    // 1000 for half a year, then 2000 for the other half
+    vault.accrue_senior_profit(YEAR / 2);
+    vault.senior_assets += 1_000;
+    vault.total_idle += 1_000;
+    report_profit(&mut vault, 1_000, YEAR);
+
+    assert_eq!(vault.senior_funds(), 2_000 + 50 + 100);
+    assert_eq!({ vault.senior_accrued_profit }, 0);
+}
+
+#[test]
+fn profit_without_unlock_time_goes_to_junior_at_once() {
+    let mut vault = tranched_vault(0);
+
+    report_profit(&mut vault, 300, YEAR);
+
+    assert_eq!({ vault.junior_locked_profit }, 0);
+    assert_eq!(vault.junior_funds_at(YEAR), 700);
+}
+
+#[test]
+fn loss_takes_locked_profit_then_junior_then_senior() {
+    let mut vault = tranched_vault(100);
+    report_profit(&mut vault, 300, YEAR);
+
+    report_loss(&mut vault, 150, YEAR);
+    assert_eq!(vault.junior_locked_profit_at(YEAR), 50);
+    assert_eq!(vault.junior_funds_at(YEAR), 500);
+    assert_eq!(vault.senior_funds(), 1_100);
+
+    report_loss(&mut vault, 800, YEAR);
+    assert_eq!(vault.junior_locked_profit_at(YEAR), 0);
+    assert_eq!(vault.junior_funds_at(YEAR), 0);
+    assert_eq!(vault.senior_funds(), 850);
+}
+
+#[test]
+fn locked_profit_keeps_unlocking_after_partial_loss() {
+    let mut vault = tranched_vault(100);
+    report_profit(&mut vault, 300, YEAR);
+
+    report_loss(&mut vault, 100, YEAR + 50);
+
+    // half of the 200 already unlocked to junior, the loss took the locked half
+    assert_eq!(vault.junior_locked_profit_at(YEAR + 50), 0);
+    assert_eq!(vault.junior_funds_at(YEAR + 50), 600);
+}
+
+#[test]
+fn senior_withdraw_loss_is_covered_by_junior() {
+    let mut vault = tranched_vault(100);
+    // 50 lost while freeing funds, already booked against the vault funds
+    vault.total_idle -= 50;
+
+    assert_eq!(vault.senior_withdraw_cover(400, 350), 400);
+    assert_eq!(vault.senior_withdraw_cover(400, 400), 400);
+}
+
+#[test]
+fn senior_withdraw_loss_is_not_covered_once_junior_is_gone() {
+    let mut vault = tranched_vault(100);
+    vault.total_idle = 900;
+
+    assert_eq!(vault.senior_withdraw_cover(400, 350), 350);
+
+    let plain = Vault { total_idle: 1_500, ..Default::default() };
+    assert_eq!(plain.senior_withdraw_cover(400, 350), 350);
+}
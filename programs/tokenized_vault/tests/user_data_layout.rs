@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use tokenized_vault::constants::USER_DATA_VERSION;
+use tokenized_vault::state::{UserData, UserDataV0};
+use tokenized_vault::utils::{load_user_data, save_user_data};
+
+fn with_account<R>(data: &mut [u8], f: impl FnOnce(&AccountInfo) -> R) -> R {
+    let key = Pubkey::new_unique();
+    let owner = tokenized_vault::ID;
+    let mut lamports = 1_000_000;
+    let info = AccountInfo::new(&key, false, true, &mut lamports, data, &owner, false, 0);
+    f(&info)
+}
+
+fn v0_account(deposited: u64, whitelisted: bool) -> Vec<u8> {
+    let mut data = UserData::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&deposited.to_le_bytes());
+    data.push(whitelisted as u8);
+    assert_eq!(data.len(), UserDataV0::LEN);
+    data
+}
+
+#[test]
+fn reads_legacy_layout() {
+    let mut data = v0_account(500, true);
+
+    let user_data = with_account(&mut data, load_user_data).unwrap();
+
+    assert_eq!(user_data.deposited, 500);
+    assert!(user_data.whitelisted);
+    assert_eq!(user_data.shares, 0);
+    assert_eq!(user_data.last_deposit_ts, 0);
+    assert_eq!(user_data.whitelist_expires_at, 0);
+    assert_eq!(user_data.version, 0);
+}
+
+#[test]
+fn round_trips_current_layout() {
+    let mut data = vec![0; UserData::LEN];
+    let mut user_data = UserData { deposited: 42, shares: 40, last_deposit_ts: 7, ..Default::default() };
+
+    with_account(&mut data, |info| {
+        // already at the current size, so no rent top-up and no cpi
+        save_user_data(info, &mut user_data, info, info).unwrap();
+        load_user_data(info)
+    })
+    .map(|loaded| {
+        assert_eq!(loaded.deposited, 42);
+        assert_eq!(loaded.shares, 40);
+        assert_eq!(loaded.last_deposit_ts, 7);
+        assert_eq!(loaded.version, USER_DATA_VERSION);
+    })
+    .unwrap();
+}
+
+#[test]
+fn rejects_unknown_layouts() {
+    let mut truncated = v0_account(1, false);
+    truncated.pop();
+    assert!(with_account(&mut truncated, load_user_data).is_err());
+
+    let mut wrong_discriminator = v0_account(1, false);
+    wrong_discriminator[0] ^= 1;
+    assert!(with_account(&mut wrong_discriminator, load_user_data).is_err());
+
+    let mut empty = vec![];
+    assert!(with_account(&mut empty, load_user_data).is_err());
+}
@@ -0,0 +1,57 @@
+use tokenized_vault::constants::ONE_SHARE_TOKEN;
+use tokenized_vault::state::UserData;
+
+#[test]
+fn deposits_average_the_entry_price() {
+    let mut user_data = UserData::default();
+
+    user_data.handle_deposit(1_000, 1_000, 10, true);
+    user_data.handle_deposit(2_000, 1_000, 20, true);
+
+    assert_eq!(user_data.shares, 2_000);
+    assert_eq!(user_data.avg_entry_price as u128, 3 * ONE_SHARE_TOKEN / 2);
+    assert_eq!(user_data.total_deposited, 3_000);
+    assert_eq!(user_data.last_deposit_ts, 20);
+}
+
+#[test]
+fn withdrawals_realize_pnl_against_the_entry_price() {
+    let mut user_data = UserData::default();
+    user_data.handle_deposit(1_000, 1_000, 10, true);
+    user_data.handle_deposit(2_000, 1_000, 20, true);
+
+    user_data.handle_withdraw(2_000, 1_000).unwrap();
+    assert_eq!(user_data.realized_pnl, 500);
+    assert_eq!(user_data.shares, 1_000);
+
+    user_data.handle_withdraw(1_000, 1_000).unwrap();
+    assert_eq!(user_data.realized_pnl, 0);
+    assert_eq!(user_data.shares, 0);
+    assert_eq!(user_data.avg_entry_price, 0);
+    assert_eq!(user_data.total_withdrawn, 3_000);
+    assert_eq!(user_data.deposited, 0);
+}
+
+#[test]
+fn shares_without_basis_realize_no_pnl() {
+    let mut user_data = UserData::default();
+    user_data.handle_deposit(1_000, 1_000, 10, true);
+
+    // 1_000 more shares came in by transfer, only the tracked half counts
+    user_data.handle_withdraw(4_000, 2_000).unwrap();
+
+    assert_eq!(user_data.realized_pnl, 1_000);
+    assert_eq!(user_data.shares, 0);
+    assert_eq!(user_data.total_withdrawn, 4_000);
+}
+
+#[test]
+fn fees_are_part_of_the_cost_basis() {
+    let mut user_data = UserData::default();
+
+    // a 20% entry fee leaves 800 shares for 1_000 paid
+    user_data.handle_deposit(1_000, 800, 10, true);
+    user_data.handle_withdraw(800, 800).unwrap();
+
+    assert_eq!(user_data.realized_pnl, -200);
+}
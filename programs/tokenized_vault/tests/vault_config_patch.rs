@@ -0,0 +1,57 @@
+use tokenized_vault::errors::ErrorCode;
+use tokenized_vault::state::{Vault, VaultConfigPatch};
+
+fn vault() -> Vault {
+    Vault {
+        deposit_limit: 1_000_000,
+        user_deposit_limit: 10_000,
+        min_user_deposit: 100,
+        kyc_verified_only: true,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn patch_only_touches_set_fields() {
+    let mut vault = vault();
+    let patch = VaultConfigPatch {
+        deposit_limit: Some(2_000_000),
+        whitelisted_only: Some(true),
+        ..Default::default()
+    };
+
+    let settings = vault.settings().patch(&patch);
+    settings.validate().unwrap();
+    vault.apply_settings(&settings);
+
+    let applied = vault.settings();
+    assert_eq!(applied.deposit_limit, 2_000_000);
+    assert!(applied.whitelisted_only);
+    assert_eq!(applied.user_deposit_limit, 10_000);
+    assert_eq!(applied.min_user_deposit, 100);
+    assert!(applied.kyc_verified_only);
+}
+
+#[test]
+fn inconsistent_limits_are_rejected() {
+    let patch = VaultConfigPatch {
+        min_user_deposit: Some(20_000),
+        ..Default::default()
+    };
+
+    let err = vault().settings().patch(&patch).validate().unwrap_err();
+
+    assert_eq!(err, ErrorCode::InvalidVaultConfig.into());
+}
+
+#[test]
+fn early_exit_fee_above_max_bps_is_rejected() {
+    let patch = VaultConfigPatch {
+        early_exit_fee: Some(10_001),
+        ..Default::default()
+    };
+
+    let err = vault().settings().patch(&patch).validate().unwrap_err();
+
+    assert_eq!(err, ErrorCode::InvalidFee.into());
+}
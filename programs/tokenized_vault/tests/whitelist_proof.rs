@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use anchor_lang::Discriminator;
+use tokenized_vault::errors::ErrorCode;
+use tokenized_vault::state::{UserData, Vault};
+use tokenized_vault::utils::{apply_whitelist_proof, whitelist_leaf};
+
+fn hash_pair(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+    if a <= b {
+        keccak::hashv(&[&a, &b]).to_bytes()
+    } else {
+        keccak::hashv(&[&b, &a]).to_bytes()
+    }
+}
+
+// four leaf tree, returns the root and the proof for `users[0]`
+fn tree(users: &[Pubkey; 4]) -> ([u8; 32], Vec<[u8; 32]>) {
+    let leaves = users.map(|user| whitelist_leaf(&user));
+    let left = hash_pair(leaves[0], leaves[1]);
+    let right = hash_pair(leaves[2], leaves[3]);
+    (hash_pair(left, right), vec![leaves[1], right])
+}
+
+fn vault_account(whitelist_root: [u8; 32]) -> Vec<u8> {
+    let vault = Vault { whitelist_root, ..Default::default() };
+    let mut data = Vault::DISCRIMINATOR.to_vec();
+    data.extend_from_slice(bytemuck::bytes_of(&vault));
+    data
+}
+
+fn apply(root: [u8; 32], user: &Pubkey, proof: Vec<[u8; 32]>) -> Result<UserData> {
+    let mut data = vault_account(root);
+    let key = Pubkey::new_unique();
+    let mut lamports = 1_000_000;
+    let info = AccountInfo::new(
+        &key, false, true, &mut lamports, &mut data, &tokenized_vault::ID, false, 0,
+    );
+    let vault = AccountLoader::<Vault>::try_from(&info)?;
+
+    let mut user_data = UserData::default();
+    apply_whitelist_proof(&vault, &mut user_data, user, Some(proof))?;
+    Ok(user_data)
+}
+
+#[test]
+fn member_proof_whitelists_until_the_root_changes() {
+    let users = [(); 4].map(|_| Pubkey::new_unique());
+    let (root, proof) = tree(&users);
+
+    let user_data = apply(root, &users[0], proof).unwrap();
+
+    assert!(user_data.is_whitelisted(root, 0));
+    assert!(!user_data.is_whitelisted([1; 32], 0));
+}
+
+#[test]
+fn proof_for_another_user_is_rejected() {
+    let users = [(); 4].map(|_| Pubkey::new_unique());
+    let (root, proof) = tree(&users);
+
+    let err = apply(root, &Pubkey::new_unique(), proof).unwrap_err();
+
+    assert_eq!(err, ErrorCode::InvalidWhitelistProof.into());
+}
+
+#[test]
+fn proof_is_rejected_without_a_root() {
+    let users = [(); 4].map(|_| Pubkey::new_unique());
+    let (_, proof) = tree(&users);
+
+    let err = apply([0; 32], &users[0], proof).unwrap_err();
+
+    assert_eq!(err, ErrorCode::InvalidWhitelistProof.into());
+}
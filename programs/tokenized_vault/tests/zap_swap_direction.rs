@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+use tokenized_vault::errors::ErrorCode;
+use tokenized_vault::instructions::swap_direction;
+
+#[test]
+fn underlying_as_token_a_swaps_b_to_a() {
+    let underlying = Pubkey::new_unique();
+    let input = Pubkey::new_unique();
+
+    assert!(swap_direction(&underlying, &input, &underlying, &input).unwrap());
+}
+
+#[test]
+fn underlying_as_token_b_swaps_a_to_b() {
+    let underlying = Pubkey::new_unique();
+    let input = Pubkey::new_unique();
+
+    assert!(!swap_direction(&input, &underlying, &underlying, &input).unwrap());
+}
+
+#[test]
+fn pool_without_the_input_mint_is_rejected() {
+    let underlying = Pubkey::new_unique();
+    let input = Pubkey::new_unique();
+
+    let err = swap_direction(&underlying, &Pubkey::new_unique(), &underlying, &input).unwrap_err();
+
+    assert_eq!(err, ErrorCode::InvalidSwapPool.into());
+}
@@ -113,7 +113,17 @@ async function main() {
 
     let adminSharesAccount = await token.createAccount(provider.connection, admin, sharesMint, admin.publicKey);
     
-    await accountantProgram.methods.initAccountant({ generic: {} })
+    await accountantProgram.methods.initAccountant({ generic: {} }, {
+      feeCaps: {
+        maxEntryFee: new BN(10000),
+        maxRedemptionFee: new BN(10000),
+        maxPerformanceFee: new BN(10000),
+      },
+      feeChangeDelay: new BN(86400),
+      entryFee: new BN(0),
+      redemptionFee: new BN(0),
+      performanceFee: new BN(0),
+    })
       .accounts({
         signer: admin.publicKey,
         underlyingMint: sharesMint,
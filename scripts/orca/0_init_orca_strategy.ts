@@ -200,7 +200,17 @@ async function main() {
     // 2. Initialize accountant
     console.log("Initializing Accountant...");
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, {
+      feeCaps: {
+        maxEntryFee: new BN(10000),
+        maxRedemptionFee: new BN(10000),
+        maxPerformanceFee: new BN(10000),
+      },
+      feeChangeDelay: new BN(86400),
+      entryFee: new BN(500),
+      redemptionFee: new BN(500),
+      performanceFee: new BN(0),
+    })
       .accounts({
         signer: admin.publicKey,
       })
@@ -229,29 +239,6 @@ async function main() {
       .rpc();
     console.log("Accountant token account initialized.");
 
-      //set fee
-      console.log("Setting entry fee...");
-      await accountantProgram.methods.setEntryFee(new BN(500))
-      .accounts({
-        accountant: accountant,
-        signer: admin.publicKey,
-      })
-      .signers([admin])
-      .rpc();
-      console.log("Entry fee set.");
-
-
-      //set fee
-      console.log("Setting redemption fee...");
-      await accountantProgram.methods.setRedemptionFee(new BN(500))
-      .accounts({
-        accountant: accountant,
-        signer: admin.publicKey,
-      })
-      .signers([admin])
-      .rpc();
-      console.log("Redemption fee set.");
-
     // 7. Initialize Vault Config
     console.log("Initializing Vault Config...");
     const configPDA = anchor.web3.PublicKey.findProgramAddressSync(
@@ -121,6 +121,7 @@ async function main() {
         underlyingMint: vaultData.underlyingMint,
         tokenProgram: TOKEN_PROGRAM_ID,
         accountant: accountant,
+        sharesTokenProgram: TOKEN_PROGRAM_ID,
       })
       .preInstructions([computeUnitIx])
       .rpc();
@@ -123,7 +123,7 @@ async function main() {
     try {
       // Execute deposit first
       await vaultProgram.methods
-        .deposit(depositAmount)
+        .deposit(depositAmount, null, null)
         .accounts({
           vault,
           accountant,
@@ -131,7 +131,9 @@ async function main() {
           underlyingMint,
           userSharesAccount: userSharesAccount.address,
           user: admin.publicKey,
+          receiver: admin.publicKey,
           tokenProgram: TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([admin])
         .rpc();
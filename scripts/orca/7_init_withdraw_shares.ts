@@ -6,6 +6,7 @@ import { AccessControl } from "../../target/types/access_control";
 import * as fs from "fs";
 import * as path from "path";
 import { PublicKey } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
 
 function getSecretKeyPath(): string {
   const ENV = process.env.CLUSTER || 'devnet';
@@ -46,6 +47,7 @@ async function main() {
       .accounts({
         vault: vault,
         signer: admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([admin])
       .rpc();
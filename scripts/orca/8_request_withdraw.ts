@@ -5,7 +5,7 @@ import { Strategy } from "../../target/types/strategy";
 import { AccessControl } from "../../target/types/access_control";
 import * as fs from "fs";
 import { PublicKey } from "@solana/web3.js";
-import { getAssociatedTokenAddress } from "@solana/spl-token";
+import { getAssociatedTokenAddress, TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import * as path from "path";
 import { Accountant } from "../../target/types/accountant";
 
@@ -119,6 +119,7 @@ async function main() {
         userSharesAccount: userSharesATA,
         userTokenAccount: userTokenAccount,
         accountant: accountant,
+        sharesTokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([admin])
       .rpc();
@@ -306,13 +306,14 @@ async function main() {
 
     // Build the instruction for direct deposit
     const depositIx = await vaultProgram.methods
-      .directDeposit(depositAmount)
+      .directDeposit(depositAmount, null, null)
       .accounts({
         vault: vaultPDA,
         userTokenAccount: userUsdcATA,
         userSharesAccount: userSharesATA.address,
         strategy: strategy,
         user: admin.publicKey,
+        receiver: admin.publicKey,
         underlyingMint: UNDERLYING_MINT,
       })
       .remainingAccounts(combinedRemainingAccounts)
@@ -791,6 +791,7 @@ async function main() {
         userTokenAccount: userUsdcATA,
         userSharesAccount: userSharesATA,
         user: admin.publicKey,
+        owner: admin.publicKey,
         underlyingMint: USDC_MINT,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
@@ -133,12 +133,13 @@ async function main() {
   
       try {
         await vaultProgram.methods
-            .deposit(depositAmount)
+            .deposit(depositAmount, null, null)
             .accounts({
                 vault: vaultPDA,
                 userTokenAccount: userUsdcATA,
                 userSharesAccount: userSharesATA.address,
                 user: admin.publicKey,
+                receiver: admin.publicKey,
             })
             .signers([admin])
             .rpc();
@@ -454,6 +455,7 @@ async function main() {
         userTokenAccount: userUsdcATA,
         userSharesAccount: userSharesATA.address,
         user: admin.publicKey,
+        owner: admin.publicKey,
       })
       .remainingAccounts(remainingAccounts)
       .signers([admin])
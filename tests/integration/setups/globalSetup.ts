@@ -5,6 +5,7 @@ import { TokenizedVault } from "../../../target/types/tokenized_vault";
 import { airdrop } from "../../utils/helpers";
 import { AccessControl } from "../../../target/types/access_control";
 import { Accountant } from "../../../target/types/accountant";
+import { KycHook } from "../../../target/types/kyc_hook";
 import { assert } from "chai";
 import { ROLES, ROLES_BUFFER } from "../../utils/constants";
 
@@ -19,6 +20,7 @@ export const accessControlProgram =
 export const vaultProgram = workspace.TokenizedVault as Program<TokenizedVault>;
 export const strategyProgram = workspace.Strategy as Program<Strategy>;
 export const accountantProgram = workspace.Accountant as Program<Accountant>;
+export const kycHookProgram = workspace.KycHook as Program<KycHook>;
 
 export const TOKEN_METADATA_PROGRAM_ID = new anchor.web3.PublicKey(
   "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
@@ -139,6 +141,30 @@ export async function mochaGlobalSetup() {
     .signers([configOwner])
     .rpc();
 
+  await accessControlProgram.methods
+    .setRoleManager(ROLES.COMPLIANCE_OFFICER, ROLES.ROLES_ADMIN)
+    .accounts({
+      signer: configOwner.publicKey,
+    })
+    .signers([configOwner])
+    .rpc();
+
+  await accessControlProgram.methods
+    .setRoleManager(ROLES.SANCTIONED, ROLES.COMPLIANCE_OFFICER)
+    .accounts({
+      signer: configOwner.publicKey,
+    })
+    .signers([configOwner])
+    .rpc();
+
+  await accessControlProgram.methods
+    .setRoleManager(ROLES.GUARDIAN, ROLES.ROLES_ADMIN)
+    .accounts({
+      signer: configOwner.publicKey,
+    })
+    .signers([configOwner])
+    .rpc();
+
   console.log("Setting role managers for all roles successfully");
 
   await vaultProgram.methods
@@ -0,0 +1,172 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import {
+  accountantProgram,
+  strategyProgram,
+  vaultProgram,
+} from "../../setups/globalSetup";
+import { ACCOUNTANT_CONFIG, errorStrings } from "../../../utils/constants";
+import {
+  createTestUser,
+  depositInto,
+  initializeSimpleStrategy,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+import { SimpleStrategyConfig } from "../../../utils/schemas";
+
+describe("Accountant: Redeem And Distribute Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+  let vaultTokenAccount: anchor.web3.PublicKey;
+
+  let accountantSharesAccount: anchor.web3.PublicKey;
+  let recipient: anchor.web3.Keypair;
+  let recipientTokenAccount: anchor.web3.PublicKey;
+
+  const redeemAndDistribute = (signer: anchor.web3.Keypair) =>
+    accountantProgram.methods
+      .redeemAndDistribute()
+      .accounts({
+        accountant,
+        vault,
+        vaultTokenAccount,
+        sharesMint,
+        underlyingMint,
+        recipient: recipientTokenAccount,
+        signer: signer.publicKey,
+        strategyProgram: strategyProgram.programId,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([signer])
+      .rpc();
+
+  const updateDebt = (strategy: anchor.web3.PublicKey, debt: number) =>
+    vaultProgram.methods
+      .updateDebt(new BN(debt))
+      .accounts({
+        vault,
+        strategy,
+        signer: admin.publicKey,
+        underlyingMint,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+  before(async () => {
+    ({ admin, underlyingMint, accountant, vault, sharesMint, vaultTokenAccount } =
+      await setupTestVault({
+        // 10% entry fee, taken as shares
+        accountantConfig: { ...ACCOUNTANT_CONFIG, entryFee: new BN(1000) },
+      }));
+
+    const depositor = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000000,
+    });
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      ...depositor,
+      amount: 1000000000,
+    });
+
+    accountantSharesAccount = token.getAssociatedTokenAddressSync(
+      sharesMint,
+      accountant,
+      true
+    );
+
+    recipient = anchor.web3.Keypair.generate();
+    ({ tokenAccount: recipientTokenAccount } = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 0,
+    }));
+  });
+
+  it("Redeeming fee shares without the accountant admin role should revert", async () => {
+    const feeShares = await tokenBalance(accountantSharesAccount);
+
+    try {
+      await redeemAndDistribute(recipient);
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(
+        errorStrings.accountExpectedToAlreadyBeInitialized
+      );
+    }
+
+    assert.strictEqual(await tokenBalance(accountantSharesAccount), feeShares);
+    assert.strictEqual(await tokenBalance(recipientTokenAccount), "0");
+  });
+
+  it("Redeeming fee shares backed by strategy funds should revert", async () => {
+    const feeShares = await tokenBalance(accountantSharesAccount);
+
+    const [strategy] = await initializeSimpleStrategy({
+      strategyProgram,
+      vault,
+      underlyingMint,
+      signer: admin,
+      config: new SimpleStrategyConfig({
+        depositLimit: new BN(1000000000),
+        performanceFee: new BN(0),
+        feeManager: admin.publicKey,
+      }),
+    });
+    await vaultProgram.methods
+      .addStrategy(new BN(1000000000))
+      .accounts({
+        vault,
+        strategy,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+    await updateDebt(strategy, 1000000000);
+
+    try {
+      await redeemAndDistribute(admin);
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.exceedWithdrawLimit);
+    }
+
+    assert.strictEqual(await tokenBalance(accountantSharesAccount), feeShares);
+
+    await updateDebt(strategy, 0);
+  });
+
+  it("Redeeming fee shares pays the underlying out to the recipient", async () => {
+    const feeShares = await tokenBalance(accountantSharesAccount);
+    assert.notStrictEqual(feeShares, "0");
+
+    const vaultBefore = await vaultProgram.account.vault.fetch(vault);
+
+    await redeemAndDistribute(admin);
+
+    const vaultAfter = await vaultProgram.account.vault.fetch(vault);
+    const redeemed = vaultBefore.totalIdle.sub(vaultAfter.totalIdle);
+
+    assert.strictEqual(await tokenBalance(accountantSharesAccount), "0");
+    assert.isTrue(redeemed.gtn(0));
+    assert.strictEqual(
+      await tokenBalance(recipientTokenAccount),
+      redeemed.toString()
+    );
+    assert.strictEqual(
+      vaultBefore.totalShares.sub(vaultAfter.totalShares).toString(),
+      feeShares
+    );
+  });
+});
@@ -11,7 +11,12 @@ import {
   TOKEN_METADATA_PROGRAM_ID,
 } from "../setups/globalSetup";
 import { assert, expect } from "chai";
-import { errorStrings, ROLES, ROLES_BUFFER } from "../../utils/constants";
+import {
+  ACCOUNTANT_CONFIG,
+  errorStrings,
+  ROLES,
+  ROLES_BUFFER,
+} from "../../utils/constants";
 import { BN } from "@coral-xyz/anchor";
 import {
   airdrop,
@@ -182,7 +187,10 @@ describe("Roles and Permissions Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, {
+        ...ACCOUNTANT_CONFIG,
+        performanceFee: new BN(1000),
+      })
       .accounts({
         signer: accountantAdmin.publicKey,
       })
@@ -350,7 +358,7 @@ describe("Roles and Permissions Tests", () => {
       const nextAccountantIndexBefore =
         accountantConfigAccount.nextAccountantIndex.toNumber();
       await accountantProgram.methods
-        .initAccountant(accountantType)
+        .initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -369,6 +377,7 @@ describe("Roles and Permissions Tests", () => {
     });
 
     it("Accountant Admin - Calling set fee method is successful", async function () {
+      // lowering the fee applies right away, raises wait for the fee change delay
       await accountantProgram.methods
         .setPerformanceFee(new BN(500))
         .accounts({
@@ -906,7 +915,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -923,15 +932,17 @@ describe("Roles and Permissions Tests", () => {
         .rpc();
 
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: kycVerifiedUserSharesAccount,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -1257,7 +1268,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -1274,15 +1285,17 @@ describe("Roles and Permissions Tests", () => {
         .rpc();
 
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: kycVerifiedUserSharesAccount,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -1330,6 +1343,9 @@ describe("Roles and Permissions Tests", () => {
             strategy: strategyOne,
             signer: accountantAdmin.publicKey,
             accountant: accountantOne,
+            underlyingMint: underlyingMint,
+            underlyingTokenProgram: token.TOKEN_PROGRAM_ID,
+            tokenProgram: token.TOKEN_PROGRAM_ID,
           })
           .signers([accountantAdmin])
           .rpc();
@@ -1421,7 +1437,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -1439,15 +1455,17 @@ describe("Roles and Permissions Tests", () => {
 
       try {
         await vaultProgram.methods
-          .deposit(new BN(depositAmount))
+          .deposit(new BN(depositAmount), null, null)
           .accounts({
             vault: vault,
             accountant: accountant,
             user: accountantAdmin.publicKey,
+            receiver: accountantAdmin.publicKey,
             userTokenAccount: tokenAccount,
             userSharesAccount: sharesAccount,
             underlyingMint: underlyingMint,
             tokenProgram: token.TOKEN_PROGRAM_ID,
+            sharesTokenProgram: token.TOKEN_PROGRAM_ID,
           })
           .signers([accountantAdmin])
           .remainingAccounts([
@@ -1519,7 +1537,7 @@ describe("Roles and Permissions Tests", () => {
 
       try {
         await accountantProgram.methods
-          .initAccountant(accountantType)
+          .initAccountant(accountantType, ACCOUNTANT_CONFIG)
           .accounts({
             signer: strategiesManager.publicKey,
           })
@@ -2064,7 +2082,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -2081,15 +2099,17 @@ describe("Roles and Permissions Tests", () => {
         .rpc();
 
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: kycVerifiedUserSharesAccount,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -2415,7 +2435,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -2432,15 +2452,17 @@ describe("Roles and Permissions Tests", () => {
         .rpc();
 
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: kycVerifiedUserSharesAccount,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -2488,6 +2510,9 @@ describe("Roles and Permissions Tests", () => {
             strategy: strategyOne,
             signer: strategiesManager.publicKey,
             accountant: accountantOne,
+            underlyingMint: underlyingMint,
+            underlyingTokenProgram: token.TOKEN_PROGRAM_ID,
+            tokenProgram: token.TOKEN_PROGRAM_ID,
           })
           .signers([strategiesManager])
           .rpc();
@@ -2572,7 +2597,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -2590,15 +2615,17 @@ describe("Roles and Permissions Tests", () => {
 
       try {
         await vaultProgram.methods
-          .deposit(new BN(depositAmount))
+          .deposit(new BN(depositAmount), null, null)
           .accounts({
             vault: vault,
             accountant: accountant,
             user: strategiesManager.publicKey,
+            receiver: strategiesManager.publicKey,
             userTokenAccount: strategiesManagerOneTokenAccount,
             userSharesAccount: sharesAccount,
             underlyingMint: underlyingMint,
             tokenProgram: token.TOKEN_PROGRAM_ID,
+            sharesTokenProgram: token.TOKEN_PROGRAM_ID,
           })
           .signers([strategiesManager])
           .remainingAccounts([
@@ -2992,7 +3019,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -3001,15 +3028,17 @@ describe("Roles and Permissions Tests", () => {
 
 
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vaultOne,
           accountant: accountantOne,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: kycVerifiedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -3215,7 +3244,7 @@ describe("Roles and Permissions Tests", () => {
 
       try {
         await accountantProgram.methods
-          .initAccountant(accountantType)
+          .initAccountant(accountantType, ACCOUNTANT_CONFIG)
           .accounts({
             signer: vaultsAdmin.publicKey,
           })
@@ -3387,7 +3416,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -3404,15 +3433,17 @@ describe("Roles and Permissions Tests", () => {
         .rpc();
 
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: kycVerifiedUserSharesAccount,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -3460,6 +3491,9 @@ describe("Roles and Permissions Tests", () => {
             strategy: strategyOne,
             signer: vaultsAdmin.publicKey,
             accountant: accountantOne,
+            underlyingMint: underlyingMint,
+            underlyingTokenProgram: token.TOKEN_PROGRAM_ID,
+            tokenProgram: token.TOKEN_PROGRAM_ID,
           })
           .signers([vaultsAdmin])
           .rpc();
@@ -3551,7 +3585,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -3569,15 +3603,17 @@ describe("Roles and Permissions Tests", () => {
 
       try {
         await vaultProgram.methods
-          .deposit(new BN(depositAmount))
+          .deposit(new BN(depositAmount), null, null)
           .accounts({
             vault: vault,
             accountant: accountant,
             user: vaultsAdmin.publicKey,
+            receiver: vaultsAdmin.publicKey,
             userTokenAccount: tokenAccount,
             userSharesAccount: sharesAccount,
             underlyingMint: underlyingMint,
             tokenProgram: token.TOKEN_PROGRAM_ID,
+            sharesTokenProgram: token.TOKEN_PROGRAM_ID,
           })
           .signers([vaultsAdmin])
           .remainingAccounts([
@@ -3644,6 +3680,9 @@ describe("Roles and Permissions Tests", () => {
           strategy: strategyOne,
           signer: reportingManager.publicKey,
           accountant: accountantOne,
+          underlyingMint: underlyingMint,
+          underlyingTokenProgram: token.TOKEN_PROGRAM_ID,
+          tokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([reportingManager])
         .rpc();
@@ -3692,7 +3731,7 @@ describe("Roles and Permissions Tests", () => {
 
       try {
         await accountantProgram.methods
-          .initAccountant(accountantType)
+          .initAccountant(accountantType, ACCOUNTANT_CONFIG)
           .accounts({
             signer: reportingManager.publicKey,
           })
@@ -4237,7 +4276,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -4254,15 +4293,17 @@ describe("Roles and Permissions Tests", () => {
         .rpc();
 
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: kycVerifiedUserSharesAccount,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -4586,7 +4627,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -4604,15 +4645,17 @@ describe("Roles and Permissions Tests", () => {
 
       try {
         await vaultProgram.methods
-          .deposit(new BN(depositAmount))
+          .deposit(new BN(depositAmount), null, null)
           .accounts({
             vault: vault,
             accountant: accountant,
             user: reportingManager.publicKey,
+            receiver: reportingManager.publicKey,
             userTokenAccount: tokenAccount,
             userSharesAccount: sharesAccount,
             underlyingMint: underlyingMint,
             tokenProgram: token.TOKEN_PROGRAM_ID,
+            sharesTokenProgram: token.TOKEN_PROGRAM_ID,
           })
           .signers([reportingManager])
           .remainingAccounts([
@@ -4715,7 +4758,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -4732,15 +4775,17 @@ describe("Roles and Permissions Tests", () => {
         .rpc();
 
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: userSharesAccount,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -4788,7 +4833,7 @@ describe("Roles and Permissions Tests", () => {
 
       try {
         await accountantProgram.methods
-          .initAccountant(accountantType)
+          .initAccountant(accountantType, ACCOUNTANT_CONFIG)
           .accounts({
             signer: kycVerifiedUser.publicKey,
           })
@@ -5356,7 +5401,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -5373,15 +5418,17 @@ describe("Roles and Permissions Tests", () => {
         .rpc();
 
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: kycVerifiedUserSharesAccount,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -5711,7 +5758,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -5728,15 +5775,17 @@ describe("Roles and Permissions Tests", () => {
         .rpc();
 
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: kycVerifiedUserSharesAccount,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -5784,6 +5833,9 @@ describe("Roles and Permissions Tests", () => {
             strategy: strategyOne,
             signer: kycVerifiedUser.publicKey,
             accountant: accountantOne,
+            underlyingMint: underlyingMint,
+            underlyingTokenProgram: token.TOKEN_PROGRAM_ID,
+            tokenProgram: token.TOKEN_PROGRAM_ID,
           })
           .signers([kycVerifiedUser])
           .rpc();
@@ -5859,7 +5911,7 @@ describe("Roles and Permissions Tests", () => {
 
       const accountantType = { generic: {} };
 
-      await accountantProgram.methods.initAccountant(accountantType)
+      await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
         .accounts({
           signer: accountantAdmin.publicKey,
         })
@@ -5877,15 +5929,17 @@ describe("Roles and Permissions Tests", () => {
 
       try {
         await vaultProgram.methods
-          .deposit(new BN(depositAmount))
+          .deposit(new BN(depositAmount), null, null)
           .accounts({
             vault: vault,
             accountant: accountant,
             user: nonVerifiedUser.publicKey,
+            receiver: nonVerifiedUser.publicKey,
             userTokenAccount: nonVerifiedUserTokenAccount,
             userSharesAccount: userSharesAccount,
             underlyingMint: underlyingMint,
             tokenProgram: token.TOKEN_PROGRAM_ID,
+            sharesTokenProgram: token.TOKEN_PROGRAM_ID,
           })
           .signers([nonVerifiedUser])
           .remainingAccounts([
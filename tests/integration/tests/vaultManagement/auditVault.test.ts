@@ -0,0 +1,95 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import { configOwner, connection, vaultProgram } from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import {
+  createTestUser,
+  depositInto,
+  setupTestVault,
+} from "../../../utils/helpers";
+
+describe("Vault Management: Audit Vault Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+  let vaultTokenAccount: anchor.web3.PublicKey;
+
+  before(async () => {
+    ({ admin, underlyingMint, accountant, vault, sharesMint, vaultTokenAccount } =
+      await setupTestVault());
+
+    await vaultProgram.methods
+      .setAuditPauseThreshold(new BN(100))
+      .accounts({
+        vault,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const depositor = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      ...depositor,
+      amount: 1000000,
+    });
+  });
+
+  it("Auditing with strategies the vault doesn't have should revert", async () => {
+    try {
+      await vaultProgram.methods
+        .auditVault()
+        .accounts({
+          vault,
+        })
+        .remainingAccounts([
+          {
+            pubkey: anchor.web3.Keypair.generate().publicKey,
+            isWritable: false,
+            isSigner: false,
+          },
+          {
+            pubkey: anchor.web3.Keypair.generate().publicKey,
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .rpc();
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.incompleteStrategyList);
+    }
+  });
+
+  it("Donations to the vault don't pause deposits", async () => {
+    await token.mintTo(
+      connection,
+      configOwner,
+      underlyingMint,
+      vaultTokenAccount,
+      configOwner.publicKey,
+      500000
+    );
+
+    await vaultProgram.methods
+      .auditVault()
+      .accounts({
+        vault,
+      })
+      .rpc();
+
+    const vaultAccount = await vaultProgram.account.vault.fetch(vault);
+    assert.strictEqual(vaultAccount.pauseFlags, 0);
+    assert.strictEqual(vaultAccount.totalIdle.toString(), "1000000");
+  });
+});
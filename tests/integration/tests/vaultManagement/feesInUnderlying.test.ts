@@ -0,0 +1,101 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import { vaultProgram } from "../../setups/globalSetup";
+import { ACCOUNTANT_CONFIG, errorStrings } from "../../../utils/constants";
+import {
+  createTestUser,
+  depositInto,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+
+describe("Vault Management: Fees In Underlying Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+
+  let accountantTokenAccount: anchor.web3.PublicKey;
+  let accountantSharesAccount: anchor.web3.PublicKey;
+
+  before(async () => {
+    ({ admin, underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault({
+        // 10% entry fee
+        accountantConfig: { ...ACCOUNTANT_CONFIG, entryFee: new BN(1000) },
+      }));
+
+    accountantTokenAccount = token.getAssociatedTokenAddressSync(
+      underlyingMint,
+      accountant,
+      true
+    );
+    accountantSharesAccount = token.getAssociatedTokenAddressSync(
+      sharesMint,
+      accountant,
+      true
+    );
+  });
+
+  it("Enabling fees in underlying without the vaults admin role should revert", async () => {
+    const { user } = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 0,
+    });
+
+    try {
+      await vaultProgram.methods
+        .setFeesInUnderlying(true)
+        .accounts({
+          vault,
+          signer: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(
+        errorStrings.accountExpectedToAlreadyBeInitialized
+      );
+    }
+
+    const vaultAccount = await vaultProgram.account.vault.fetch(vault);
+    assert.isFalse(vaultAccount.feesInUnderlying);
+  });
+
+  it("Entry fee is paid to the accountant in underlying instead of shares", async () => {
+    await vaultProgram.methods
+      .setFeesInUnderlying(true)
+      .accounts({
+        vault,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const depositor = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      ...depositor,
+      amount: 1000000,
+    });
+
+    assert.strictEqual(await tokenBalance(accountantTokenAccount), "100000");
+    assert.strictEqual(await tokenBalance(accountantSharesAccount), "0");
+    assert.strictEqual(await tokenBalance(depositor.sharesAccount), "900000");
+
+    const vaultAccount = await vaultProgram.account.vault.fetch(vault);
+    assert.strictEqual(vaultAccount.totalIdle.toString(), "900000");
+    assert.strictEqual(vaultAccount.totalShares.toString(), "900000");
+  });
+});
@@ -0,0 +1,88 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert, expect } from "chai";
+import {
+  accountantProgram,
+  connection,
+  vaultProgram,
+} from "../../setups/globalSetup";
+import { errorStrings, ROLES } from "../../../utils/constants";
+import { airdrop, setRoles, setupTestVault } from "../../../utils/helpers";
+
+const PAUSE_DEPOSITS = 1 << 0;
+
+describe("Vault Management: Guardian Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let vault: anchor.web3.PublicKey;
+  let guardian: anchor.web3.Keypair;
+
+  before(async () => {
+    ({ admin, vault } = await setupTestVault());
+
+    guardian = anchor.web3.Keypair.generate();
+    await airdrop({ connection, publicKey: guardian.publicKey, amount: 10e9 });
+    await setRoles({ user: guardian.publicKey, roles: [ROLES.GUARDIAN] });
+  });
+
+  it("Pausing a vault without the guardian role should revert", async () => {
+    try {
+      await vaultProgram.methods
+        .pauseVault(PAUSE_DEPOSITS)
+        .accounts({
+          vault,
+          signer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(
+        errorStrings.accountExpectedToAlreadyBeInitialized
+      );
+    }
+
+    const vaultAccount = await vaultProgram.account.vault.fetch(vault);
+    assert.strictEqual(vaultAccount.pauseFlags, 0);
+  });
+
+  it("Guardian pauses a vault and the accountants", async () => {
+    await vaultProgram.methods
+      .pauseVault(PAUSE_DEPOSITS)
+      .accounts({
+        vault,
+        signer: guardian.publicKey,
+      })
+      .signers([guardian])
+      .rpc();
+
+    const vaultAccount = await vaultProgram.account.vault.fetch(vault);
+    assert.strictEqual(vaultAccount.pauseFlags, PAUSE_DEPOSITS);
+
+    await accountantProgram.methods
+      .pauseAccountants()
+      .accounts({
+        signer: guardian.publicKey,
+      })
+      .signers([guardian])
+      .rpc();
+
+    const pauseState = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("pause")],
+      accountantProgram.programId
+    )[0];
+    assert.isTrue(
+      (await accountantProgram.account.pauseState.fetch(pauseState)).paused
+    );
+
+    // the pause is global, lift it so the other accountant tests keep running
+    await accountantProgram.methods
+      .unpauseAccountants()
+      .accounts({
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+    assert.isFalse(
+      (await accountantProgram.account.pauseState.fetch(pauseState)).paused
+    );
+  });
+});
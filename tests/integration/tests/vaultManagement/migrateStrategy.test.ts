@@ -0,0 +1,55 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { assert, expect } from "chai";
+import { connection, strategyProgram } from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import {
+  initializeSimpleStrategy,
+  setupTestVault,
+} from "../../../utils/helpers";
+import { SimpleStrategyConfig } from "../../../utils/schemas";
+
+// older layouts can't be created from here, migrating them is covered by the program's own tests
+describe("Vault Management: Migrate Strategy Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let strategy: anchor.web3.PublicKey;
+
+  before(async () => {
+    let vault: anchor.web3.PublicKey;
+    let underlyingMint: anchor.web3.PublicKey;
+    ({ admin, vault, underlyingMint } = await setupTestVault());
+
+    [strategy] = await initializeSimpleStrategy({
+      strategyProgram,
+      vault,
+      underlyingMint,
+      signer: admin,
+      config: new SimpleStrategyConfig({
+        depositLimit: new BN(1000),
+        performanceFee: new BN(1000),
+        feeManager: admin.publicKey,
+      }),
+    });
+  });
+
+  it("Migrating a strategy already on the latest layout should revert", async () => {
+    const before = await connection.getAccountInfo(strategy);
+
+    try {
+      await strategyProgram.methods
+        .migrateStrategyAccount()
+        .accounts({
+          strategy,
+          signer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.strategyAlreadyMigrated);
+    }
+
+    const after = await connection.getAccountInfo(strategy);
+    assert.isTrue(before.data.equals(after.data));
+  });
+});
@@ -0,0 +1,55 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert, expect } from "chai";
+import { connection, vaultProgram } from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import { airdrop, setupTestVault } from "../../../utils/helpers";
+
+// older layouts can't be created from here, migrating them is covered by the program's own tests
+describe("Vault Management: Migrate Vault Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let vault: anchor.web3.PublicKey;
+
+  before(async () => {
+    ({ admin, vault } = await setupTestVault());
+  });
+
+  it("Migrating a vault without the vaults admin role should revert", async () => {
+    const user = anchor.web3.Keypair.generate();
+    await airdrop({ connection, publicKey: user.publicKey, amount: 10e9 });
+
+    try {
+      await vaultProgram.methods
+        .migrateVault()
+        .accounts({
+          vault,
+          signer: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(
+        errorStrings.accountExpectedToAlreadyBeInitialized
+      );
+    }
+  });
+
+  it("Migrating a vault already on the latest layout should revert", async () => {
+    try {
+      await vaultProgram.methods
+        .migrateVault()
+        .accounts({
+          vault,
+          signer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.vaultAlreadyMigrated);
+    }
+
+    const vaultAccount = await vaultProgram.account.vault.fetch(vault);
+    assert.isAbove(vaultAccount.version, 0);
+  });
+});
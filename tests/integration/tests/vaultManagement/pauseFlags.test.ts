@@ -0,0 +1,100 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import { vaultProgram } from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import {
+  createTestUser,
+  depositInto,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+
+const PAUSE_DEPOSITS = 1 << 0;
+
+describe("Vault Management: Pause Flags Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+
+  let depositor: Awaited<ReturnType<typeof createTestUser>>;
+
+  const setPauseFlags = (flags: number) =>
+    vaultProgram.methods
+      .setPauseFlags(flags)
+      .accounts({
+        vault,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+  before(async () => {
+    ({ admin, underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault());
+
+    depositor = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 2000000,
+    });
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      ...depositor,
+      amount: 1000000,
+    });
+  });
+
+  it("Setting unknown pause flags should revert", async () => {
+    try {
+      await setPauseFlags(1 << 7);
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.invalidPauseFlags);
+    }
+
+    const vaultAccount = await vaultProgram.account.vault.fetch(vault);
+    assert.strictEqual(vaultAccount.pauseFlags, 0);
+  });
+
+  it("Pausing deposits leaves withdrawals open", async () => {
+    await setPauseFlags(PAUSE_DEPOSITS);
+
+    try {
+      await depositInto({
+        vault,
+        accountant,
+        underlyingMint,
+        ...depositor,
+        amount: 1000000,
+      });
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.vaultPaused);
+    }
+
+    await vaultProgram.methods
+      .redeem(new BN(1000000), new BN(0), { accountsMap: [] })
+      .accounts({
+        vault,
+        accountant,
+        underlyingMint,
+        user: depositor.user.publicKey,
+        owner: depositor.user.publicKey,
+        userTokenAccount: depositor.tokenAccount,
+        userSharesAccount: depositor.sharesAccount,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([depositor.user])
+      .rpc();
+
+    assert.strictEqual(await tokenBalance(depositor.sharesAccount), "0");
+    assert.strictEqual(await tokenBalance(depositor.tokenAccount), "2000000");
+  });
+});
@@ -0,0 +1,91 @@
+import * as anchor from "@coral-xyz/anchor";
+import { assert, expect } from "chai";
+import { connection, vaultProgram } from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import {
+  airdrop,
+  createTestUser,
+  depositInto,
+  setupTestVault,
+} from "../../../utils/helpers";
+
+describe("Vault Management: Price Feed Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+  let priceFeed: anchor.web3.PublicKey;
+
+  before(async () => {
+    ({ admin, underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault({ decimals: 9 }));
+
+    priceFeed = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("price_feed"), vault.toBuffer()],
+      vaultProgram.programId
+    )[0];
+  });
+
+  it("Initializing a price feed without the vaults admin role should revert", async () => {
+    const user = anchor.web3.Keypair.generate();
+    await airdrop({ connection, publicKey: user.publicKey, amount: 10e9 });
+
+    try {
+      await vaultProgram.methods
+        .initPriceFeed()
+        .accounts({
+          vault,
+          signer: user.publicKey,
+        })
+        .signers([user])
+        .rpc();
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(
+        errorStrings.accountExpectedToAlreadyBeInitialized
+      );
+    }
+
+    assert.isNull(await connection.getAccountInfo(priceFeed));
+  });
+
+  it("Price feed reports the share price once refreshed", async () => {
+    await vaultProgram.methods
+      .initPriceFeed()
+      .accounts({
+        vault,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const depositor = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      ...depositor,
+      amount: 1000000,
+    });
+
+    await vaultProgram.methods
+      .refreshPrice()
+      .accounts({
+        vault,
+      })
+      .rpc();
+
+    const feed = await vaultProgram.account.priceFeed.fetch(priceFeed);
+    assert.strictEqual(feed.vault.toBase58(), vault.toBase58());
+    // one share token is worth one underlying token: 10^15 * 10^-(6 + 9)
+    assert.strictEqual(feed.price.toString(), "1000000000000000");
+    assert.strictEqual(feed.exponent, -15);
+    assert.strictEqual(feed.confidence.toString(), "0");
+    assert.isAbove(feed.lastUpdateTimestamp.toNumber(), 0);
+  });
+});
@@ -0,0 +1,150 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import { configOwner, connection, vaultProgram } from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import {
+  createTestUser,
+  depositInto,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+
+describe("Vault Management: Sweep Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+  let vaultTokenAccount: anchor.web3.PublicKey;
+
+  const sweep = ({
+    tokenAccount,
+    mint,
+    recipient,
+  }: {
+    tokenAccount: anchor.web3.PublicKey;
+    mint: anchor.web3.PublicKey;
+    recipient: anchor.web3.PublicKey;
+  }) =>
+    vaultProgram.methods
+      .sweep()
+      .accounts({
+        vault,
+        tokenAccount,
+        mint,
+        recipient,
+        signer: admin.publicKey,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+  before(async () => {
+    ({ admin, underlyingMint, accountant, vault, sharesMint, vaultTokenAccount } =
+      await setupTestVault());
+
+    const depositor = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      ...depositor,
+      amount: 1000000,
+    });
+  });
+
+  it("Sweeping the underlying should revert", async () => {
+    const recipient = await token.createAccount(
+      connection,
+      admin,
+      underlyingMint,
+      admin.publicKey
+    );
+
+    try {
+      await sweep({
+        tokenAccount: vaultTokenAccount,
+        mint: underlyingMint,
+        recipient,
+      });
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.invalidSweepMint);
+    }
+
+    assert.strictEqual(await tokenBalance(vaultTokenAccount), "1000000");
+    assert.strictEqual(await tokenBalance(recipient), "0");
+  });
+
+  it("Stray tokens are swept to the recipient", async () => {
+    const strayMint = await token.createMint(
+      connection,
+      configOwner,
+      configOwner.publicKey,
+      null,
+      9
+    );
+    const strayAccount = await token.createAssociatedTokenAccount(
+      connection,
+      admin,
+      strayMint,
+      vault,
+      undefined,
+      undefined,
+      undefined,
+      true
+    );
+    await token.mintTo(
+      connection,
+      configOwner,
+      strayMint,
+      strayAccount,
+      configOwner.publicKey,
+      1000
+    );
+    const recipient = await token.createAccount(
+      connection,
+      admin,
+      strayMint,
+      admin.publicKey
+    );
+
+    await sweep({ tokenAccount: strayAccount, mint: strayMint, recipient });
+
+    assert.strictEqual(await tokenBalance(strayAccount), "0");
+    assert.strictEqual(await tokenBalance(recipient), "1000");
+  });
+
+  it("Syncing idle on a tranched vault should revert", async () => {
+    const tranched = await setupTestVault();
+    await vaultProgram.methods
+      .enableTranches(new BN(500))
+      .accounts({
+        vault: tranched.vault,
+        signer: tranched.admin.publicKey,
+      })
+      .signers([tranched.admin])
+      .rpc();
+
+    try {
+      await vaultProgram.methods
+        .syncIdle()
+        .accounts({
+          vault: tranched.vault,
+          signer: tranched.admin.publicKey,
+          tokenProgram: token.TOKEN_PROGRAM_ID,
+        })
+        .signers([tranched.admin])
+        .rpc();
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.vaultTranched);
+    }
+  });
+});
@@ -0,0 +1,118 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import { connection, vaultProgram } from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import {
+  createTestUser,
+  depositInto,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+
+describe("Vault Management: Tranches Tests", () => {
+  // 5% a year for the senior tranche
+  const seniorTargetRate = new BN(500);
+
+  it("Enabling tranches on a vault holding deposits should revert", async () => {
+    const { admin, underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault();
+
+    const depositor = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      ...depositor,
+      amount: 1000000,
+    });
+
+    try {
+      await vaultProgram.methods
+        .enableTranches(seniorTargetRate)
+        .accounts({
+          vault,
+          signer: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.vaultNotEmpty);
+    }
+
+    const vaultAccount = await vaultProgram.account.vault.fetch(vault);
+    assert.isFalse(vaultAccount.isTranched);
+  });
+
+  it("Senior and junior deposits are tracked per tranche", async () => {
+    const { admin, underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault();
+
+    await vaultProgram.methods
+      .enableTranches(seniorTargetRate)
+      .accounts({
+        vault,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    const juniorSharesMint = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("junior_shares"), vault.toBuffer()],
+      vaultProgram.programId
+    )[0];
+
+    const senior = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      ...senior,
+      amount: 1000000,
+    });
+
+    const junior = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 500000,
+    });
+    const juniorSharesAccount = await token.createAccount(
+      connection,
+      junior.user,
+      juniorSharesMint,
+      junior.user.publicKey
+    );
+
+    await vaultProgram.methods
+      .depositJunior(new BN(500000))
+      .accounts({
+        vault,
+        userTokenAccount: junior.tokenAccount,
+        underlyingMint,
+        userSharesAccount: juniorSharesAccount,
+        user: junior.user.publicKey,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([junior.user])
+      .rpc();
+
+    assert.strictEqual(await tokenBalance(senior.sharesAccount), "1000000");
+    assert.strictEqual(await tokenBalance(juniorSharesAccount), "500000");
+
+    const vaultAccount = await vaultProgram.account.vault.fetch(vault);
+    assert.isTrue(vaultAccount.isTranched);
+    assert.strictEqual(vaultAccount.totalIdle.toString(), "1500000");
+    assert.strictEqual(vaultAccount.seniorAssets.toString(), "1000000");
+    assert.strictEqual(vaultAccount.juniorTotalShares.toString(), "500000");
+  });
+});
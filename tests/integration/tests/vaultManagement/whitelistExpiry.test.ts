@@ -0,0 +1,92 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import { assert, expect } from "chai";
+import { connection, vaultProgram } from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import {
+  createTestUser,
+  depositInto,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+
+describe("Vault Management: Whitelist Expiry Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+
+  const whitelistWithExpiry = (user: anchor.web3.PublicKey, expiresAt: number) =>
+    vaultProgram.methods
+      .whitelistWithExpiry(user, new BN(expiresAt))
+      .accounts({
+        vault,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+  const now = async () => {
+    const slot = await connection.getSlot();
+    return await connection.getBlockTime(slot);
+  };
+
+  before(async () => {
+    ({ admin, underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault({
+        vaultConfig: { whitelistedOnly: true },
+      }));
+  });
+
+  it("Whitelisting with an expiry in the past should revert", async () => {
+    const { user } = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 0,
+    });
+
+    try {
+      await whitelistWithExpiry(user.publicKey, (await now()) - 60);
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.invalidExpiry);
+    }
+  });
+
+  it("User whitelisted until a future expiry can deposit", async () => {
+    const depositor = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+    const expiresAt = (await now()) + 3600;
+
+    await whitelistWithExpiry(depositor.user.publicKey, expiresAt);
+
+    const userData = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("user_data"),
+        vault.toBuffer(),
+        depositor.user.publicKey.toBuffer(),
+      ],
+      vaultProgram.programId
+    )[0];
+    const userDataAccount = await vaultProgram.account.userData.fetch(userData);
+    assert.isTrue(userDataAccount.whitelisted);
+    assert.strictEqual(
+      userDataAccount.whitelistExpiresAt.toNumber(),
+      expiresAt
+    );
+
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      ...depositor,
+      amount: 1000000,
+    });
+
+    assert.strictEqual(await tokenBalance(depositor.sharesAccount), "1000000");
+  });
+});
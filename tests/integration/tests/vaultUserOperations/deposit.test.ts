@@ -11,7 +11,12 @@ import {
   TOKEN_METADATA_PROGRAM_ID,
 } from "../../setups/globalSetup";
 import { assert, expect } from "chai";
-import { errorStrings, ROLES, ROLES_BUFFER } from "../../../utils/constants";
+import {
+  ACCOUNTANT_CONFIG,
+  errorStrings,
+  ROLES,
+  ROLES_BUFFER,
+} from "../../../utils/constants";
 import { BN } from "@coral-xyz/anchor";
 import {
   airdrop,
@@ -198,7 +203,7 @@ describe("Vault User Operations: Deposit Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: generalAdmin.publicKey,
       })
@@ -436,15 +441,17 @@ describe("Vault User Operations: Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vaultOne,
           accountant: accountantOne,
           user: kycVerifiedWhitelistedUser.publicKey,
+          receiver: kycVerifiedWhitelistedUser.publicKey,
           userTokenAccount: kycVerifiedWhitelistedUserTokenAccount,
           userSharesAccount: kycVerifiedWhitelistedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedWhitelistedUser])
         .remainingAccounts([
@@ -488,15 +495,17 @@ describe("Vault User Operations: Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vaultOne,
           accountant: accountantOne,
           user: kycVerifiedWhitelistedUser.publicKey,
+          receiver: kycVerifiedWhitelistedUser.publicKey,
           userTokenAccount: kycVerifiedWhitelistedUserTokenAccount,
           userSharesAccount: kycVerifiedWhitelistedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedWhitelistedUser])
         .remainingAccounts([
@@ -540,15 +549,17 @@ describe("Vault User Operations: Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vaultOne,
           accountant: accountantOne,
           user: kycVerifiedWhitelistedUser.publicKey,
+          receiver: kycVerifiedWhitelistedUser.publicKey,
           userTokenAccount: kycVerifiedWhitelistedUserTokenAccount,
           userSharesAccount: kycVerifiedWhitelistedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedWhitelistedUser])
         .remainingAccounts([
@@ -638,7 +649,7 @@ describe("Vault User Operations: Deposit Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: generalAdmin.publicKey,
       })
@@ -663,15 +674,17 @@ describe("Vault User Operations: Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: kycVerifiedWhitelistedUser.publicKey,
+          receiver: kycVerifiedWhitelistedUser.publicKey,
           userTokenAccount: kycVerifiedWhitelistedUserTokenAccount,
           userSharesAccount: userSharesAccount,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedWhitelistedUser])
         .remainingAccounts([
@@ -714,15 +727,17 @@ describe("Vault User Operations: Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vaultOne,
           accountant: accountantOne,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: kycVerifiedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -765,15 +780,17 @@ describe("Vault User Operations: Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .deposit(new BN(depositAmount))
+        .deposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vaultOne,
           accountant: accountantOne,
           user: whitelistedUser.publicKey,
+          receiver: whitelistedUser.publicKey,
           userTokenAccount: whitelistedUserTokenAccount,
           userSharesAccount: whitelistedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([whitelistedUser])
         .remainingAccounts([
@@ -815,15 +832,17 @@ describe("Vault User Operations: Deposit Tests", () => {
     )[0];
 
     await vaultProgram.methods
-      .deposit(new BN(depositAmount))
+      .deposit(new BN(depositAmount), null, null)
       .accounts({
         vault: vaultOne,
         accountant: accountantOne,
         user: kycVerifiedWhitelistedUser.publicKey,
+        receiver: kycVerifiedWhitelistedUser.publicKey,
         userTokenAccount: kycVerifiedWhitelistedUserTokenAccount,
         userSharesAccount: kycVerifiedWhitelistedUserSharesAccountVaultOne,
         underlyingMint: underlyingMint,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([kycVerifiedWhitelistedUser])
       .remainingAccounts([
@@ -873,7 +892,7 @@ describe("Vault User Operations: Deposit Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: generalAdmin.publicKey,
       })
@@ -942,15 +961,17 @@ describe("Vault User Operations: Deposit Tests", () => {
     );
 
     await vaultProgram.methods
-      .deposit(new BN(depositAmount))
+      .deposit(new BN(depositAmount), null, null)
       .accounts({
         vault: vault,
         accountant: accountant,
         user: whitelistedUser.publicKey,
+        receiver: whitelistedUser.publicKey,
         userTokenAccount: whitelistedUserTokenAccount,
         userSharesAccount: userSharesAccount,
         underlyingMint: underlyingMint,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([whitelistedUser])
       .rpc();
@@ -989,7 +1010,7 @@ describe("Vault User Operations: Deposit Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: generalAdmin.publicKey,
       })
@@ -1049,15 +1070,17 @@ describe("Vault User Operations: Deposit Tests", () => {
     );
 
     await vaultProgram.methods
-      .deposit(new BN(depositAmount))
+      .deposit(new BN(depositAmount), null, null)
       .accounts({
         vault: vault,
         accountant: accountant,
         user: nonVerifiedUser.publicKey,
+        receiver: nonVerifiedUser.publicKey,
         userTokenAccount: nonVerifiedUserTokenAccount,
         userSharesAccount: userSharesAccount,
         underlyingMint: underlyingMint,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([nonVerifiedUser])
       .rpc();
@@ -11,7 +11,12 @@ import {
   TOKEN_METADATA_PROGRAM_ID,
 } from "../../setups/globalSetup";
 import { assert, expect } from "chai";
-import { errorStrings, ROLES, ROLES_BUFFER } from "../../../utils/constants";
+import {
+  ACCOUNTANT_CONFIG,
+  errorStrings,
+  ROLES,
+  ROLES_BUFFER,
+} from "../../../utils/constants";
 import { BN } from "@coral-xyz/anchor";
 import {
   airdrop,
@@ -200,7 +205,7 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: generalAdmin.publicKey,
       })
@@ -441,16 +446,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .directDeposit(new BN(depositAmount))
+        .directDeposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vaultOne,
           accountant: accountantOne,
           user: kycVerifiedWhitelistedUser.publicKey,
+          receiver: kycVerifiedWhitelistedUser.publicKey,
           userTokenAccount: kycVerifiedWhitelistedUserTokenAccount,
           userSharesAccount: kycVerifiedWhitelistedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           strategy: strategyOne,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedWhitelistedUser])
         .remainingAccounts([
@@ -498,16 +505,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .directDeposit(new BN(depositAmount))
+        .directDeposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vaultOne,
           accountant: accountantOne,
           user: kycVerifiedWhitelistedUser.publicKey,
+          receiver: kycVerifiedWhitelistedUser.publicKey,
           userTokenAccount: kycVerifiedWhitelistedUserTokenAccount,
           userSharesAccount: kycVerifiedWhitelistedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           strategy: strategyOne,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedWhitelistedUser])
         .remainingAccounts([
@@ -555,16 +564,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .directDeposit(new BN(depositAmount))
+        .directDeposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vaultOne,
           accountant: accountantOne,
           user: kycVerifiedWhitelistedUser.publicKey,
+          receiver: kycVerifiedWhitelistedUser.publicKey,
           userTokenAccount: kycVerifiedWhitelistedUserTokenAccount,
           userSharesAccount: kycVerifiedWhitelistedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           strategy: strategyOne,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedWhitelistedUser])
         .remainingAccounts([
@@ -683,7 +694,7 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: generalAdmin.publicKey,
       })
@@ -708,16 +719,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .directDeposit(new BN(depositAmount))
+        .directDeposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: kycVerifiedWhitelistedUser.publicKey,
+          receiver: kycVerifiedWhitelistedUser.publicKey,
           userTokenAccount: kycVerifiedWhitelistedUserTokenAccount,
           userSharesAccount: kycVerifiedWhitelistedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           strategy: strategy,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedWhitelistedUser])
         .remainingAccounts([
@@ -764,16 +777,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .directDeposit(new BN(depositAmount))
+        .directDeposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vaultOne,
           accountant: accountantOne,
           user: kycVerifiedUser.publicKey,
+          receiver: kycVerifiedUser.publicKey,
           userTokenAccount: kycVerifiedUserTokenAccount,
           userSharesAccount: kycVerifiedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           strategy: strategyOne,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([kycVerifiedUser])
         .remainingAccounts([
@@ -820,16 +835,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .directDeposit(new BN(depositAmount))
+        .directDeposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vaultOne,
           accountant: accountantOne,
           user: whitelistedUser.publicKey,
+          receiver: whitelistedUser.publicKey,
           userTokenAccount: whitelistedUserTokenAccount,
           userSharesAccount: whitelistedUserSharesAccountVaultOne,
           underlyingMint: underlyingMint,
           strategy: strategyOne,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([whitelistedUser])
         .remainingAccounts([
@@ -875,16 +892,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
     )[0];
 
     await vaultProgram.methods
-      .directDeposit(new BN(depositAmount))
+      .directDeposit(new BN(depositAmount), null, null)
       .accounts({
         vault: vaultOne,
         accountant: accountantOne,
         user: kycVerifiedWhitelistedUser.publicKey,
+        receiver: kycVerifiedWhitelistedUser.publicKey,
         userTokenAccount: kycVerifiedWhitelistedUserTokenAccount,
         userSharesAccount: kycVerifiedWhitelistedUserSharesAccountVaultOne,
         underlyingMint: underlyingMint,
         strategy: strategyOne,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([kycVerifiedWhitelistedUser])
       .remainingAccounts([
@@ -940,7 +959,7 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: generalAdmin.publicKey,
       })
@@ -1043,16 +1062,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
     )[0];
 
     await vaultProgram.methods
-      .directDeposit(new BN(depositAmount))
+      .directDeposit(new BN(depositAmount), null, null)
       .accounts({
         vault: vault,
         accountant: accountant,
         user: whitelistedUser.publicKey,
+        receiver: whitelistedUser.publicKey,
         userTokenAccount: whitelistedUserTokenAccount,
         userSharesAccount: userSharesAccount,
         underlyingMint: underlyingMint,
         strategy: strategy,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([whitelistedUser])
       .remainingAccounts([
@@ -1102,7 +1123,7 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: generalAdmin.publicKey,
       })
@@ -1187,16 +1208,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
     );
 
     await vaultProgram.methods
-      .directDeposit(new BN(depositAmount))
+      .directDeposit(new BN(depositAmount), null, null)
       .accounts({
         vault: vault,
         accountant: accountant,
         user: nonVerifiedUser.publicKey,
+        receiver: nonVerifiedUser.publicKey,
         userTokenAccount: nonVerifiedUserTokenAccount,
         userSharesAccount: userSharesAccount,
         underlyingMint: underlyingMint,
         strategy: strategy,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([nonVerifiedUser])
       .rpc();
@@ -1239,7 +1262,7 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: generalAdmin.publicKey,
       })
@@ -1325,16 +1348,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .directDeposit(new BN(depositAmount))
+        .directDeposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: nonVerifiedUser.publicKey,
+          receiver: nonVerifiedUser.publicKey,
           userTokenAccount: nonVerifiedUserTokenAccount,
           userSharesAccount: userSharesAccount,
           underlyingMint: underlyingMint,
           strategy: strategy,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([nonVerifiedUser])
         .rpc();
@@ -1378,7 +1403,7 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: generalAdmin.publicKey,
       })
@@ -1464,16 +1489,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .directDeposit(new BN(depositAmount))
+        .directDeposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: nonVerifiedUser.publicKey,
+          receiver: nonVerifiedUser.publicKey,
           userTokenAccount: nonVerifiedUserTokenAccount,
           userSharesAccount: userSharesAccount,
           underlyingMint: underlyingMint,
           strategy: strategy,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([nonVerifiedUser])
         .rpc();
@@ -1517,7 +1544,7 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
     )[0];
 
     await accountantProgram.methods
-      .initAccountant(accountantType)
+      .initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: generalAdmin.publicKey,
       })
@@ -1603,16 +1630,18 @@ describe("Vault User Operations: Direct Deposit Tests", () => {
 
     try {
       await vaultProgram.methods
-        .directDeposit(new BN(depositAmount))
+        .directDeposit(new BN(depositAmount), null, null)
         .accounts({
           vault: vault,
           accountant: accountant,
           user: nonVerifiedUser.publicKey,
+          receiver: nonVerifiedUser.publicKey,
           userTokenAccount: nonVerifiedUserTokenAccount,
           userSharesAccount: userSharesAccount,
           underlyingMint: underlyingMint,
           strategy: strategy,
           tokenProgram: token.TOKEN_PROGRAM_ID,
+          sharesTokenProgram: token.TOKEN_PROGRAM_ID,
         })
         .signers([nonVerifiedUser])
         .rpc();
@@ -0,0 +1,261 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import {
+  accessControlProgram,
+  connection,
+  vaultProgram,
+} from "../../setups/globalSetup";
+import {
+  ACCOUNTANT_CONFIG,
+  errorStrings,
+  ROLES,
+} from "../../../utils/constants";
+import {
+  createTestUser,
+  depositInto,
+  setRoles,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+
+describe("Vault User Operations: KYC Shares Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+
+  let depositor: Awaited<ReturnType<typeof createTestUser>>;
+  let verifiedReceiver: Awaited<ReturnType<typeof createTestUser>>;
+  let unverifiedReceiver: Awaited<ReturnType<typeof createTestUser>>;
+
+  const sharesBalance = (account: anchor.web3.PublicKey) =>
+    tokenBalance(account, token.TOKEN_2022_PROGRAM_ID);
+
+  const transferShares = (
+    to: anchor.web3.PublicKey,
+    amount: number
+  ) =>
+    token.transferCheckedWithTransferHook(
+      connection,
+      depositor.user,
+      depositor.sharesAccount,
+      sharesMint,
+      to,
+      depositor.user,
+      BigInt(amount),
+      9,
+      [],
+      undefined,
+      token.TOKEN_2022_PROGRAM_ID
+    );
+
+  const verify = (user: anchor.web3.PublicKey) =>
+    accessControlProgram.methods
+      .setRole(ROLES.KYC_VERIFIED, user)
+      .accounts({
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+  const verifyUntil = (user: anchor.web3.PublicKey, expiresAt: number) =>
+    accessControlProgram.methods
+      .setRoleWithExpiry(ROLES.KYC_VERIFIED, user, new BN(expiresAt))
+      .accounts({
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+  const now = async () => {
+    const slot = await connection.getSlot();
+    return await connection.getBlockTime(slot);
+  };
+
+  const newUser = (amount: number) =>
+    createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount,
+      sharesTokenProgram: token.TOKEN_2022_PROGRAM_ID,
+    });
+
+  before(async () => {
+    ({ admin, underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault({
+        vaultConfig: { directWithdrawEnabled: false },
+        // 10% redemption fee, paid out as shares
+        accountantConfig: { ...ACCOUNTANT_CONFIG, redemptionFee: new BN(1000) },
+        kycShares: true,
+      }));
+    await setRoles({
+      user: admin.publicKey,
+      roles: [ROLES.KYC_PROVIDER, ROLES.COMPLIANCE_OFFICER],
+    });
+
+    await vaultProgram.methods
+      .initWithdrawSharesAccount()
+      .accounts({
+        vault,
+        signer: admin.publicKey,
+        tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+    depositor = await newUser(1000000);
+    verifiedReceiver = await newUser(0);
+    unverifiedReceiver = await newUser(0);
+    await verify(depositor.user.publicKey);
+    await verify(verifiedReceiver.user.publicKey);
+
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      ...depositor,
+      amount: 1000000,
+      sharesTokenProgram: token.TOKEN_2022_PROGRAM_ID,
+    });
+  });
+
+  it("Transferring shares to an unverified receiver should revert", async () => {
+    try {
+      await transferShares(unverifiedReceiver.sharesAccount, 100000);
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.receiverNotVerified);
+    }
+
+    assert.strictEqual(await sharesBalance(depositor.sharesAccount), "1000000");
+    assert.strictEqual(await sharesBalance(unverifiedReceiver.sharesAccount), "0");
+  });
+
+  it("Transferring shares to a KYC verified receiver succeeds", async () => {
+    await transferShares(verifiedReceiver.sharesAccount, 100000);
+
+    assert.strictEqual(await sharesBalance(depositor.sharesAccount), "900000");
+    assert.strictEqual(await sharesBalance(verifiedReceiver.sharesAccount), "100000");
+  });
+
+  it("Transferring shares out of a sanctioned holder's account should revert", async () => {
+    await accessControlProgram.methods
+      .setRole(ROLES.SANCTIONED, verifiedReceiver.user.publicKey)
+      .accounts({
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    try {
+      await token.transferCheckedWithTransferHook(
+        connection,
+        verifiedReceiver.user,
+        verifiedReceiver.sharesAccount,
+        sharesMint,
+        depositor.sharesAccount,
+        verifiedReceiver.user,
+        BigInt(100000),
+        9,
+        [],
+        undefined,
+        token.TOKEN_2022_PROGRAM_ID
+      );
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.senderSanctioned);
+    }
+
+    assert.strictEqual(await sharesBalance(verifiedReceiver.sharesAccount), "100000");
+    assert.strictEqual(await sharesBalance(depositor.sharesAccount), "900000");
+  });
+
+  it("Transferring shares to a receiver whose KYC role expired should revert", async () => {
+    const expiredReceiver = await newUser(0);
+    const expiresAt = (await now()) + 2;
+    await verifyUntil(expiredReceiver.user.publicKey, expiresAt);
+
+    // nobody ran the expire_role crank, the role is still set
+    while ((await now()) <= expiresAt) {
+      await new Promise((resolve) => setTimeout(resolve, 500));
+    }
+
+    try {
+      await transferShares(expiredReceiver.sharesAccount, 100000);
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.receiverNotVerified);
+    }
+
+    assert.strictEqual(await sharesBalance(depositor.sharesAccount), "900000");
+    assert.strictEqual(await sharesBalance(expiredReceiver.sharesAccount), "0");
+  });
+
+  it("Withdrawal requests move shares to the pool and pay fee shares to the accountant", async () => {
+    const config = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("config")],
+      vaultProgram.programId
+    )[0];
+    const { nextWithdrawRequestIndex } = await vaultProgram.account.config.fetch(config);
+
+    // neither the withdraw pool nor the accountant is KYC verified, so these
+    // moves only go through because the vault burns and re-mints the shares
+    await vaultProgram.methods
+      .requestRedeem(new BN(500000), new BN(0))
+      .accounts({
+        vault,
+        accountant,
+        user: depositor.user.publicKey,
+        userSharesAccount: depositor.sharesAccount,
+        userTokenAccount: depositor.tokenAccount,
+        sharesTokenProgram: token.TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([depositor.user])
+      .rpc();
+
+    const withdrawPool = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("withdraw_shares_account"), vault.toBuffer()],
+      vaultProgram.programId
+    )[0];
+    assert.strictEqual(await sharesBalance(depositor.sharesAccount), "450000");
+    assert.strictEqual(await sharesBalance(withdrawPool), "450000");
+
+    const withdrawRequest = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("withdraw_request"),
+        vault.toBuffer(),
+        depositor.user.publicKey.toBuffer(),
+        nextWithdrawRequestIndex.toArrayLike(Buffer, "le", 8),
+      ],
+      vaultProgram.programId
+    )[0];
+    await vaultProgram.methods
+      .fulfillWithdrawalRequest()
+      .accounts({
+        withdrawRequest,
+        vault,
+        accountant,
+        user: depositor.user.publicKey,
+        userTokenAccount: depositor.tokenAccount,
+        underlyingMint,
+        signer: admin.publicKey,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+    const accountantSharesAccount = token.getAssociatedTokenAddressSync(
+      sharesMint,
+      accountant,
+      true,
+      token.TOKEN_2022_PROGRAM_ID
+    );
+    assert.strictEqual(await sharesBalance(withdrawPool), "0");
+    assert.strictEqual(await sharesBalance(accountantSharesAccount), "50000");
+    assert.strictEqual(await tokenBalance(depositor.tokenAccount), "400000");
+  });
+});
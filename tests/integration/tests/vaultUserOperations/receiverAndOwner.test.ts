@@ -0,0 +1,104 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import { connection, vaultProgram } from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import {
+  createTestUser,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+
+describe("Vault User Operations: Receiver And Owner Tests", () => {
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+
+  let payer: Awaited<ReturnType<typeof createTestUser>>;
+  let receiver: Awaited<ReturnType<typeof createTestUser>>;
+  let operator: Awaited<ReturnType<typeof createTestUser>>;
+
+  const remainingAccountsMap = { accountsMap: [] };
+
+  const redeemFromReceiver = (shares: number) =>
+    vaultProgram.methods
+      .redeem(new BN(shares), new BN(0), remainingAccountsMap)
+      .accounts({
+        vault,
+        accountant,
+        underlyingMint,
+        user: operator.user.publicKey,
+        owner: receiver.user.publicKey,
+        userTokenAccount: operator.tokenAccount,
+        userSharesAccount: receiver.sharesAccount,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([operator.user])
+      .rpc();
+
+  before(async () => {
+    ({ underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault());
+
+    payer = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+    receiver = await createTestUser({ underlyingMint, sharesMint, amount: 0 });
+    operator = await createTestUser({ underlyingMint, sharesMint, amount: 0 });
+  });
+
+  it("Depositing for a receiver mints the shares to the receiver", async () => {
+    await vaultProgram.methods
+      .deposit(new BN(1000000), null, null)
+      .accounts({
+        vault,
+        accountant,
+        user: payer.user.publicKey,
+        receiver: receiver.user.publicKey,
+        userTokenAccount: payer.tokenAccount,
+        userSharesAccount: receiver.sharesAccount,
+        underlyingMint,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([payer.user])
+      .rpc();
+
+    assert.strictEqual(await tokenBalance(payer.tokenAccount), "0");
+    assert.strictEqual(await tokenBalance(payer.sharesAccount), "0");
+    assert.strictEqual(await tokenBalance(receiver.sharesAccount), "1000000");
+  });
+
+  it("Redeeming someone else's shares without an approval should revert", async () => {
+    try {
+      await redeemFromReceiver(400000);
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.tokenOwnerMismatch);
+    }
+
+    assert.strictEqual(await tokenBalance(receiver.sharesAccount), "1000000");
+    assert.strictEqual(await tokenBalance(operator.tokenAccount), "0");
+  });
+
+  it("Approved delegate redeems on behalf of the owner", async () => {
+    await token.approve(
+      connection,
+      receiver.user,
+      receiver.sharesAccount,
+      operator.user.publicKey,
+      receiver.user,
+      400000
+    );
+
+    await redeemFromReceiver(400000);
+
+    assert.strictEqual(await tokenBalance(receiver.sharesAccount), "600000");
+    assert.strictEqual(await tokenBalance(operator.tokenAccount), "400000");
+  });
+});
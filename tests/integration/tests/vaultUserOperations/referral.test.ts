@@ -0,0 +1,125 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import { accountantProgram, vaultProgram } from "../../setups/globalSetup";
+import { ACCOUNTANT_CONFIG, errorStrings } from "../../../utils/constants";
+import {
+  createTestUser,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+
+describe("Vault User Operations: Referral Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+
+  let referrer: anchor.web3.Keypair;
+  let referrerSharesAccount: anchor.web3.PublicKey;
+  let accountantSharesAccount: anchor.web3.PublicKey;
+
+  const depositWithReferrer = ({
+    depositor,
+    referrerSharesAccount,
+  }: {
+    depositor: {
+      user: anchor.web3.Keypair;
+      tokenAccount: anchor.web3.PublicKey;
+      sharesAccount: anchor.web3.PublicKey;
+    };
+    referrerSharesAccount: anchor.web3.PublicKey;
+  }) =>
+    vaultProgram.methods
+      .deposit(new BN(1000000), referrer.publicKey, null)
+      .accounts({
+        vault,
+        accountant,
+        user: depositor.user.publicKey,
+        receiver: depositor.user.publicKey,
+        userTokenAccount: depositor.tokenAccount,
+        userSharesAccount: depositor.sharesAccount,
+        referrerSharesAccount,
+        underlyingMint,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([depositor.user])
+      .rpc();
+
+  before(async () => {
+    ({ admin, underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault({
+        // 10% entry fee, half of it goes to the referrer
+        accountantConfig: { ...ACCOUNTANT_CONFIG, entryFee: new BN(1000) },
+      }));
+    await accountantProgram.methods
+      .setReferralFee(new BN(5000))
+      .accounts({
+        accountant,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    ({ user: referrer, sharesAccount: referrerSharesAccount } =
+      await createTestUser({ underlyingMint, sharesMint, amount: 0 }));
+
+    accountantSharesAccount = token.getAssociatedTokenAddressSync(
+      sharesMint,
+      accountant,
+      true
+    );
+  });
+
+  it("Depositing with a referrer but someone else's shares account should revert", async () => {
+    const depositor = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+
+    try {
+      await depositWithReferrer({
+        depositor,
+        referrerSharesAccount: depositor.sharesAccount,
+      });
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.invalidReferrerAccount);
+    }
+
+    assert.strictEqual(await tokenBalance(depositor.tokenAccount), "1000000");
+    assert.strictEqual(await tokenBalance(referrerSharesAccount), "0");
+  });
+
+  it("Referrer receives their share of the entry fee", async () => {
+    const depositor = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+
+    await depositWithReferrer({ depositor, referrerSharesAccount });
+
+    assert.strictEqual(await tokenBalance(depositor.sharesAccount), "900000");
+    assert.strictEqual(await tokenBalance(referrerSharesAccount), "50000");
+    assert.strictEqual(await tokenBalance(accountantSharesAccount), "50000");
+
+    const referral = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("referral"),
+        vault.toBuffer(),
+        depositor.user.publicKey.toBuffer(),
+      ],
+      vaultProgram.programId
+    )[0];
+    const referralAccount = await vaultProgram.account.referral.fetch(referral);
+    assert.strictEqual(
+      referralAccount.referrer.toBase58(),
+      referrer.publicKey.toBase58()
+    );
+  });
+});
@@ -0,0 +1,178 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import {
+  accessControlProgram,
+  connection,
+  vaultProgram,
+} from "../../setups/globalSetup";
+import { errorStrings, ROLES } from "../../../utils/constants";
+import {
+  airdrop,
+  createTestUser,
+  depositInto,
+  setRoles,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+
+describe("Vault User Operations: Sanctions Tests", () => {
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+
+  let complianceOfficer: anchor.web3.Keypair;
+  let depositor: Awaited<ReturnType<typeof createTestUser>>;
+
+  const remainingAccountsMap = { accountsMap: [] };
+
+  const sanction = (user: anchor.web3.PublicKey) =>
+    accessControlProgram.methods
+      .setRole(ROLES.SANCTIONED, user)
+      .accounts({
+        signer: complianceOfficer.publicKey,
+      })
+      .signers([complianceOfficer])
+      .rpc();
+
+  const redeem = (shares: number) =>
+    vaultProgram.methods
+      .redeem(new BN(shares), new BN(0), remainingAccountsMap)
+      .accounts({
+        vault,
+        accountant,
+        underlyingMint,
+        user: depositor.user.publicKey,
+        owner: depositor.user.publicKey,
+        userTokenAccount: depositor.tokenAccount,
+        userSharesAccount: depositor.sharesAccount,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([depositor.user])
+      .rpc();
+
+  before(async () => {
+    ({ underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault());
+
+    complianceOfficer = anchor.web3.Keypair.generate();
+    await airdrop({
+      connection,
+      publicKey: complianceOfficer.publicKey,
+      amount: 10e9,
+    });
+    await setRoles({
+      user: complianceOfficer.publicKey,
+      roles: [ROLES.COMPLIANCE_OFFICER],
+    });
+
+    depositor = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      ...depositor,
+      amount: 1000000,
+    });
+
+    await sanction(depositor.user.publicKey);
+  });
+
+  it("Sanctioned user redeeming should revert", async () => {
+    try {
+      await redeem(1000000);
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.sanctioned);
+    }
+
+    assert.strictEqual(await tokenBalance(depositor.sharesAccount), "1000000");
+    assert.strictEqual(await tokenBalance(depositor.tokenAccount), "0");
+  });
+
+  it("User can redeem once the sanction is revoked", async () => {
+    await accessControlProgram.methods
+      .revokeRole(ROLES.SANCTIONED, depositor.user.publicKey)
+      .accounts({
+        signer: complianceOfficer.publicKey,
+        recipient: complianceOfficer.publicKey,
+      })
+      .signers([complianceOfficer])
+      .rpc();
+
+    await redeem(1000000);
+
+    assert.strictEqual(await tokenBalance(depositor.sharesAccount), "0");
+    assert.strictEqual(await tokenBalance(depositor.tokenAccount), "1000000");
+  });
+
+  it("Sanctioned user redeeming junior shares should revert", async () => {
+    const tranched = await setupTestVault();
+    await vaultProgram.methods
+      .enableTranches(new BN(500))
+      .accounts({
+        vault: tranched.vault,
+        signer: tranched.admin.publicKey,
+      })
+      .signers([tranched.admin])
+      .rpc();
+
+    const juniorSharesMint = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("junior_shares"), tranched.vault.toBuffer()],
+      vaultProgram.programId
+    )[0];
+    const junior = await createTestUser({
+      underlyingMint: tranched.underlyingMint,
+      sharesMint: tranched.sharesMint,
+      amount: 500000,
+    });
+    const juniorSharesAccount = await token.createAccount(
+      connection,
+      junior.user,
+      juniorSharesMint,
+      junior.user.publicKey
+    );
+    await vaultProgram.methods
+      .depositJunior(new BN(500000))
+      .accounts({
+        vault: tranched.vault,
+        userTokenAccount: junior.tokenAccount,
+        underlyingMint: tranched.underlyingMint,
+        userSharesAccount: juniorSharesAccount,
+        user: junior.user.publicKey,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([junior.user])
+      .rpc();
+
+    await sanction(junior.user.publicKey);
+
+    try {
+      await vaultProgram.methods
+        .redeemJunior(new BN(500000), new BN(0), remainingAccountsMap)
+        .accounts({
+          vault: tranched.vault,
+          userTokenAccount: junior.tokenAccount,
+          underlyingMint: tranched.underlyingMint,
+          userSharesAccount: juniorSharesAccount,
+          user: junior.user.publicKey,
+          tokenProgram: token.TOKEN_PROGRAM_ID,
+        })
+        .signers([junior.user])
+        .rpc();
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.sanctioned);
+    }
+
+    assert.strictEqual(await tokenBalance(juniorSharesAccount), "500000");
+    assert.strictEqual(await tokenBalance(junior.tokenAccount), "0");
+  });
+});
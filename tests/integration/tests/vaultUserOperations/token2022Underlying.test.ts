@@ -0,0 +1,266 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import {
+  accountantProgram,
+  configOwner,
+  connection,
+  strategyProgram,
+  vaultProgram,
+} from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import {
+  DEFAULT_VAULT_CONFIG,
+  depositInto,
+  initializeSimpleStrategy,
+  initializeVault,
+  setupTestVault,
+} from "../../../utils/helpers";
+import { SimpleStrategyConfig } from "../../../utils/schemas";
+
+const createToken2022Mint = async (
+  extension: token.ExtensionType,
+  initExtension: (mint: anchor.web3.PublicKey) => anchor.web3.TransactionInstruction
+) => {
+  const mint = anchor.web3.Keypair.generate();
+  const mintLen = token.getMintLen([extension]);
+  const lamports = await connection.getMinimumBalanceForRentExemption(mintLen);
+
+  const tx = new anchor.web3.Transaction().add(
+    anchor.web3.SystemProgram.createAccount({
+      fromPubkey: configOwner.publicKey,
+      newAccountPubkey: mint.publicKey,
+      space: mintLen,
+      lamports,
+      programId: token.TOKEN_2022_PROGRAM_ID,
+    }),
+    initExtension(mint.publicKey),
+    token.createInitializeMintInstruction(
+      mint.publicKey,
+      9,
+      configOwner.publicKey,
+      null,
+      token.TOKEN_2022_PROGRAM_ID
+    )
+  );
+  await anchor.web3.sendAndConfirmTransaction(connection, tx, [
+    configOwner,
+    mint,
+  ]);
+
+  return mint.publicKey;
+};
+
+describe("Vault User Operations: Token-2022 Underlying Tests", () => {
+  let admin: anchor.web3.Keypair;
+  let accountant: anchor.web3.PublicKey;
+
+  let transferFeeMint: anchor.web3.PublicKey;
+  let transferFeeVault: anchor.web3.PublicKey;
+  let transferFeeVaultTokenAccount: anchor.web3.PublicKey;
+
+  const sharesConfig = {
+    name: "Token-2022 Test Vault",
+    symbol: "TV22",
+    uri: "https://example.com/vault.json",
+  };
+
+  before(async () => {
+    ({ admin, accountant } = await setupTestVault());
+  });
+
+  it("Initializing a vault over a permanent delegate mint should revert", async () => {
+    const underlyingMint = await createToken2022Mint(
+      token.ExtensionType.PermanentDelegate,
+      (mint) =>
+        token.createInitializePermanentDelegateInstruction(
+          mint,
+          configOwner.publicKey,
+          token.TOKEN_2022_PROGRAM_ID
+        )
+    );
+
+    try {
+      await initializeVault({
+        vaultProgram,
+        underlyingMint,
+        signer: admin,
+        vaultConfig: { ...DEFAULT_VAULT_CONFIG, accountant },
+        sharesConfig,
+        tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+      });
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.unsupportedMintExtension);
+    }
+  });
+
+  it("Deposits of a transfer-fee mint credit what the vault received", async () => {
+    // 1% transfer fee
+    const underlyingMint = await createToken2022Mint(
+      token.ExtensionType.TransferFeeConfig,
+      (mint) =>
+        token.createInitializeTransferFeeConfigInstruction(
+          mint,
+          configOwner.publicKey,
+          configOwner.publicKey,
+          100,
+          BigInt(1000000000),
+          token.TOKEN_2022_PROGRAM_ID
+        )
+    );
+
+    const [vault, sharesMint, , vaultTokenAccount] = await initializeVault({
+      vaultProgram,
+      underlyingMint,
+      signer: admin,
+      vaultConfig: { ...DEFAULT_VAULT_CONFIG, accountant },
+      sharesConfig,
+      tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+    });
+
+    await accountantProgram.methods
+      .initTokenAccount()
+      .accounts({
+        accountant,
+        signer: admin.publicKey,
+        mint: sharesMint,
+      })
+      .signers([admin])
+      .rpc();
+
+    const user = anchor.web3.Keypair.generate();
+    const airdropSignature = await connection.requestAirdrop(
+      user.publicKey,
+      10e9
+    );
+    await connection.confirmTransaction(airdropSignature);
+
+    const tokenAccount = await token.createAccount(
+      connection,
+      user,
+      underlyingMint,
+      user.publicKey,
+      undefined,
+      undefined,
+      token.TOKEN_2022_PROGRAM_ID
+    );
+    const sharesAccount = await token.createAccount(
+      connection,
+      user,
+      sharesMint,
+      user.publicKey
+    );
+    await token.mintTo(
+      connection,
+      configOwner,
+      underlyingMint,
+      tokenAccount,
+      configOwner.publicKey,
+      1000000,
+      [],
+      undefined,
+      token.TOKEN_2022_PROGRAM_ID
+    );
+
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      user,
+      tokenAccount,
+      sharesAccount,
+      amount: 1000000,
+      tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+    });
+
+    const vaultTokenAccountInfo = await token.getAccount(
+      connection,
+      vaultTokenAccount,
+      undefined,
+      token.TOKEN_2022_PROGRAM_ID
+    );
+    assert.strictEqual(vaultTokenAccountInfo.amount.toString(), "990000");
+
+    const sharesAccountInfo = await token.getAccount(connection, sharesAccount);
+    assert.strictEqual(sharesAccountInfo.amount.toString(), "990000");
+
+    const vaultAccount = await vaultProgram.account.vault.fetch(vault);
+    assert.strictEqual(vaultAccount.totalIdle.toString(), "990000");
+    assert.strictEqual(vaultAccount.totalShares.toString(), "990000");
+
+    transferFeeMint = underlyingMint;
+    transferFeeVault = vault;
+    transferFeeVaultTokenAccount = vaultTokenAccount;
+  });
+
+  it("Updating debt with a transfer-fee mint books what the strategy received", async () => {
+    const [strategy, strategyTokenAccount] = await initializeSimpleStrategy({
+      strategyProgram,
+      vault: transferFeeVault,
+      underlyingMint: transferFeeMint,
+      signer: admin,
+      config: new SimpleStrategyConfig({
+        depositLimit: new BN(1000000000),
+        performanceFee: new BN(0),
+        feeManager: admin.publicKey,
+      }),
+      tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+    });
+    await vaultProgram.methods
+      .addStrategy(new BN(1000000000))
+      .accounts({
+        vault: transferFeeVault,
+        strategy,
+        signer: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await vaultProgram.methods
+      .updateDebt(new BN(500000))
+      .accounts({
+        vault: transferFeeVault,
+        strategy,
+        underlyingMint: transferFeeMint,
+        signer: admin.publicKey,
+        tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+    const vaultTokenAccountInfo = await token.getAccount(
+      connection,
+      transferFeeVaultTokenAccount,
+      undefined,
+      token.TOKEN_2022_PROGRAM_ID
+    );
+    assert.strictEqual(vaultTokenAccountInfo.amount.toString(), "490000");
+
+    const strategyTokenAccountInfo = await token.getAccount(
+      connection,
+      strategyTokenAccount,
+      undefined,
+      token.TOKEN_2022_PROGRAM_ID
+    );
+    assert.strictEqual(strategyTokenAccountInfo.amount.toString(), "495000");
+
+    const strategyData = anchor.web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("strategy_data"),
+        transferFeeVault.toBuffer(),
+        strategy.toBuffer(),
+      ],
+      vaultProgram.programId
+    )[0];
+    const strategyDataAccount = await vaultProgram.account.strategyData.fetch(
+      strategyData
+    );
+    assert.strictEqual(strategyDataAccount.currentDebt.toString(), "495000");
+
+    const vaultAccount = await vaultProgram.account.vault.fetch(transferFeeVault);
+    assert.strictEqual(vaultAccount.totalIdle.toString(), "490000");
+    assert.strictEqual(vaultAccount.totalDebt.toString(), "495000");
+  });
+});
@@ -0,0 +1,129 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import { configOwner, connection, vaultProgram } from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import {
+  createTestUser,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+
+// the swap itself needs a whirlpool, which the local validator doesn't load,
+// so only the checks made before the swap are covered here
+describe("Vault User Operations: Zap Deposit Tests", () => {
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+
+  const zapDeposit = ({
+    user,
+    tokenAccount,
+    sharesAccount,
+    inputMint,
+    userInputTokenAccount,
+    inputTokenProgram = token.TOKEN_PROGRAM_ID,
+  }: {
+    user: anchor.web3.Keypair;
+    tokenAccount: anchor.web3.PublicKey;
+    sharesAccount: anchor.web3.PublicKey;
+    inputMint: anchor.web3.PublicKey;
+    userInputTokenAccount: anchor.web3.PublicKey;
+    inputTokenProgram?: anchor.web3.PublicKey;
+  }) =>
+    vaultProgram.methods
+      .zapDeposit(new BN(1000000), new BN(1000000), null, null)
+      .accounts({
+        deposit: {
+          vault,
+          accountant,
+          user: user.publicKey,
+          receiver: user.publicKey,
+          userTokenAccount: tokenAccount,
+          userSharesAccount: sharesAccount,
+          underlyingMint,
+          tokenProgram: token.TOKEN_PROGRAM_ID,
+        },
+        userInputTokenAccount,
+        inputMint,
+        whirlpool: anchor.web3.Keypair.generate().publicKey,
+        tokenVaultA: tokenAccount,
+        tokenVaultB: tokenAccount,
+        tickArray0: anchor.web3.Keypair.generate().publicKey,
+        tickArray1: anchor.web3.Keypair.generate().publicKey,
+        tickArray2: anchor.web3.Keypair.generate().publicKey,
+        oracle: anchor.web3.Keypair.generate().publicKey,
+        inputTokenProgram,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+  before(async () => {
+    ({ underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault());
+  });
+
+  it("Zapping the vault's own underlying should revert", async () => {
+    const { user, tokenAccount, sharesAccount } = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    });
+
+    try {
+      await zapDeposit({
+        user,
+        tokenAccount,
+        sharesAccount,
+        inputMint: underlyingMint,
+        userInputTokenAccount: tokenAccount,
+      });
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.invalidSwapPool);
+    }
+
+    assert.strictEqual(await tokenBalance(tokenAccount), "1000000");
+    assert.strictEqual(await tokenBalance(sharesAccount), "0");
+  });
+
+  it("Zapping with a token program that doesn't own the input account should revert", async () => {
+    const { user, tokenAccount, sharesAccount } = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 0,
+    });
+    const inputMint = await token.createMint(
+      connection,
+      configOwner,
+      configOwner.publicKey,
+      null,
+      6
+    );
+    const userInputTokenAccount = await token.createAccount(
+      connection,
+      user,
+      inputMint,
+      user.publicKey
+    );
+
+    try {
+      await zapDeposit({
+        user,
+        tokenAccount,
+        sharesAccount,
+        inputMint,
+        userInputTokenAccount,
+        inputTokenProgram: token.TOKEN_2022_PROGRAM_ID,
+      });
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.tokenTokenProgramConstraint);
+    }
+
+    assert.strictEqual(await tokenBalance(sharesAccount), "0");
+  });
+});
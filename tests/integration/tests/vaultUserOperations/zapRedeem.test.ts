@@ -0,0 +1,120 @@
+import * as anchor from "@coral-xyz/anchor";
+import { BN } from "@coral-xyz/anchor";
+import * as token from "@solana/spl-token";
+import { assert, expect } from "chai";
+import { configOwner, connection, vaultProgram } from "../../setups/globalSetup";
+import { errorStrings } from "../../../utils/constants";
+import {
+  createTestUser,
+  depositInto,
+  setupTestVault,
+  tokenBalance,
+} from "../../../utils/helpers";
+
+// the swap itself needs a whirlpool, which the local validator doesn't load,
+// so only the checks made before any shares are burned are covered here
+describe("Vault User Operations: Zap Redeem Tests", () => {
+  let underlyingMint: anchor.web3.PublicKey;
+  let accountant: anchor.web3.PublicKey;
+  let vault: anchor.web3.PublicKey;
+  let sharesMint: anchor.web3.PublicKey;
+  let outputMint: anchor.web3.PublicKey;
+
+  let user: anchor.web3.Keypair;
+  let tokenAccount: anchor.web3.PublicKey;
+  let sharesAccount: anchor.web3.PublicKey;
+  let outputTokenAccount: anchor.web3.PublicKey;
+
+  const remainingAccountsMap = { accountsMap: [] };
+
+  const zapRedeem = (
+    userOutputTokenAccount: anchor.web3.PublicKey,
+    userOutputMint: anchor.web3.PublicKey,
+    tokenVaultA: anchor.web3.PublicKey,
+    tokenVaultB: anchor.web3.PublicKey
+  ) =>
+    vaultProgram.methods
+      .zapRedeem(new BN(1000000), new BN(0), new BN(1), remainingAccountsMap)
+      .accounts({
+        withdraw: {
+          vault,
+          accountant,
+          user: user.publicKey,
+          owner: user.publicKey,
+          userTokenAccount: tokenAccount,
+          userSharesAccount: sharesAccount,
+          underlyingMint,
+          tokenProgram: token.TOKEN_PROGRAM_ID,
+        },
+        userOutputTokenAccount,
+        outputMint: userOutputMint,
+        whirlpool: anchor.web3.Keypair.generate().publicKey,
+        tokenVaultA,
+        tokenVaultB,
+        tickArray0: anchor.web3.Keypair.generate().publicKey,
+        tickArray1: anchor.web3.Keypair.generate().publicKey,
+        tickArray2: anchor.web3.Keypair.generate().publicKey,
+        oracle: anchor.web3.Keypair.generate().publicKey,
+        outputTokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
+      })
+      .signers([user])
+      .rpc();
+
+  before(async () => {
+    ({ underlyingMint, accountant, vault, sharesMint } =
+      await setupTestVault());
+
+    ({ user, tokenAccount, sharesAccount } = await createTestUser({
+      underlyingMint,
+      sharesMint,
+      amount: 1000000,
+    }));
+    await depositInto({
+      vault,
+      accountant,
+      underlyingMint,
+      user,
+      tokenAccount,
+      sharesAccount,
+      amount: 1000000,
+    });
+
+    outputMint = await token.createMint(
+      connection,
+      configOwner,
+      configOwner.publicKey,
+      null,
+      6
+    );
+    outputTokenAccount = await token.createAccount(
+      connection,
+      user,
+      outputMint,
+      user.publicKey
+    );
+  });
+
+  it("Zapping out into the vault's own underlying should revert", async () => {
+    try {
+      await zapRedeem(tokenAccount, underlyingMint, tokenAccount, outputTokenAccount);
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.invalidSwapPool);
+    }
+
+    assert.strictEqual(await tokenBalance(sharesAccount), "1000000");
+  });
+
+  it("Zapping out through a pool without the output mint should revert", async () => {
+    try {
+      await zapRedeem(outputTokenAccount, outputMint, tokenAccount, tokenAccount);
+      assert.fail("Error was not thrown");
+    } catch (err) {
+      expect(err.message).to.contain(errorStrings.invalidSwapPool);
+    }
+
+    assert.strictEqual(await tokenBalance(sharesAccount), "1000000");
+    assert.strictEqual(await tokenBalance(outputTokenAccount), "0");
+  });
+});
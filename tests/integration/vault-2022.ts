@@ -7,6 +7,7 @@ import * as token from "@solana/spl-token";
 import * as borsh from 'borsh';
 import { assert, expect } from 'chai';
 import { SimpleStrategyConfig, SimpleStrategyConfigSchema } from "../utils/schemas";
+import { ACCOUNTANT_CONFIG } from "../utils/constants";
 
 const METADATA_SEED = "metadata";
 const TOKEN_METADATA_PROGRAM_ID = new web3.PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
@@ -307,7 +308,7 @@ describe("tokenized_vault", () => {
   it("generic accountant", async () => {
     const accountantType = { generic: {} };
 
-    await accountantProgram.methods.initAccountant(accountantType)
+    await accountantProgram.methods.initAccountant(accountantType, ACCOUNTANT_CONFIG)
       .accounts({
         signer: admin.publicKey,
       })
@@ -562,14 +563,16 @@ describe("tokenized_vault", () => {
       accessControlProgram.programId,
     )[0];
 
-    await vaultProgram.methods.deposit(new BN(100))
+    await vaultProgram.methods.deposit(new BN(100), null, null)
       .accounts({
         vault,
         user: user.publicKey,
+        receiver: user.publicKey,
         underlyingMint,
         userTokenAccount,
         userSharesAccount,
         tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([user])
       .remainingAccounts([
@@ -759,9 +762,11 @@ describe("tokenized_vault", () => {
         vault,
         underlyingMint,
         user: user.publicKey,
+        owner: user.publicKey,
         userTokenAccount,
         userSharesAccount,
         tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .remainingAccounts([
         { pubkey: strategy, isWritable: true, isSigner: false },
@@ -862,9 +867,11 @@ describe("tokenized_vault", () => {
         vault,
         underlyingMint,
         user: newOwner.publicKey,
+        owner: newOwner.publicKey,
         userTokenAccount: newOwnerTokenAccount,
         userSharesAccount: newOwnerSharesAccount,
         tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .remainingAccounts([
         { pubkey: strategy, isWritable: true, isSigner: false },
@@ -911,6 +918,9 @@ describe("tokenized_vault", () => {
         strategy,
         signer: admin.publicKey,
         accountant,
+        underlyingMint,
+        underlyingTokenProgram: token.TOKEN_2022_PROGRAM_ID,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([admin])
       .rpc();
@@ -951,9 +961,11 @@ describe("tokenized_vault", () => {
         vault,
         underlyingMint,
         user: user.publicKey,
+        owner: user.publicKey,
         userTokenAccount,
         userSharesAccount,
         tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .remainingAccounts([
         { pubkey: strategy, isWritable: true, isSigner: false },
@@ -994,9 +1006,11 @@ describe("tokenized_vault", () => {
         vault,
         underlyingMint,
         user: feeRecipient.publicKey,
+        owner: feeRecipient.publicKey,
         userTokenAccount: feeRecipientTokenAccount,
         userSharesAccount: feeRecipientSharesAccount,
         tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .remainingAccounts([
         { pubkey: strategy, isWritable: true, isSigner: false },
@@ -1135,14 +1149,16 @@ describe("tokenized_vault", () => {
       accessControlProgram.programId,
     )[0];
 
-    await vaultProgram.methods.deposit(new BN(100))
+    await vaultProgram.methods.deposit(new BN(100), null, null)
       .accounts({
         vault,
         underlyingMint,
         user: user.publicKey,
+        receiver: user.publicKey,
         userTokenAccount,
         userSharesAccount,
         tokenProgram: token.TOKEN_2022_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([user])
       .remainingAccounts([
@@ -1186,6 +1202,9 @@ describe("tokenized_vault", () => {
         strategy,
         accountant,
         signer: admin.publicKey,
+        underlyingMint,
+        underlyingTokenProgram: token.TOKEN_2022_PROGRAM_ID,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([admin])
       .rpc();
@@ -253,10 +253,11 @@ describe("tokenized_vault with trade-fi strategy", () => {
     await token.mintTo(provider.connection, admin, underlyingMint, userTokenAccount, admin.publicKey, 1000);
     console.log("Minted 1000 tokens to user:", userTokenAccount.toBase58());
 
-    await vaultProgram.methods.deposit(new BN(100))
+    await vaultProgram.methods.deposit(new BN(100), null, null)
       .accounts({
         vault,
         user: user.publicKey,
+        receiver: user.publicKey,
         userTokenAccount,
         userSharesAccount,
       })
@@ -368,6 +369,7 @@ describe("tokenized_vault with trade-fi strategy", () => {
       .accounts({
         vault,
         user: user.publicKey,
+        owner: user.publicKey,
         userTokenAccount,
         userSharesAccount,
       })
@@ -7,6 +7,7 @@ import * as token from "@solana/spl-token";
 import * as borsh from 'borsh';
 import { assert, expect } from 'chai';
 import { SimpleStrategyConfig, SimpleStrategyConfigSchema } from "../utils/schemas";
+import { ACCOUNTANT_CONFIG } from "../utils/constants";
 
 const METADATA_SEED = "metadata";
 const TOKEN_METADATA_PROGRAM_ID = new web3.PublicKey("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
@@ -287,7 +288,7 @@ describe("tokenized_vault", () => {
   it("generic accountant", async () => {
     const accountantType = { generic: {} };
 
-    await accountantProgram.methods.initAccountant(accountantType)
+    await accountantProgram.methods.initAccountant(accountantType, { ...ACCOUNTANT_CONFIG, performanceFee: new BN(1000) })
       .accounts({
         signer: admin.publicKey,
       })
@@ -558,16 +559,18 @@ describe("tokenized_vault", () => {
     let user_data = await vaultProgram.account.userData.fetch(user_data_addr);
     console.log("deposited: ", user_data.deposited.toString());
 
-    await vaultProgram.methods.deposit(new BN(100))
+    await vaultProgram.methods.deposit(new BN(100), null, null)
       .accounts({
         vault,
         underlyingMint,
         accountant,
         // strategy,
         user: user.publicKey,
+        receiver: user.publicKey,
         userTokenAccount,
         userSharesAccount,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([user])
       .remainingAccounts([
@@ -601,10 +604,12 @@ describe("tokenized_vault", () => {
         vault,
         accountant,
         user: user.publicKey,
+        owner: user.publicKey,
         underlyingMint,
         userTokenAccount,
         userSharesAccount,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .remainingAccounts([
         { pubkey: strategy, isWritable: true, isSigner: false },
@@ -617,16 +622,18 @@ describe("tokenized_vault", () => {
       user_data = await vaultProgram.account.userData.fetch(user_data_addr);
     console.log("deposited: ", user_data.deposited.toString());
 
-    await vaultProgram.methods.deposit(new BN(1))
+    await vaultProgram.methods.deposit(new BN(1), null, null)
       .accounts({
         vault,
         underlyingMint,
         accountant,
         // strategy,
         user: user.publicKey,
+        receiver: user.publicKey,
         userTokenAccount,
         userSharesAccount,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([user])
       .remainingAccounts([
@@ -817,9 +824,11 @@ describe("tokenized_vault", () => {
         accountant,
         underlyingMint,
         user: user.publicKey,
+        owner: user.publicKey,
         userTokenAccount,
         userSharesAccount,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .remainingAccounts([
         { pubkey: strategy, isWritable: true, isSigner: false },
@@ -913,9 +922,11 @@ describe("tokenized_vault", () => {
         accountant,
         underlyingMint,
         user: newOwner.publicKey,
+        owner: newOwner.publicKey,
         userTokenAccount: newOwnerTokenAccount,
         userSharesAccount: newOwnerSharesAccount,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .remainingAccounts([
         { pubkey: strategy, isWritable: true, isSigner: false },
@@ -962,6 +973,9 @@ describe("tokenized_vault", () => {
         strategy,
         signer: admin.publicKey,
         accountant,
+        underlyingMint,
+        underlyingTokenProgram: token.TOKEN_PROGRAM_ID,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([admin])
       .rpc();
@@ -1003,9 +1017,11 @@ describe("tokenized_vault", () => {
         underlyingMint,
         accountant,
         user: user.publicKey,
+        owner: user.publicKey,
         userTokenAccount,
         userSharesAccount,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .remainingAccounts([
         { pubkey: strategy, isWritable: true, isSigner: false },
@@ -1047,9 +1063,11 @@ describe("tokenized_vault", () => {
         underlyingMint,
         accountant,
         user: feeRecipient.publicKey,
+        owner: feeRecipient.publicKey,
         userTokenAccount: feeRecipientTokenAccount,
         userSharesAccount: feeRecipientSharesAccount,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .remainingAccounts([
         { pubkey: strategy, isWritable: true, isSigner: false },
@@ -1188,15 +1206,17 @@ describe("tokenized_vault", () => {
       accessControlProgram.programId,
     )[0];
 
-    await vaultProgram.methods.deposit(new BN(100))
+    await vaultProgram.methods.deposit(new BN(100), null, null)
       .accounts({
         vault,
         accountant,
         underlyingMint,
         user: user.publicKey,
+        receiver: user.publicKey,
         userTokenAccount,
         userSharesAccount,
         tokenProgram: token.TOKEN_PROGRAM_ID,
+        sharesTokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([user])
       .remainingAccounts([
@@ -1240,6 +1260,9 @@ describe("tokenized_vault", () => {
         strategy,
         accountant,
         signer: admin.publicKey,
+        underlyingMint,
+        underlyingTokenProgram: token.TOKEN_PROGRAM_ID,
+        tokenProgram: token.TOKEN_PROGRAM_ID,
       })
       .signers([admin])
       .rpc();
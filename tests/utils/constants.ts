@@ -8,6 +8,9 @@ export const ROLES = {
   ACCOUNTANT_ADMIN: new BN(4),
   KYC_PROVIDER: new BN(5),
   KYC_VERIFIED: new BN(6),
+  COMPLIANCE_OFFICER: new BN(7),
+  SANCTIONED: new BN(8),
+  GUARDIAN: new BN(9),
 };
 export const ROLES_BUFFER = {
   ROLES_ADMIN: Buffer.from(
@@ -31,6 +34,27 @@ export const ROLES_BUFFER = {
   KYC_VERIFIED: Buffer.from(
     new Uint8Array(new BigUint64Array([BigInt(6)]).buffer)
   ),
+  COMPLIANCE_OFFICER: Buffer.from(
+    new Uint8Array(new BigUint64Array([BigInt(7)]).buffer)
+  ),
+  SANCTIONED: Buffer.from(
+    new Uint8Array(new BigUint64Array([BigInt(8)]).buffer)
+  ),
+  GUARDIAN: Buffer.from(
+    new Uint8Array(new BigUint64Array([BigInt(9)]).buffer)
+  ),
+};
+
+export const ACCOUNTANT_CONFIG = {
+  feeCaps: {
+    maxEntryFee: new BN(10000),
+    maxRedemptionFee: new BN(10000),
+    maxPerformanceFee: new BN(10000),
+  },
+  feeChangeDelay: new BN(86400),
+  entryFee: new BN(0),
+  redemptionFee: new BN(0),
+  performanceFee: new BN(0),
 };
 
 export const errorStrings = {
@@ -60,4 +84,38 @@ export const errorStrings = {
     "Error Code: MaxDepositReached. Error Number: 6005. Error Message: Max deposit reached.",
   debtHigherThanMaxDebt:
     "Error Code: DebtHigherThanMaxDebt. Error Number: 6007. Error Message: Debt cannot be higher than max debt.",
+  exceedWithdrawLimit:
+    "Error Code: ExceedWithdrawLimit. Error Number: 6012. Error Message: Exceed withdraw limit.",
+  vaultNotEmpty:
+    "Error Code: VaultNotEmpty. Error Number: 6027. Error Message: Vault must be empty.",
+  tokenTokenProgramConstraint:
+    "Error Code: ConstraintTokenTokenProgram. Error Number: 2021. Error Message: A token account token program constraint was violated.",
+  invalidSwapPool:
+    "Error Code: InvalidSwapPool. Error Number: 6030. Error Message: Swap pool does not match the expected mints.",
+  unsupportedMintExtension:
+    "Error Code: UnsupportedMintExtension. Error Number: 6031. Error Message: Mint has an unsupported token extension.",
+  invalidReferrerAccount:
+    "Error Code: InvalidReferrerAccount. Error Number: 6034. Error Message: Referrer shares account is missing or invalid.",
+  tokenOwnerMismatch: "custom program error: 0x4",
+  // raised by the kyc hook inside token-2022, so only the code makes it into the message
+  receiverNotVerified: "custom program error: 0x1770",
+  senderSanctioned: "custom program error: 0x1772",
+  invalidExpiry:
+    "Error Code: InvalidExpiry. Error Number: 6036. Error Message: Expiry must be in the future.",
+  sanctioned:
+    "Error Code: Sanctioned. Error Number: 6037. Error Message: Account is sanctioned.",
+  vaultPaused:
+    "Error Code: Paused. Error Number: 6039. Error Message: Operation is paused.",
+  invalidPauseFlags:
+    "Error Code: InvalidPauseFlags. Error Number: 6040. Error Message: Unknown pause flags.",
+  vaultAlreadyMigrated:
+    "Error Code: VaultAlreadyMigrated. Error Number: 6044. Error Message: Vault is already on the latest layout.",
+  strategyAlreadyMigrated:
+    "Error Code: StrategyAlreadyMigrated. Error Number: 6014. Error Message: Strategy is already on the latest layout.",
+  incompleteStrategyList:
+    "Error Code: IncompleteStrategyList. Error Number: 6045. Error Message: Every vault strategy must be passed exactly once.",
+  invalidSweepMint:
+    "Error Code: InvalidSweepMint. Error Number: 6046. Error Message: Underlying and vault shares can't be swept.",
+  vaultTranched:
+    "Error Code: VaultTranched. Error Number: 6047. Error Message: Vault is tranched.",
 };
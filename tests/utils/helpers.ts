@@ -5,6 +5,11 @@ import { Strategy } from "../../target/types/strategy";
 import { SimpleStrategyConfigSchema } from "./schemas";
 import * as borsh from "borsh";
 import {
+  accessControlProgram,
+  accountantProgram,
+  configOwner,
+  connection,
+  kycHookProgram,
   METADATA_SEED,
   provider,
   TOKEN_METADATA_PROGRAM_ID,
@@ -12,6 +17,7 @@ import {
 } from "../integration/setups/globalSetup";
 import * as token from "@solana/spl-token";
 import { assert } from "chai";
+import { ACCOUNTANT_CONFIG, ROLES } from "./constants";
 
 export const airdrop = async ({
   connection,
@@ -37,12 +43,16 @@ export const initializeVault = async ({
   signer,
   vaultConfig,
   sharesConfig,
+  tokenProgram = token.TOKEN_PROGRAM_ID,
+  kycShares = false,
 }: {
   vaultProgram: anchor.Program<TokenizedVault>;
   underlyingMint: anchor.web3.PublicKey;
   signer: anchor.web3.Keypair;
   vaultConfig: any;
   sharesConfig: any;
+  tokenProgram?: anchor.web3.PublicKey;
+  kycShares?: boolean;
 }) => {
   const config = web3.PublicKey.findProgramAddressSync(
     [Buffer.from("config")],
@@ -87,19 +97,36 @@ export const initializeVault = async ({
     .accounts({
       underlyingMint,
       signer: signer.publicKey,
-      tokenProgram: token.TOKEN_PROGRAM_ID,
+      tokenProgram,
     })
     .signers([signer])
     .rpc();
 
-  await vaultProgram.methods
-    .initVaultShares(new BN(nextVaultIndex), sharesConfig)
-    .accounts({
-      metadata: metadataAddress,
-      signer: signer.publicKey,
-    })
-    .signers([signer])
-    .rpc();
+  if (kycShares) {
+    const extraAccountMetaList = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("extra-account-metas"), sharesMint.toBuffer()],
+      kycHookProgram.programId
+    )[0];
+
+    await vaultProgram.methods
+      .initKycVaultShares(new BN(nextVaultIndex), sharesConfig)
+      .accounts({
+        metadata: metadataAddress,
+        extraAccountMetaList,
+        signer: signer.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  } else {
+    await vaultProgram.methods
+      .initVaultShares(new BN(nextVaultIndex), sharesConfig)
+      .accounts({
+        metadata: metadataAddress,
+        signer: signer.publicKey,
+      })
+      .signers([signer])
+      .rpc();
+  }
 
   return [vault, sharesMint, metadataAddress, vaultTokenAccount];
 };
@@ -110,12 +137,14 @@ export const initializeSimpleStrategy = async ({
   underlyingMint,
   signer,
   config,
+  tokenProgram = token.TOKEN_PROGRAM_ID,
 }: {
   strategyProgram: anchor.Program<Strategy>;
   vault: anchor.web3.PublicKey;
   underlyingMint: anchor.web3.PublicKey;
   signer: anchor.web3.Keypair;
   config: any;
+  tokenProgram?: anchor.web3.PublicKey;
 }) => {
   const globalStrategyConfig = web3.PublicKey.findProgramAddressSync(
     [Buffer.from("config")],
@@ -154,7 +183,7 @@ export const initializeSimpleStrategy = async ({
       vault,
       signer: signer.publicKey,
       underlyingMint,
-      tokenProgram: token.TOKEN_PROGRAM_ID,
+      tokenProgram,
     })
     .signers([signer])
     .rpc();
@@ -309,4 +338,253 @@ export const validateDirectDeposit = async ({
   );
 };
 
+export const DEFAULT_VAULT_CONFIG = {
+  depositLimit: new BN(100000000000),
+  userDepositLimit: new BN(0),
+  minUserDeposit: new BN(0),
+  accountant: null,
+  profitMaxUnlockTime: new BN(0),
+  kycVerifiedOnly: false,
+  directDepositEnabled: false,
+  whitelistedOnly: false,
+  directWithdrawEnabled: true,
+};
+
+export const setRoles = async ({
+  user,
+  roles,
+}: {
+  user: anchor.web3.PublicKey;
+  roles: BN[];
+}) => {
+  for (const role of roles) {
+    await accessControlProgram.methods
+      .setRole(role, user)
+      .accounts({
+        signer: configOwner.publicKey,
+      })
+      .signers([configOwner])
+      .rpc();
+  }
+};
+
+export const initializeAccountant = async ({
+  signer,
+  config = ACCOUNTANT_CONFIG,
+}: {
+  signer: anchor.web3.Keypair;
+  config?: any;
+}) => {
+  const accountantConfig = web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    accountantProgram.programId
+  )[0];
+  const configAccount = await accountantProgram.account.config.fetch(
+    accountantConfig
+  );
+
+  const accountant = web3.PublicKey.findProgramAddressSync(
+    [
+      Buffer.from(
+        new Uint8Array(
+          new BigUint64Array([
+            BigInt(configAccount.nextAccountantIndex.toNumber()),
+          ]).buffer
+        )
+      ),
+    ],
+    accountantProgram.programId
+  )[0];
+
+  await accountantProgram.methods
+    .initAccountant({ generic: {} }, config)
+    .accounts({
+      signer: signer.publicKey,
+    })
+    .signers([signer])
+    .rpc();
+
+  return accountant;
+};
+
+// a fresh admin holding every vault role, an accountant and a vault with its shares mint
+export const setupTestVault = async ({
+  vaultConfig = {},
+  accountantConfig = ACCOUNTANT_CONFIG,
+  decimals = 9,
+  kycShares = false,
+}: {
+  vaultConfig?: any;
+  accountantConfig?: any;
+  decimals?: number;
+  kycShares?: boolean;
+} = {}) => {
+  const admin = anchor.web3.Keypair.generate();
+  await airdrop({ connection, publicKey: admin.publicKey, amount: 10e9 });
+  await setRoles({
+    user: admin.publicKey,
+    roles: [
+      ROLES.VAULTS_ADMIN,
+      ROLES.ACCOUNTANT_ADMIN,
+      ROLES.STRATEGIES_MANAGER,
+      ROLES.REPORTING_MANAGER,
+    ],
+  });
+
+  const underlyingMint = await token.createMint(
+    connection,
+    configOwner,
+    configOwner.publicKey,
+    null,
+    decimals
+  );
+
+  const accountant = await initializeAccountant({
+    signer: admin,
+    config: accountantConfig,
+  });
+
+  const [vault, sharesMint, metadataAccount, vaultTokenAccount] =
+    await initializeVault({
+      vaultProgram,
+      underlyingMint,
+      signer: admin,
+      vaultConfig: { ...DEFAULT_VAULT_CONFIG, accountant, ...vaultConfig },
+      sharesConfig: {
+        name: "Test Vault",
+        symbol: "TV",
+        uri: "https://example.com/vault.json",
+      },
+      kycShares,
+    });
+
+  // the accountant only initializes legacy token accounts, KYC shares live on Token-2022
+  if (kycShares) {
+    await token.createAssociatedTokenAccountIdempotent(
+      connection,
+      admin,
+      sharesMint,
+      accountant,
+      {},
+      token.TOKEN_2022_PROGRAM_ID,
+      token.ASSOCIATED_TOKEN_PROGRAM_ID,
+      true
+    );
+  }
+
+  for (const mint of kycShares ? [underlyingMint] : [sharesMint, underlyingMint]) {
+    await accountantProgram.methods
+      .initTokenAccount()
+      .accounts({
+        accountant,
+        signer: admin.publicKey,
+        mint,
+      })
+      .signers([admin])
+      .rpc();
+  }
+
+  return {
+    admin,
+    underlyingMint,
+    accountant,
+    vault,
+    sharesMint,
+    metadataAccount,
+    vaultTokenAccount,
+  };
+};
+
+export const createTestUser = async ({
+  underlyingMint,
+  sharesMint,
+  amount,
+  sharesTokenProgram = token.TOKEN_PROGRAM_ID,
+}: {
+  underlyingMint: anchor.web3.PublicKey;
+  sharesMint: anchor.web3.PublicKey;
+  amount: number;
+  sharesTokenProgram?: anchor.web3.PublicKey;
+}) => {
+  const user = anchor.web3.Keypair.generate();
+  await airdrop({ connection, publicKey: user.publicKey, amount: 10e9 });
+
+  const tokenAccount = await token.createAccount(
+    connection,
+    user,
+    underlyingMint,
+    user.publicKey
+  );
+  const sharesAccount = await token.createAccount(
+    connection,
+    user,
+    sharesMint,
+    user.publicKey,
+    undefined,
+    undefined,
+    sharesTokenProgram
+  );
+  if (amount > 0) {
+    await token.mintTo(
+      connection,
+      configOwner,
+      underlyingMint,
+      tokenAccount,
+      configOwner.publicKey,
+      amount
+    );
+  }
+
+  return { user, tokenAccount, sharesAccount };
+};
+
+export const depositInto = async ({
+  vault,
+  accountant,
+  underlyingMint,
+  user,
+  tokenAccount,
+  sharesAccount,
+  amount,
+  tokenProgram = token.TOKEN_PROGRAM_ID,
+  sharesTokenProgram = token.TOKEN_PROGRAM_ID,
+}: {
+  vault: anchor.web3.PublicKey;
+  accountant: anchor.web3.PublicKey;
+  underlyingMint: anchor.web3.PublicKey;
+  user: anchor.web3.Keypair;
+  tokenAccount: anchor.web3.PublicKey;
+  sharesAccount: anchor.web3.PublicKey;
+  amount: number;
+  tokenProgram?: anchor.web3.PublicKey;
+  sharesTokenProgram?: anchor.web3.PublicKey;
+}) => {
+  await vaultProgram.methods
+    .deposit(new BN(amount), null, null)
+    .accounts({
+      vault,
+      accountant,
+      user: user.publicKey,
+      receiver: user.publicKey,
+      userTokenAccount: tokenAccount,
+      userSharesAccount: sharesAccount,
+      underlyingMint,
+      tokenProgram,
+      sharesTokenProgram,
+    })
+    .signers([user])
+    .rpc();
+};
 
+export const tokenBalance = async (
+  account: anchor.web3.PublicKey,
+  tokenProgram = token.TOKEN_PROGRAM_ID
+) => {
+  const info = await token.getAccount(
+    connection,
+    account,
+    undefined,
+    tokenProgram
+  );
+  return info.amount.toString();
+};